@@ -0,0 +1,101 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(
+    name = "pg-tempest-bench",
+    version,
+    about = "Load generator simulating concurrent clients of pg-tempest"
+)]
+pub struct Cli {
+    /// Number of concurrently simulated clients
+    #[arg(long, default_value_t = 8)]
+    pub clients: usize,
+
+    /// Duration of the load, template initialization is not included
+    #[arg(long, default_value_t = 30)]
+    pub duration_s: u64,
+
+    /// Number of templates the clients are spread across
+    #[arg(long, default_value_t = 1)]
+    pub templates: usize,
+
+    /// Distribution of hold and think times
+    #[arg(long, value_enum, default_value_t = Distribution::Exponential)]
+    pub distribution: Distribution,
+
+    /// Mean time a client holds a leased test db
+    #[arg(long, default_value_t = 200)]
+    pub hold_ms: u64,
+
+    /// Mean time a client waits after a release before the next lease
+    #[arg(long, default_value_t = 0)]
+    pub think_ms: u64,
+
+    /// Interval of polling of test db states, which bounds precision of recreate latency
+    #[arg(long, default_value_t = 10)]
+    pub poll_interval_ms: u64,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub target: Target,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Distribution {
+    /// Always the mean
+    Constant,
+    /// Uniform between zero and twice the mean
+    Uniform,
+    /// Exponential with the given mean
+    Exponential,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Target {
+    /// Run the load against a running pg-tempest server.
+    /// Templates created by the run are left on the server
+    Server {
+        /// Address of the pg-tempest server
+        #[arg(long, env = "PG_TEMPEST_URL", default_value = "http://localhost:8000")]
+        server_url: String,
+    },
+
+    /// Run the load against a core embedded into the benchmark.
+    /// Templates created by the run are purged in the end
+    Embedded(EmbeddedArgs),
+}
+
+#[derive(Args)]
+pub struct EmbeddedArgs {
+    #[arg(long, default_value = "localhost")]
+    pub pg_host: String,
+    #[arg(long, default_value_t = 5432)]
+    pub pg_port: u16,
+    #[arg(long, default_value = "postgres")]
+    pub pg_database: String,
+    #[arg(long, default_value = "postgres")]
+    pub pg_user: String,
+    #[arg(long, env = "PGPASSWORD", default_value = "postgres")]
+    pub pg_password: String,
+
+    /// Number of ready test dbs kept for each template
+    #[arg(long, default_value_t = 4)]
+    pub min_size: u8,
+
+    /// Delay between retries of creation of corrupted test dbs
+    #[arg(long, default_value_t = 1000)]
+    pub creation_retries_delay_ms: u64,
+
+    /// Simulate the DBMS in memory with the given latency of each operation,
+    /// so that overhead of pg-tempest itself is measured
+    #[arg(long)]
+    pub fake_pg_latency_ms: Option<u64>,
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::cli::Distribution;
+
+impl Distribution {
+    pub fn sample(self, mean: Duration) -> Duration {
+        if mean.is_zero() {
+            return Duration::ZERO;
+        }
+
+        match self {
+            Distribution::Constant => mean,
+            Distribution::Uniform => mean.mul_f64(2.0 * rand::random::<f64>()),
+            // Inverse transform sampling, 1 - u is used to avoid ln(0)
+            Distribution::Exponential => mean.mul_f64(-(1.0 - rand::random::<f64>()).ln()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::cli::Distribution;
+
+    #[test]
+    fn samples_have_expected_means() {
+        let mean = Duration::from_millis(100);
+        let count = 100_000;
+
+        for distribution in [
+            Distribution::Constant,
+            Distribution::Uniform,
+            Distribution::Exponential,
+        ] {
+            let sum: Duration = (0..count).map(|_| distribution.sample(mean)).sum();
+            let sample_mean_ms = sum.as_secs_f64() * 1000.0 / count as f64;
+
+            assert!(
+                (sample_mean_ms - 100.0).abs() < 5.0,
+                "{distribution:?} mean is {sample_mean_ms}"
+            );
+        }
+    }
+
+    #[test]
+    fn uniform_samples_are_bounded_by_twice_the_mean() {
+        let mean = Duration::from_millis(100);
+
+        assert!((0..10_000).all(|_| Distribution::Uniform.sample(mean) <= mean * 2));
+    }
+}
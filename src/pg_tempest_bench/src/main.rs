@@ -0,0 +1,25 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::{cli::Cli, runner::run};
+
+mod cli;
+mod distribution;
+mod report;
+mod runner;
+mod stats;
+mod targets;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use pg_tempest_core::utils::errors::BoxDynError;
+use serde::Serialize;
+
+use crate::{cli::OutputFormat, stats::LatencySummary};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchReport {
+    pub clients: usize,
+    pub templates: usize,
+    pub duration_ms: u64,
+    pub leases: usize,
+    pub failed_leases: usize,
+    pub failed_releases: usize,
+    pub throughput_per_s: f64,
+    pub template_initialization: LatencySummary,
+    pub lease_wait: LatencySummary,
+    pub recreate: LatencySummary,
+    // Released test dbs which weren't seen available again, e.g. because they are corrupted
+    pub unfinished_recreates: usize,
+    pub last_error: Option<String>,
+}
+
+pub fn print_report(report: &BenchReport, output_format: OutputFormat) -> Result<(), BoxDynError> {
+    match output_format {
+        OutputFormat::Table => print_table(report),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(report)?),
+    }
+
+    Ok(())
+}
+
+fn print_table(report: &BenchReport) {
+    let mut fields = vec![
+        ("clients", report.clients.to_string()),
+        ("templates", report.templates.to_string()),
+        (
+            "duration",
+            format!("{:.1}s", report.duration_ms as f64 / 1000.0),
+        ),
+        ("leases", report.leases.to_string()),
+        ("failed leases", report.failed_leases.to_string()),
+        ("failed releases", report.failed_releases.to_string()),
+        ("throughput", format!("{:.1}/s", report.throughput_per_s)),
+        (
+            "unfinished recreates",
+            report.unfinished_recreates.to_string(),
+        ),
+    ];
+    if let Some(last_error) = &report.last_error {
+        fields.push(("last error", last_error.clone()));
+    }
+
+    for (name, value) in fields {
+        println!("{name:<24}  {value}");
+    }
+    println!();
+
+    println!(
+        "{:<24}  {:>8}  {:>10}  {:>10}  {:>10}",
+        "LATENCY", "SAMPLES", "P50 MS", "P99 MS", "MAX MS"
+    );
+    for (name, summary) in [
+        ("template initialization", &report.template_initialization),
+        ("lease wait", &report.lease_wait),
+        ("recreate", &report.recreate),
+    ] {
+        println!(
+            "{name:<24}  {:>8}  {:>10}  {:>10}  {:>10}",
+            summary.samples,
+            format_ms(summary.p50_ms),
+            format_ms(summary.p99_ms),
+            format_ms(summary.max_ms),
+        );
+    }
+}
+
+fn format_ms(ms: Option<f64>) -> String {
+    match ms {
+        Some(ms) => format!("{ms:.1}"),
+        None => "-".into(),
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use pg_tempest_core::{
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    utils::errors::BoxDynError,
+};
+use tokio::{
+    task::JoinSet,
+    time::{Instant, sleep, sleep_until},
+};
+
+use crate::{
+    cli::{Cli, Distribution, Target},
+    report::{BenchReport, print_report},
+    stats::Latencies,
+    targets::{
+        BenchTarget, TestDbPhase, embedded_target::EmbeddedTarget, server_target::ServerTarget,
+    },
+};
+
+// Added to hold time so that test dbs aren't reclaimed by the usage deadline while they are held
+const USAGE_DURATION_MARGIN: Duration = Duration::from_secs(60);
+const POOL_FILLING_TIMEOUT: Duration = Duration::from_secs(300);
+const RECREATE_DRAINING_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub async fn run(cli: Cli) -> Result<(), BoxDynError> {
+    if cli.clients == 0 || cli.templates == 0 {
+        return Err("Number of clients and templates must be positive".into());
+    }
+
+    let target: Arc<dyn BenchTarget> = match &cli.target {
+        Target::Server { server_url } => Arc::new(ServerTarget::new(server_url.clone())),
+        Target::Embedded(args) => Arc::new(EmbeddedTarget::start(args).await?),
+    };
+
+    let report = run_load(&cli, target.clone()).await;
+    target.cleanup().await?;
+
+    print_report(&report?, cli.output)
+}
+
+struct LoadSettings {
+    distribution: Distribution,
+    hold: Duration,
+    think: Duration,
+    retry_delay: Duration,
+    deadline: Instant,
+}
+
+#[derive(Default)]
+struct Recorder {
+    lease_waits: Latencies,
+    recreates: Latencies,
+    leases: usize,
+    failed_leases: usize,
+    failed_releases: usize,
+    last_error: Option<String>,
+    release_instants: HashMap<(TemplateHash, TestDbId), Instant>,
+}
+
+impl Recorder {
+    fn record_error(&mut self, err: BoxDynError) {
+        self.last_error = Some(err.to_string());
+    }
+
+    // A test db is recreated once it's seen ready or it's leased again, whichever is observed first
+    fn record_availability(&mut self, template_hash: TemplateHash, test_db_id: TestDbId) {
+        if let Some(release_instant) = self.release_instants.remove(&(template_hash, test_db_id)) {
+            self.recreates.add(release_instant.elapsed());
+        }
+    }
+}
+
+async fn run_load(cli: &Cli, target: Arc<dyn BenchTarget>) -> Result<BenchReport, BoxDynError> {
+    let template_hashes: Vec<TemplateHash> = (0..cli.templates)
+        .map(|_| TemplateHash::new(rand::random()))
+        .collect();
+
+    let mut template_initializations = Latencies::default();
+    for template_hash in template_hashes.iter().copied() {
+        let started_at = Instant::now();
+        target.initialize_template(template_hash).await?;
+        template_initializations.add(started_at.elapsed());
+    }
+
+    wait_for_filled_pools(target.as_ref(), &template_hashes).await?;
+
+    let recorder = Arc::new(Mutex::new(Recorder::default()));
+    let poll_interval = Duration::from_millis(cli.poll_interval_ms.max(1));
+    let is_sampling_stopped = Arc::new(AtomicBool::new(false));
+
+    let sampler = tokio::spawn(sample_test_db_phases(
+        target.clone(),
+        template_hashes.clone(),
+        poll_interval,
+        recorder.clone(),
+        is_sampling_stopped.clone(),
+    ));
+
+    let load_started_at = Instant::now();
+    let settings = Arc::new(LoadSettings {
+        distribution: cli.distribution,
+        hold: Duration::from_millis(cli.hold_ms),
+        think: Duration::from_millis(cli.think_ms),
+        retry_delay: poll_interval,
+        deadline: load_started_at + Duration::from_secs(cli.duration_s),
+    });
+
+    let mut clients = JoinSet::new();
+    for client_index in 0..cli.clients {
+        clients.spawn(simulate_client(
+            target.clone(),
+            template_hashes[client_index % template_hashes.len()],
+            settings.clone(),
+            recorder.clone(),
+        ));
+    }
+    clients.join_all().await;
+
+    let load_duration = load_started_at.elapsed();
+
+    // Test dbs released in the end are still recreated, so they are awaited to not skew the latency
+    let draining_deadline = Instant::now() + RECREATE_DRAINING_TIMEOUT;
+    while Instant::now() < draining_deadline
+        && !recorder.lock().unwrap().release_instants.is_empty()
+    {
+        sleep(poll_interval).await;
+    }
+
+    is_sampling_stopped.store(true, Ordering::Relaxed);
+    sampler.await?;
+
+    let recorder = Arc::into_inner(recorder)
+        .ok_or("Recorder is still shared")?
+        .into_inner()
+        .map_err(|err| err.to_string())?;
+
+    Ok(BenchReport {
+        clients: cli.clients,
+        templates: cli.templates,
+        duration_ms: load_duration.as_millis() as u64,
+        leases: recorder.leases,
+        failed_leases: recorder.failed_leases,
+        failed_releases: recorder.failed_releases,
+        throughput_per_s: recorder.leases as f64 / load_duration.as_secs_f64(),
+        template_initialization: template_initializations.summary(),
+        lease_wait: recorder.lease_waits.summary(),
+        recreate: recorder.recreates.summary(),
+        unfinished_recreates: recorder.release_instants.len(),
+        last_error: recorder.last_error,
+    })
+}
+
+// Test dbs of the pools are created right after initialization,
+// the load is started afterwards so that the creation isn't measured as lease wait
+async fn wait_for_filled_pools(
+    target: &dyn BenchTarget,
+    template_hashes: &[TemplateHash],
+) -> Result<(), BoxDynError> {
+    let deadline = Instant::now() + POOL_FILLING_TIMEOUT;
+
+    for template_hash in template_hashes.iter().copied() {
+        loop {
+            let test_db_phases = target.get_test_db_phases(template_hash).await?;
+
+            if test_db_phases
+                .iter()
+                .all(|(_, phase)| *phase != TestDbPhase::Creating)
+            {
+                break;
+            }
+
+            if Instant::now() >= deadline {
+                return Err(
+                    format!("Pool of template {template_hash} wasn't filled in time").into(),
+                );
+            }
+
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    Ok(())
+}
+
+async fn simulate_client(
+    target: Arc<dyn BenchTarget>,
+    template_hash: TemplateHash,
+    settings: Arc<LoadSettings>,
+    recorder: Arc<Mutex<Recorder>>,
+) {
+    while Instant::now() < settings.deadline {
+        let hold = settings.distribution.sample(settings.hold);
+        let lease_started_at = Instant::now();
+
+        let test_db_id = match target
+            .get_test_db(template_hash, hold + USAGE_DURATION_MARGIN)
+            .await
+        {
+            Ok(test_db_id) => test_db_id,
+            Err(err) => {
+                {
+                    let mut recorder = recorder.lock().unwrap();
+                    recorder.failed_leases += 1;
+                    recorder.record_error(err);
+                }
+
+                sleep(settings.retry_delay).await;
+                continue;
+            }
+        };
+
+        {
+            let mut recorder = recorder.lock().unwrap();
+            recorder.leases += 1;
+            recorder.lease_waits.add(lease_started_at.elapsed());
+            recorder.record_availability(template_hash, test_db_id);
+        }
+
+        sleep_until((Instant::now() + hold).min(settings.deadline)).await;
+
+        // Recorded before the release, since the test db may be recreated before the response
+        recorder
+            .lock()
+            .unwrap()
+            .release_instants
+            .insert((template_hash, test_db_id), Instant::now());
+
+        if let Err(err) = target.finish_test_db_usage(template_hash, test_db_id).await {
+            let mut recorder = recorder.lock().unwrap();
+            recorder.failed_releases += 1;
+            recorder
+                .release_instants
+                .remove(&(template_hash, test_db_id));
+            recorder.record_error(err);
+        }
+
+        let think = settings.distribution.sample(settings.think);
+        sleep_until((Instant::now() + think).min(settings.deadline)).await;
+    }
+}
+
+async fn sample_test_db_phases(
+    target: Arc<dyn BenchTarget>,
+    template_hashes: Vec<TemplateHash>,
+    poll_interval: Duration,
+    recorder: Arc<Mutex<Recorder>>,
+    is_stopped: Arc<AtomicBool>,
+) {
+    while !is_stopped.load(Ordering::Relaxed) {
+        for template_hash in template_hashes.iter().copied() {
+            // Failed polls only make the latency less precise, leases report the errors
+            let Ok(test_db_phases) = target.get_test_db_phases(template_hash).await else {
+                continue;
+            };
+
+            let mut recorder = recorder.lock().unwrap();
+            for (test_db_id, phase) in test_db_phases {
+                if phase == TestDbPhase::Ready {
+                    recorder.record_availability(template_hash, test_db_id);
+                }
+            }
+        }
+
+        sleep(poll_interval).await;
+    }
+}
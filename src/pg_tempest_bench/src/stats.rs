@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+#[derive(Default)]
+pub struct Latencies {
+    samples: Vec<Duration>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencySummary {
+    pub samples: usize,
+    pub p50_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+}
+
+impl Latencies {
+    pub fn add(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    pub fn summary(mut self) -> LatencySummary {
+        self.samples.sort_unstable();
+
+        LatencySummary {
+            samples: self.samples.len(),
+            p50_ms: percentile(&self.samples, 50.0).map(as_ms),
+            p99_ms: percentile(&self.samples, 99.0).map(as_ms),
+            max_ms: self.samples.last().copied().map(as_ms),
+        }
+    }
+}
+
+// Nearest-rank percentile of sorted samples
+fn percentile(sorted_samples: &[Duration], percent: f64) -> Option<Duration> {
+    if sorted_samples.is_empty() {
+        return None;
+    }
+
+    let rank = (percent / 100.0 * sorted_samples.len() as f64).ceil() as usize;
+
+    Some(sorted_samples[rank.clamp(1, sorted_samples.len()) - 1])
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::stats::Latencies;
+
+    #[test]
+    fn summary_uses_nearest_rank_percentiles() {
+        let mut latencies = Latencies::default();
+        for ms in (1..=200).rev() {
+            latencies.add(Duration::from_millis(ms));
+        }
+
+        let summary = latencies.summary();
+
+        assert_eq!(summary.samples, 200);
+        assert_eq!(summary.p50_ms, Some(100.0));
+        assert_eq!(summary.p99_ms, Some(198.0));
+        assert_eq!(summary.max_ms, Some(200.0));
+    }
+
+    #[test]
+    fn summary_of_no_samples_is_empty() {
+        let summary = Latencies::default().summary();
+
+        assert_eq!(summary.samples, 0);
+        assert_eq!(summary.p50_ms, None);
+        assert_eq!(summary.p99_ms, None);
+        assert_eq!(summary.max_ms, None);
+    }
+}
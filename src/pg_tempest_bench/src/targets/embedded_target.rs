@@ -0,0 +1,189 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use pg_tempest_core::{
+    PgTempestCore,
+    configs::{
+        db_pool_configs::DbPoolConfigs,
+        dbms_configs::{DbmsConfigs, InnerDbmsConfigs, OuterDbmsConfigs},
+        template_initialization_configs::TemplateInitializationConfigs,
+        templates_configs::{TemplateCacheConfigs, TemplateImportConfigs, TemplatesConfigs},
+    },
+    features::{
+        templates::start_template_initialization::StartTemplateInitializationResult,
+        test_dbs::finish_test_db_usage::TestDbUsageOutcome,
+    },
+    metadata::template_metadata::TestDbState,
+    models::{
+        db_settings::DbSettings,
+        value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    },
+    pg_client::PgClient,
+    utils::errors::BoxDynError,
+};
+use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use pg_tempest_testkit::fake_pg_client::FakePgClient;
+
+use crate::{
+    cli::EmbeddedArgs,
+    targets::{BenchTarget, TestDbPhase},
+};
+
+const INITIALIZATION_DURATION: Duration = Duration::from_secs(60);
+
+pub struct EmbeddedTarget {
+    tempest_core: Arc<PgTempestCore>,
+}
+
+impl EmbeddedTarget {
+    pub async fn start(args: &EmbeddedArgs) -> Result<EmbeddedTarget, BoxDynError> {
+        let dbms_configs = Arc::new(DbmsConfigs {
+            inner: InnerDbmsConfigs {
+                host: args.pg_host.as_str().into(),
+                port: args.pg_port,
+            },
+            outer: OuterDbmsConfigs {
+                host: None,
+                port: None,
+                parameters: Default::default(),
+            },
+            profiles: Default::default(),
+            database: args.pg_database.as_str().into(),
+            user: args.pg_user.as_str().into(),
+            password: args.pg_password.as_str().into(),
+            ssl: Default::default(),
+        });
+
+        let pg_client: Arc<dyn PgClient> = match args.fake_pg_latency_ms {
+            Some(latency_ms) => {
+                let fake_pg_client = FakePgClient::new();
+                fake_pg_client.set_delay(Duration::from_millis(latency_ms));
+                Arc::new(fake_pg_client)
+            }
+            None => Arc::new(PgClientImpl::new(dbms_configs.clone())),
+        };
+
+        let tempest_core = PgTempestCore::start(
+            pg_client,
+            dbms_configs,
+            Arc::new(DbPoolConfigs {
+                min_size: args.min_size,
+                creation_retries_delay_in_ms: args.creation_retries_delay_ms,
+                reuse: Default::default(),
+                recycling: Default::default(),
+            }),
+            Arc::new(TemplatesConfigs {
+                initialization: Arc::new(TemplateInitializationConfigs {
+                    long_polling_timeout_ms: 10_000,
+                    max_deadline_handling_delay_ms: 1000,
+                }),
+                parent_template_db_name: None,
+                import: TemplateImportConfigs::default(),
+                cache: TemplateCacheConfigs::default(),
+            }),
+        )
+        .await?;
+
+        Ok(EmbeddedTarget { tempest_core })
+    }
+}
+
+#[async_trait]
+impl BenchTarget for EmbeddedTarget {
+    async fn initialize_template(&self, template_hash: TemplateHash) -> Result<(), BoxDynError> {
+        let result = self
+            .tempest_core
+            .clone()
+            .start_template_initialization(
+                template_hash,
+                INITIALIZATION_DURATION,
+                None,
+                None,
+                DbSettings::new(),
+                Vec::new(),
+            )
+            .await?;
+
+        if !matches!(
+            result,
+            StartTemplateInitializationResult::InitializationWasStarted { .. }
+        ) {
+            return Err(
+                format!("Initialization of template {template_hash} wasn't started").into(),
+            );
+        }
+
+        self.tempest_core
+            .clone()
+            .finish_template_initialization(template_hash)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(())
+    }
+
+    async fn get_test_db(
+        &self,
+        template_hash: TemplateHash,
+        usage_duration: Duration,
+    ) -> Result<TestDbId, BoxDynError> {
+        let result = self
+            .tempest_core
+            .clone()
+            .get_test_db(template_hash, usage_duration, None)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(result.test_db_id)
+    }
+
+    async fn finish_test_db_usage(
+        &self,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+    ) -> Result<(), BoxDynError> {
+        self.tempest_core
+            .clone()
+            .finish_test_db_usage(
+                template_hash,
+                test_db_id,
+                TestDbUsageOutcome::Succeeded,
+                None,
+            )
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(())
+    }
+
+    async fn get_test_db_phases(
+        &self,
+        template_hash: TemplateHash,
+    ) -> Result<Vec<(TestDbId, TestDbPhase)>, BoxDynError> {
+        let test_db_phases = self
+            .tempest_core
+            .get_template(template_hash)
+            .await
+            .into_iter()
+            .flat_map(|template| template.test_dbs)
+            .map(|test_db| {
+                let phase = match test_db.state {
+                    TestDbState::Creating => TestDbPhase::Creating,
+                    TestDbState::Ready => TestDbPhase::Ready,
+                    TestDbState::Corrupted => TestDbPhase::Corrupted,
+                    TestDbState::InUse { .. } => TestDbPhase::InUse,
+                    TestDbState::Retained { .. } => TestDbPhase::Retained,
+                };
+                (test_db.id, phase)
+            })
+            .collect();
+
+        Ok(test_db_phases)
+    }
+
+    async fn cleanup(&self) -> Result<(), BoxDynError> {
+        self.tempest_core.purge_templates(false).await?;
+
+        Ok(())
+    }
+}
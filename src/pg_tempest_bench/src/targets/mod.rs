@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use pg_tempest_core::{
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    utils::errors::BoxDynError,
+};
+
+pub mod embedded_target;
+pub mod server_target;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TestDbPhase {
+    Creating,
+    Ready,
+    Corrupted,
+    InUse,
+    Retained,
+}
+
+// Operations the simulated clients perform, implemented over the http api or an embedded core
+#[async_trait]
+pub trait BenchTarget: Send + Sync {
+    async fn initialize_template(&self, template_hash: TemplateHash) -> Result<(), BoxDynError>;
+
+    async fn get_test_db(
+        &self,
+        template_hash: TemplateHash,
+        usage_duration: Duration,
+    ) -> Result<TestDbId, BoxDynError>;
+
+    async fn finish_test_db_usage(
+        &self,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+    ) -> Result<(), BoxDynError>;
+
+    async fn get_test_db_phases(
+        &self,
+        template_hash: TemplateHash,
+    ) -> Result<Vec<(TestDbId, TestDbPhase)>, BoxDynError>;
+
+    async fn cleanup(&self) -> Result<(), BoxDynError>;
+}
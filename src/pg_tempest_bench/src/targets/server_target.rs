@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use pg_tempest_client::{
+    client::PgTempestClient,
+    routes::{
+        templates::{
+            finish_template_initialization::{
+                FinishTemplateInitializationRequestBody, FinishTemplateInitializationResponseBody,
+            },
+            get_templates::{GetTemplatesResponseBody, TestDbStateDto},
+            start_template_initialization::{
+                StartTemplateInitializationRequestBody, StartTemplateInitializationResponseBody,
+            },
+        },
+        test_dbs::{
+            finish_test_db_usage::{
+                FinishTestDbUsageRequestBody, FinishTestDbUsageResponseBody, TestDbUsageOutcomeDto,
+            },
+            get_test_db::{GetTestDbRequestBody, GetTestDbResponseBody},
+        },
+    },
+};
+use pg_tempest_core::{
+    models::{
+        db_settings::DbSettings,
+        value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    },
+    utils::errors::BoxDynError,
+};
+
+use crate::targets::{BenchTarget, TestDbPhase};
+
+const INITIALIZATION_DURATION_MS: u64 = 60_000;
+
+pub struct ServerTarget {
+    client: PgTempestClient,
+}
+
+impl ServerTarget {
+    pub fn new(server_url: String) -> ServerTarget {
+        ServerTarget {
+            client: PgTempestClient::new(server_url),
+        }
+    }
+}
+
+#[async_trait]
+impl BenchTarget for ServerTarget {
+    async fn initialize_template(&self, template_hash: TemplateHash) -> Result<(), BoxDynError> {
+        let response = self
+            .client
+            .start_template_initialization(&StartTemplateInitializationRequestBody {
+                template_hash,
+                initialization_duration_ms: INITIALIZATION_DURATION_MS,
+                parent_template_db_name: None,
+                network_profile: None,
+                settings: DbSettings::new(),
+                extensions: Vec::new(),
+            })
+            .await?;
+
+        match response {
+            StartTemplateInitializationResponseBody::InitializationWasStarted { .. } => {}
+            response => return Err(format!("Unexpected response {response:?}").into()),
+        }
+
+        let response = self
+            .client
+            .finish_template_initialization(&FinishTemplateInitializationRequestBody {
+                template_hash,
+            })
+            .await?;
+
+        match response {
+            FinishTemplateInitializationResponseBody::InitializationIsFinished {} => Ok(()),
+            response => Err(format!("Unexpected response {response:?}").into()),
+        }
+    }
+
+    async fn get_test_db(
+        &self,
+        template_hash: TemplateHash,
+        usage_duration: Duration,
+    ) -> Result<TestDbId, BoxDynError> {
+        let response = self
+            .client
+            .get_test_db(&GetTestDbRequestBody {
+                template_hash,
+                usage_duration_ms: usage_duration.as_millis() as u64,
+                network_profile: None,
+            })
+            .await?;
+
+        match response {
+            GetTestDbResponseBody::TestDbWasCreated { test_db_id, .. } => Ok(test_db_id),
+            response => Err(format!("Unexpected response {response:?}").into()),
+        }
+    }
+
+    async fn finish_test_db_usage(
+        &self,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+    ) -> Result<(), BoxDynError> {
+        let response = self
+            .client
+            .finish_test_db_usage(&FinishTestDbUsageRequestBody {
+                template_hash,
+                test_db_id,
+                outcome: TestDbUsageOutcomeDto::Succeeded,
+                retain_for_ms: None,
+                network_profile: None,
+            })
+            .await?;
+
+        match response {
+            FinishTestDbUsageResponseBody::TestDbWasReleased {} => Ok(()),
+            response => Err(format!("Unexpected response {response:?}").into()),
+        }
+    }
+
+    async fn get_test_db_phases(
+        &self,
+        template_hash: TemplateHash,
+    ) -> Result<Vec<(TestDbId, TestDbPhase)>, BoxDynError> {
+        let GetTemplatesResponseBody::TemplatesWereFound { templates } =
+            self.client.get_templates().await?;
+
+        let test_db_phases = templates
+            .into_iter()
+            .filter(|template| template.template_hash == template_hash)
+            .flat_map(|template| template.test_dbs)
+            .map(|test_db| {
+                let phase = match test_db.state {
+                    TestDbStateDto::Creating {} => TestDbPhase::Creating,
+                    TestDbStateDto::Ready {} => TestDbPhase::Ready,
+                    TestDbStateDto::Corrupted {} => TestDbPhase::Corrupted,
+                    TestDbStateDto::InUse { .. } => TestDbPhase::InUse,
+                    TestDbStateDto::Retained { .. } => TestDbPhase::Retained,
+                };
+                (test_db.test_db_id, phase)
+            })
+            .collect();
+
+        Ok(test_db_phases)
+    }
+
+    async fn cleanup(&self) -> Result<(), BoxDynError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::path::PathBuf;
+
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use pg_tempest_core::models::value_types::{
+    checkpoint_id::CheckpointId, db_setting_name::DbSettingName, extension_name::ExtensionName,
+    pg_identifier::PgIdentifier, template_hash::TemplateHash, test_db_id::TestDbId,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "pg-tempest-cli",
+    version,
+    about = "Client of the pg-tempest HTTP API"
+)]
+pub struct Cli {
+    /// Address of the pg-tempest server
+    #[arg(
+        long,
+        env = "PG_TEMPEST_URL",
+        default_value = "http://localhost:8000",
+        global = true
+    )]
+    pub server_url: String,
+
+    /// Network profile of returned connection options
+    #[arg(long, env = "PG_TEMPEST_NETWORK_PROFILE", global = true)]
+    pub network_profile: Option<String>,
+
+    /// Admin token of the server, required by its admin routes, such as purge, template import and export
+    #[arg(
+        long,
+        env = "PG_TEMPEST_ADMIN_TOKEN",
+        global = true,
+        hide_env_values = true
+    )]
+    pub admin_token: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Manage initialization of a template
+    #[command(subcommand)]
+    Template(TemplateCommand),
+
+    /// Manage usage of a test db
+    #[command(subcommand)]
+    Db(DbCommand),
+
+    /// Inspect templates known to the server
+    #[command(subcommand)]
+    Templates(TemplatesCommand),
+
+    /// Run a command with a leased test db
+    Exec {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long, default_value_t = 60_000)]
+        usage_duration_ms: u64,
+        /// Retain the test db for the given time if the command fails
+        #[arg(long)]
+        retain_failed_for_ms: Option<u64>,
+        /// Command to run, passed after `--`
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+
+    /// Forget all templates and drop their dbs. Requires the admin token
+    Purge {
+        /// Confirm dropping of the dbs
+        #[arg(long)]
+        yes: bool,
+        /// Drop pg-tempest dbs of templates unknown to the server too,
+        /// including dbs of other servers sharing the dbms
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TemplateCommand {
+    Start {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long, default_value_t = 60_000)]
+        initialization_duration_ms: u64,
+        #[arg(long)]
+        parent_template_db_name: Option<PgIdentifier>,
+        /// Setting of the template db and its test dbs as NAME=VALUE, e.g. statement_timeout=5s.
+        /// Can be repeated
+        #[arg(long = "setting", value_parser = parse_db_setting)]
+        settings: Vec<(DbSettingName, String)>,
+        /// Extension created by the server in the template db, e.g. pgcrypto. Can be repeated
+        #[arg(long = "extension")]
+        extensions: Vec<ExtensionName>,
+    },
+    Finish {
+        #[arg(long)]
+        template_hash: TemplateHash,
+    },
+    Fail {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        reason: Option<String>,
+    },
+    Extend {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        additional_time_ms: u64,
+    },
+    /// Initialize the template from a SQL dump or a pg_dump archive on the server. Requires the admin token
+    #[command(group(ArgGroup::new("dump").required(true).args(["sql_file", "archive_path"])))]
+    Import {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        /// Local file with a plain SQL dump, uploaded to the server
+        #[arg(long)]
+        sql_file: Option<PathBuf>,
+        /// Path of the archive relative to the archive dir of the server
+        #[arg(long)]
+        archive_path: Option<PathBuf>,
+        #[arg(long, default_value_t = 600_000)]
+        initialization_duration_ms: u64,
+        #[arg(long)]
+        parent_template_db_name: Option<PgIdentifier>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DbCommand {
+    Get {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long, default_value_t = 60_000)]
+        usage_duration_ms: u64,
+    },
+    Extend {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+        #[arg(long)]
+        additional_time_ms: u64,
+    },
+    Finish {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+        #[arg(long, value_enum, default_value_t = TestDbUsageOutcome::Succeeded)]
+        outcome: TestDbUsageOutcome,
+        /// Retain the test db for the given time if the outcome is failed
+        #[arg(long)]
+        retain_for_ms: Option<u64>,
+    },
+    /// Drop a retained test db and return it to the pool
+    Drop {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+    },
+    /// Snapshot a leased test db. It must have no connections
+    Checkpoint {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+    },
+    /// Recreate a leased test db from its checkpoint
+    Restore {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+        #[arg(long)]
+        checkpoint_id: CheckpointId,
+    },
+    /// Copy a leased test db into a new finished template and finish the usage.
+    /// It must have no connections
+    Promote {
+        #[arg(long)]
+        template_hash: TemplateHash,
+        #[arg(long)]
+        test_db_id: TestDbId,
+        #[arg(long)]
+        new_template_hash: TemplateHash,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum TestDbUsageOutcome {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Subcommand)]
+pub enum TemplatesCommand {
+    #[command(alias = "list")]
+    Ls,
+}
+
+fn parse_db_setting(value: &str) -> Result<(DbSettingName, String), String> {
+    let (name, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Setting {value:?} is not in NAME=VALUE format"))?;
+    let name = name
+        .parse()
+        .map_err(|err| format!("Invalid setting name {name:?}: {err}"))?;
+
+    Ok((name, value.to_owned()))
+}
@@ -0,0 +1,191 @@
+use std::process::ExitCode;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_client::routes::test_dbs::{
+    create_test_db_checkpoint::{
+        CreateTestDbCheckpointRequestBody, CreateTestDbCheckpointResponseBody,
+    },
+    drop_retained_test_db::{DropRetainedTestDbRequestBody, DropRetainedTestDbResponseBody},
+    extend_test_db_usage::{ExtendTestDbUsageRequestBody, ExtendTestDbUsageResponseBody},
+    finish_test_db_usage::{
+        FinishTestDbUsageRequestBody, FinishTestDbUsageResponseBody, TestDbUsageOutcomeDto,
+    },
+    get_test_db::{GetTestDbRequestBody, GetTestDbResponseBody},
+    promote_test_db::{PromoteTestDbRequestBody, PromoteTestDbResponseBody},
+    restore_test_db_checkpoint::{
+        RestoreTestDbCheckpointRequestBody, RestoreTestDbCheckpointResponseBody,
+    },
+};
+use pg_tempest_core::utils::errors::BoxDynError;
+
+use crate::cli::{DbCommand, OutputFormat, TestDbUsageOutcome};
+use crate::output::{CommandOutput, print_output};
+
+pub async fn run(
+    client: &PgTempestClient,
+    command: DbCommand,
+    output_format: OutputFormat,
+) -> Result<ExitCode, BoxDynError> {
+    match command {
+        DbCommand::Get {
+            template_hash,
+            usage_duration_ms,
+        } => {
+            let response_body = client
+                .get_test_db(&GetTestDbRequestBody {
+                    template_hash,
+                    usage_duration_ms,
+                    network_profile: client.network_profile().map(Into::into),
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Extend {
+            template_hash,
+            test_db_id,
+            additional_time_ms,
+        } => {
+            let response_body = client
+                .extend_test_db_usage(&ExtendTestDbUsageRequestBody {
+                    template_hash,
+                    test_db_id,
+                    additional_time_ms,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Finish {
+            template_hash,
+            test_db_id,
+            outcome,
+            retain_for_ms,
+        } => {
+            let outcome = match outcome {
+                TestDbUsageOutcome::Succeeded => TestDbUsageOutcomeDto::Succeeded,
+                TestDbUsageOutcome::Failed => TestDbUsageOutcomeDto::Failed,
+            };
+
+            let response_body = client
+                .finish_test_db_usage(&FinishTestDbUsageRequestBody {
+                    template_hash,
+                    test_db_id,
+                    outcome,
+                    retain_for_ms,
+                    network_profile: client.network_profile().map(Into::into),
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Drop {
+            template_hash,
+            test_db_id,
+        } => {
+            let response_body = client
+                .drop_retained_test_db(&DropRetainedTestDbRequestBody {
+                    template_hash,
+                    test_db_id,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Checkpoint {
+            template_hash,
+            test_db_id,
+        } => {
+            let response_body = client
+                .create_test_db_checkpoint(&CreateTestDbCheckpointRequestBody {
+                    template_hash,
+                    test_db_id,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Restore {
+            template_hash,
+            test_db_id,
+            checkpoint_id,
+        } => {
+            let response_body = client
+                .restore_test_db_checkpoint(&RestoreTestDbCheckpointRequestBody {
+                    template_hash,
+                    test_db_id,
+                    checkpoint_id,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        DbCommand::Promote {
+            template_hash,
+            test_db_id,
+            new_template_hash,
+        } => {
+            let response_body = client
+                .promote_test_db(&PromoteTestDbRequestBody {
+                    template_hash,
+                    test_db_id,
+                    new_template_hash,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+    }
+}
+
+impl CommandOutput for GetTestDbResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(self, GetTestDbResponseBody::TestDbWasCreated { .. })
+    }
+}
+
+impl CommandOutput for ExtendTestDbUsageResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(self, ExtendTestDbUsageResponseBody::UsageWasExtended { .. })
+    }
+}
+
+impl CommandOutput for FinishTestDbUsageResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            FinishTestDbUsageResponseBody::TestDbWasReleased {}
+                | FinishTestDbUsageResponseBody::TestDbWasRetained { .. }
+        )
+    }
+}
+
+impl CommandOutput for DropRetainedTestDbResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(self, DropRetainedTestDbResponseBody::TestDbWasDropped {})
+    }
+}
+
+impl CommandOutput for CreateTestDbCheckpointResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            CreateTestDbCheckpointResponseBody::CheckpointWasCreated { .. }
+        )
+    }
+}
+
+impl CommandOutput for RestoreTestDbCheckpointResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            RestoreTestDbCheckpointResponseBody::TestDbWasRestored {}
+        )
+    }
+}
+
+impl CommandOutput for PromoteTestDbResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(self, PromoteTestDbResponseBody::TestDbWasPromoted {})
+    }
+}
@@ -0,0 +1,86 @@
+use std::process::{ExitCode, ExitStatus};
+use std::time::Duration;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_client::routes::test_dbs::finish_test_db_usage::FinishTestDbUsageResponseBody;
+use pg_tempest_core::models::value_types::template_hash::TemplateHash;
+use pg_tempest_core::utils::errors::BoxDynError;
+use tokio::process::Command;
+
+pub async fn run(
+    client: &PgTempestClient,
+    template_hash: TemplateHash,
+    usage_duration_ms: u64,
+    command: Vec<String>,
+) -> Result<ExitCode, BoxDynError> {
+    let Some((program, args)) = command.split_first() else {
+        return Err("Command is not specified".into());
+    };
+
+    let lease = client
+        .lease_test_db(template_hash, Duration::from_millis(usage_duration_ms))
+        .await?;
+
+    let db_connection_options = lease.db_connection_options();
+
+    let child = Command::new(program)
+        .args(args)
+        .env("DATABASE_URL", db_connection_options.to_url())
+        .env("PGHOST", db_connection_options.host.as_ref())
+        .env("PGPORT", db_connection_options.port.to_string())
+        .env("PGDATABASE", db_connection_options.database.to_string())
+        .env("PGUSER", db_connection_options.username.as_ref())
+        .env("PGPASSWORD", db_connection_options.password.as_ref())
+        .spawn();
+
+    let status = match child {
+        Ok(mut child) => wait_for_child(&mut child).await,
+        Err(err) => Err(format!("Failed to run {program}: {err}").into()),
+    };
+
+    // The test db of a failed command is released too, unless failure retention is set
+    let finish_result = match &status {
+        Ok(status) if status.success() => lease.finish().await,
+        _ => lease.fail().await,
+    };
+
+    match finish_result {
+        Ok(FinishTestDbUsageResponseBody::TestDbWasReleased {}) => {}
+        Ok(FinishTestDbUsageResponseBody::TestDbWasRetained {
+            db_connection_options,
+            retention_deadline,
+        }) => eprintln!(
+            "Test db is retained until {retention_deadline}: {}",
+            db_connection_options.to_url()
+        ),
+        Ok(response_body) => eprintln!("Test db was not released: {response_body:?}"),
+        Err(err) => eprintln!("Failed to release test db: {err}"),
+    }
+
+    Ok(to_exit_code(status?))
+}
+
+// Ctrl+C is delivered to the whole process group, so the child handles it by itself
+// and the lease is finished after the child exits
+async fn wait_for_child(child: &mut tokio::process::Child) -> Result<ExitStatus, BoxDynError> {
+    loop {
+        tokio::select! {
+            status = child.wait() => return Ok(status?),
+            _ = tokio::signal::ctrl_c() => {}
+        }
+    }
+}
+
+fn to_exit_code(status: ExitStatus) -> ExitCode {
+    if let Some(code) = status.code() {
+        return ExitCode::from(code as u8);
+    }
+
+    // Mimic shells, which report a child killed by a signal as 128 + signal number
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return ExitCode::from(128u8.wrapping_add(signal as u8));
+    }
+
+    ExitCode::FAILURE
+}
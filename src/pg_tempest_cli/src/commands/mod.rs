@@ -0,0 +1,42 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_core::utils::errors::BoxDynError;
+
+use crate::cli::{Cli, Command};
+
+mod db;
+mod exec;
+mod purge;
+mod template;
+mod templates;
+
+pub async fn run_command(cli: Cli) -> Result<ExitCode, BoxDynError> {
+    let mut client = PgTempestClient::new(cli.server_url);
+    if let Some(network_profile) = cli.network_profile {
+        client = client.with_network_profile(network_profile);
+    }
+    if let Some(admin_token) = cli.admin_token {
+        client = client.with_admin_token(admin_token);
+    }
+
+    match cli.command {
+        Command::Template(command) => template::run(&client, command, cli.output).await,
+        Command::Db(command) => db::run(&client, command, cli.output).await,
+        Command::Templates(command) => templates::run(&client, command, cli.output).await,
+        Command::Exec {
+            template_hash,
+            usage_duration_ms,
+            retain_failed_for_ms,
+            command,
+        } => {
+            if let Some(retain_failed_for_ms) = retain_failed_for_ms {
+                client = client.with_failure_retention(Duration::from_millis(retain_failed_for_ms));
+            }
+
+            exec::run(&client, template_hash, usage_duration_ms, command).await
+        }
+        Command::Purge { yes, all } => purge::run(&client, yes, all, cli.output).await,
+    }
+}
@@ -0,0 +1,29 @@
+use std::process::ExitCode;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_client::routes::admin::purge_templates::PurgedTemplatesDto;
+use pg_tempest_core::utils::errors::BoxDynError;
+
+use crate::cli::OutputFormat;
+use crate::output::{CommandOutput, print_output};
+
+pub async fn run(
+    client: &PgTempestClient,
+    yes: bool,
+    all: bool,
+    output_format: OutputFormat,
+) -> Result<ExitCode, BoxDynError> {
+    if !yes {
+        return Err("Purge drops pg-tempest databases on the server. Pass --yes to confirm".into());
+    }
+
+    let response_body = client.purge_templates(all).await?;
+
+    print_output(&response_body, output_format)
+}
+
+impl CommandOutput for PurgedTemplatesDto {
+    fn is_success(&self) -> bool {
+        self.failed_dbs.is_empty()
+    }
+}
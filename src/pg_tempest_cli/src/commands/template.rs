@@ -0,0 +1,169 @@
+use std::process::ExitCode;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_client::dtos::template_dump_dto::TemplateDumpDto;
+use pg_tempest_client::routes::templates::{
+    extend_template_initialization::{
+        ExtendTemplateInitializationRequestBody, ExtendTemplateInitializationResponseBody,
+    },
+    fail_template_initialization::{
+        FailTemplateInitializationRequestBody, FailTemplateInitializationResponseBody,
+    },
+    finish_template_initialization::{
+        FinishTemplateInitializationRequestBody, FinishTemplateInitializationResponseBody,
+    },
+    import_template::{ImportTemplateRequestBody, ImportTemplateResponseBody},
+    start_template_initialization::{
+        StartTemplateInitializationRequestBody, StartTemplateInitializationResponseBody,
+    },
+};
+use pg_tempest_core::utils::errors::BoxDynError;
+
+use crate::cli::{OutputFormat, TemplateCommand};
+use crate::output::{CommandOutput, print_output};
+
+pub async fn run(
+    client: &PgTempestClient,
+    command: TemplateCommand,
+    output_format: OutputFormat,
+) -> Result<ExitCode, BoxDynError> {
+    match command {
+        TemplateCommand::Start {
+            template_hash,
+            initialization_duration_ms,
+            parent_template_db_name,
+            settings,
+            extensions,
+        } => {
+            let response_body = client
+                .start_template_initialization(&StartTemplateInitializationRequestBody {
+                    template_hash,
+                    initialization_duration_ms,
+                    parent_template_db_name,
+                    network_profile: client.network_profile().map(Into::into),
+                    settings: settings
+                        .into_iter()
+                        .map(|(name, value)| (name, value.into()))
+                        .collect(),
+                    extensions,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        TemplateCommand::Finish { template_hash } => {
+            let response_body = client
+                .finish_template_initialization(&FinishTemplateInitializationRequestBody {
+                    template_hash,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        TemplateCommand::Fail {
+            template_hash,
+            reason,
+        } => {
+            let response_body = client
+                .fail_template_initialization(&FailTemplateInitializationRequestBody {
+                    template_hash,
+                    reason: reason.map(Into::into),
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        TemplateCommand::Extend {
+            template_hash,
+            additional_time_ms,
+        } => {
+            let response_body = client
+                .extend_template_initialization(&ExtendTemplateInitializationRequestBody {
+                    template_hash,
+                    additional_time_ms,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+        TemplateCommand::Import {
+            template_hash,
+            sql_file,
+            archive_path,
+            initialization_duration_ms,
+            parent_template_db_name,
+        } => {
+            let dump = match (sql_file, archive_path) {
+                (Some(sql_file), _) => {
+                    let script = tokio::fs::read_to_string(&sql_file)
+                        .await
+                        .map_err(|err| format!("Failed to read {}: {err}", sql_file.display()))?;
+
+                    TemplateDumpDto::Sql {
+                        script: script.into(),
+                    }
+                }
+                (None, Some(path)) => TemplateDumpDto::Archive { path },
+                (None, None) => return Err("Either sql file or archive path is required".into()),
+            };
+
+            let response_body = client
+                .import_template(&ImportTemplateRequestBody {
+                    template_hash,
+                    dump,
+                    initialization_duration_ms,
+                    parent_template_db_name,
+                })
+                .await?;
+
+            print_output(&response_body, output_format)
+        }
+    }
+}
+
+impl CommandOutput for StartTemplateInitializationResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            StartTemplateInitializationResponseBody::InitializationWasStarted { .. }
+                | StartTemplateInitializationResponseBody::InitializationIsFinished {}
+        )
+    }
+}
+
+impl CommandOutput for FinishTemplateInitializationResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            FinishTemplateInitializationResponseBody::InitializationIsFinished {}
+        )
+    }
+}
+
+impl CommandOutput for FailTemplateInitializationResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            FailTemplateInitializationResponseBody::InitializationIsFailed {}
+        )
+    }
+}
+
+impl CommandOutput for ExtendTemplateInitializationResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            ExtendTemplateInitializationResponseBody::InitializationWasExtended { .. }
+        )
+    }
+}
+
+impl CommandOutput for ImportTemplateResponseBody {
+    fn is_success(&self) -> bool {
+        matches!(
+            self,
+            ImportTemplateResponseBody::TemplateWasImported {}
+                | ImportTemplateResponseBody::InitializationIsFinished {}
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use std::process::ExitCode;
+
+use pg_tempest_client::client::PgTempestClient;
+use pg_tempest_client::routes::templates::get_templates::{
+    GetTemplatesResponseBody, TemplateInitializationStateDto, TestDbStateDto,
+};
+use pg_tempest_core::utils::errors::BoxDynError;
+
+use crate::cli::{OutputFormat, TemplatesCommand};
+use crate::output::{CommandOutput, print_output};
+use crate::table::Table;
+
+pub async fn run(
+    client: &PgTempestClient,
+    command: TemplatesCommand,
+    output_format: OutputFormat,
+) -> Result<ExitCode, BoxDynError> {
+    match command {
+        TemplatesCommand::Ls => {
+            let response_body = client.get_templates().await?;
+
+            print_output(&response_body, output_format)
+        }
+    }
+}
+
+impl CommandOutput for GetTemplatesResponseBody {
+    fn is_success(&self) -> bool {
+        true
+    }
+
+    fn to_table(&self) -> Result<Table, BoxDynError> {
+        let GetTemplatesResponseBody::TemplatesWereFound { templates } = self;
+
+        let mut table = Table::new(&[
+            "template hash",
+            "initialization",
+            "test dbs",
+            "ready",
+            "in use",
+            "creating",
+            "corrupted",
+            "retained",
+        ]);
+
+        for template in templates.iter() {
+            let initialization = match &template.initialization_state {
+                TemplateInitializationStateDto::Creating {} => "creating".to_string(),
+                TemplateInitializationStateDto::Created {} => "created".to_string(),
+                TemplateInitializationStateDto::InProgress {
+                    initialization_deadline,
+                } => format!("in progress until {initialization_deadline}"),
+                TemplateInitializationStateDto::Finished {} => "finished".to_string(),
+                TemplateInitializationStateDto::Failed {
+                    reason: Some(reason),
+                } => {
+                    format!("failed: {reason}")
+                }
+                TemplateInitializationStateDto::Failed { reason: None } => "failed".to_string(),
+            };
+
+            let count_test_dbs = |is_matching: fn(&TestDbStateDto) -> bool| {
+                template
+                    .test_dbs
+                    .iter()
+                    .filter(|test_db| is_matching(&test_db.state))
+                    .count()
+                    .to_string()
+            };
+
+            table.add_row(vec![
+                template.template_hash.to_string(),
+                initialization,
+                template.test_dbs.len().to_string(),
+                count_test_dbs(|state| matches!(state, TestDbStateDto::Ready {})),
+                count_test_dbs(|state| matches!(state, TestDbStateDto::InUse { .. })),
+                count_test_dbs(|state| matches!(state, TestDbStateDto::Creating {})),
+                count_test_dbs(|state| matches!(state, TestDbStateDto::Corrupted {})),
+                count_test_dbs(|state| matches!(state, TestDbStateDto::Retained { .. })),
+            ]);
+        }
+
+        Ok(table)
+    }
+}
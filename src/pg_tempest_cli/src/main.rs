@@ -0,0 +1,23 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::{cli::Cli, commands::run_command};
+
+mod cli;
+mod commands;
+mod output;
+mod table;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run_command(cli).await {
+        Ok(exit_code) => exit_code,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
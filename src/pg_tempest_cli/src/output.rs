@@ -0,0 +1,80 @@
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use pg_tempest_core::utils::errors::BoxDynError;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{cli::OutputFormat, table::Table};
+
+pub trait CommandOutput: Serialize {
+    fn is_success(&self) -> bool;
+
+    // Response bodies are externally tagged enums,
+    // so the variant is printed as a result and its fields are flattened
+    fn to_table(&self) -> Result<Table, BoxDynError> {
+        let mut table = Table::new(&["field", "value"]);
+
+        match serde_json::to_value(self)? {
+            Value::Object(object) => {
+                for (variant, fields) in object {
+                    table.add_row(vec!["result".into(), variant]);
+                    add_json_rows(&mut table, "", fields);
+                }
+            }
+            value => table.add_row(vec!["result".into(), value.to_string()]),
+        }
+
+        Ok(table)
+    }
+}
+
+pub fn print_output(
+    output: &impl CommandOutput,
+    output_format: OutputFormat,
+) -> Result<ExitCode, BoxDynError> {
+    let text = match output_format {
+        OutputFormat::Table => output.to_table()?.to_string(),
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(output)?),
+    };
+
+    let mut stdout = io::stdout().lock();
+    match stdout
+        .write_all(text.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        // The reader has closed the pipe, e.g. `pg-tempest-cli templates ls | head`
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        result => result?,
+    }
+
+    if output.is_success() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn add_json_rows(table: &mut Table, prefix: &str, value: Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                add_json_rows(table, &key, value);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                add_json_rows(table, &format!("{prefix}[{index}]"), value);
+            }
+        }
+        Value::String(value) => table.add_row(vec![prefix.into(), value]),
+        Value::Null => table.add_row(vec![prefix.into(), String::new()]),
+        value => table.add_row(vec![prefix.into(), value.to_string()]),
+    }
+}
@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use derive_more::{Debug as DebugV2, Display};
+use reqwest::StatusCode;
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+use crate::dtos::api_error_dto::ApiErrorDto;
+
+#[derive(Clone)]
+pub struct PgTempestClient {
+    http_client: reqwest::Client,
+    base_url: Box<str>,
+    network_profile: Option<Box<str>>,
+    failure_retention: Option<Duration>,
+    admin_token: Option<Box<str>>,
+}
+
+impl PgTempestClient {
+    pub fn new(base_url: impl Into<Box<str>>) -> PgTempestClient {
+        PgTempestClient::with_http_client(reqwest::Client::new(), base_url)
+    }
+
+    pub fn with_http_client(
+        http_client: reqwest::Client,
+        base_url: impl Into<Box<str>>,
+    ) -> PgTempestClient {
+        let base_url: Box<str> = base_url.into();
+
+        PgTempestClient {
+            http_client,
+            base_url: base_url.trim_end_matches('/').into(),
+            network_profile: None,
+            failure_retention: None,
+            admin_token: None,
+        }
+    }
+
+    // Network profile of connection options returned to `initialize_template` and `lease_test_db`
+    pub fn with_network_profile(mut self, network_profile: impl Into<Box<str>>) -> PgTempestClient {
+        self.network_profile = Some(network_profile.into());
+        self
+    }
+
+    // Test dbs of leases finished as failed are retained for the duration instead of being recycled
+    pub fn with_failure_retention(mut self, failure_retention: Duration) -> PgTempestClient {
+        self.failure_retention = Some(failure_retention);
+        self
+    }
+
+    // Bearer token of admin routes, which are served only when the server has an admin token
+    pub fn with_admin_token(mut self, admin_token: impl Into<Box<str>>) -> PgTempestClient {
+        self.admin_token = Some(admin_token.into());
+        self
+    }
+
+    // Same client without connections pooled by the runtime of the original one
+    pub(crate) fn with_new_http_client(&self) -> PgTempestClient {
+        PgTempestClient {
+            http_client: reqwest::Client::new(),
+            ..self.clone()
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn network_profile(&self) -> Option<&str> {
+        self.network_profile.as_deref()
+    }
+
+    pub fn failure_retention(&self) -> Option<Duration> {
+        self.failure_retention
+    }
+
+    pub(crate) async fn post<TRequestBody, TResponseBody>(
+        &self,
+        path: &str,
+        request_body: &TRequestBody,
+    ) -> Result<TResponseBody, PgTempestClientError>
+    where
+        TRequestBody: Serialize,
+        TResponseBody: DeserializeOwned,
+    {
+        let request = self
+            .http_client
+            .post(format!("{}{path}", self.base_url))
+            .json(request_body);
+
+        self.send(request).await
+    }
+
+    pub(crate) async fn post_without_body<TResponseBody>(
+        &self,
+        path: &str,
+    ) -> Result<TResponseBody, PgTempestClientError>
+    where
+        TResponseBody: DeserializeOwned,
+    {
+        let request = self.http_client.post(format!("{}{path}", self.base_url));
+
+        self.send(request).await
+    }
+
+    pub(crate) async fn post_as_admin<TRequestBody, TResponseBody>(
+        &self,
+        path: &str,
+        request_body: &TRequestBody,
+    ) -> Result<TResponseBody, PgTempestClientError>
+    where
+        TRequestBody: Serialize,
+        TResponseBody: DeserializeOwned,
+    {
+        let admin_token = self
+            .admin_token
+            .as_deref()
+            .ok_or(PgTempestClientError::AdminTokenIsMissing)?;
+        let response = self
+            .http_client
+            .post(format!("{}{path}", self.base_url))
+            .bearer_auth(admin_token)
+            .json(request_body)
+            .send()
+            .await?;
+
+        // Rejected tokens are reported with an error object, other errors with the regular json body
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(api_error(response).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    pub(crate) async fn delete_as_admin<TResponseBody>(
+        &self,
+        path: &str,
+    ) -> Result<TResponseBody, PgTempestClientError>
+    where
+        TResponseBody: DeserializeOwned,
+    {
+        let admin_token = self
+            .admin_token
+            .as_deref()
+            .ok_or(PgTempestClientError::AdminTokenIsMissing)?;
+        let response = self
+            .http_client
+            .delete(format!("{}{path}", self.base_url))
+            .bearer_auth(admin_token)
+            .send()
+            .await?;
+
+        // Unlike the routes above, admin routes report errors with an error object
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    async fn send<TResponseBody>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<TResponseBody, PgTempestClientError>
+    where
+        TResponseBody: DeserializeOwned,
+    {
+        let response = request.send().await?;
+
+        // Routes report errors with non-success status codes and a regular json body,
+        // so the body is deserialized regardless of the status code
+        let response_body = response.json().await?;
+
+        Ok(response_body)
+    }
+}
+
+async fn api_error(response: reqwest::Response) -> PgTempestClientError {
+    let status_code = response.status().as_u16();
+
+    match response.json::<ApiErrorDto>().await {
+        Ok(error) => PgTempestClientError::Api {
+            status_code,
+            code: error.code,
+            message: error.message,
+        },
+        Err(err) => err.into(),
+    }
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("PgTempestClientError::{self:?}")]
+pub enum PgTempestClientError {
+    Http(
+        #[from]
+        #[debug("{_0}")]
+        reqwest::Error,
+    ),
+    AdminTokenIsMissing,
+    Api {
+        status_code: u16,
+        code: Box<str>,
+        message: Box<str>,
+    },
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+// Error object of resource-oriented and admin routes
+#[derive(Deserialize, Debug)]
+pub struct ApiErrorDto {
+    pub code: Box<str>,
+    pub message: Box<str>,
+}
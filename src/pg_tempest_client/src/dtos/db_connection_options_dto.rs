@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use pg_tempest_core::models::{
+    connection_strings::build_uri, db_connection_options::DbConnectionOptions,
+    value_types::pg_identifier::PgIdentifier,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DbConnectionOptionsDto {
+    pub host: Box<str>,
+    pub port: u16,
+    pub username: Box<str>,
+    pub password: Box<str>,
+    pub database: PgIdentifier,
+    // Absent in responses of older servers
+    #[serde(default)]
+    pub connection_strings: Option<ConnectionStringsDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionStringsDto {
+    pub uri: String,
+    pub key_value: String,
+    pub jdbc: String,
+    pub ado_net: String,
+}
+
+impl DbConnectionOptionsDto {
+    pub fn to_url(&self) -> String {
+        match &self.connection_strings {
+            Some(connection_strings) => connection_strings.uri.clone(),
+            None => build_uri(&DbConnectionOptions {
+                host: self.host.clone(),
+                port: self.port,
+                username: self.username.clone(),
+                password: self.password.clone(),
+                database: self.database.clone(),
+                parameters: BTreeMap::new(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod api_error_dto;
+pub mod db_connection_options_dto;
+pub mod template_dump_dto;
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TemplateDumpDto {
+    Sql { script: Box<str> },
+    // Relative to the archive dir of the server
+    Archive { path: PathBuf },
+}
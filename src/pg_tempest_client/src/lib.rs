@@ -0,0 +1,15 @@
+pub mod client;
+pub mod dtos;
+pub mod routes;
+pub mod template_initialization;
+pub mod test_db_lease;
+#[cfg(feature = "macros")]
+pub mod testing;
+
+#[cfg(feature = "macros")]
+pub use pg_tempest_macros::test;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use tokio;
+}
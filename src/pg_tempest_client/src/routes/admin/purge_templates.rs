@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use pg_tempest_core::models::value_types::{
+    pg_identifier::PgIdentifier, template_hash::TemplateHash,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgedTemplatesDto {
+    pub purged_template_hashes: Vec<TemplateHash>,
+    pub dropped_dbs: Vec<PgIdentifier>,
+    pub failed_dbs: Vec<FailedDbDto>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedDbDto {
+    pub db_name: PgIdentifier,
+    pub reason: Arc<str>,
+}
+
+impl PgTempestClient {
+    // Dbs of templates unknown to the server are dropped only if `include_unknown_dbs` is set
+    pub async fn purge_templates(
+        &self,
+        include_unknown_dbs: bool,
+    ) -> Result<PurgedTemplatesDto, PgTempestClientError> {
+        self.delete_as_admin(&format!("/api/admin/templates?all={include_unknown_dbs}"))
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod admin;
+pub mod templates;
+pub mod test_dbs;
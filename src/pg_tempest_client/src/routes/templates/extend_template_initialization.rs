@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::value_types::template_hash::TemplateHash;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendTemplateInitializationRequestBody {
+    pub template_hash: TemplateHash,
+    pub additional_time_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ExtendTemplateInitializationResponseBody {
+    InitializationWasExtended {
+        new_initialization_deadline: DateTime<Utc>,
+    },
+    TemplateWasNotFound {},
+    InitializationIsNotStarted {},
+    InitializationIsFinished {},
+    InitializationIsFailed {
+        reason: Option<Arc<str>>,
+    },
+}
+
+impl PgTempestClient {
+    pub async fn extend_template_initialization(
+        &self,
+        request_body: &ExtendTemplateInitializationRequestBody,
+    ) -> Result<ExtendTemplateInitializationResponseBody, PgTempestClientError> {
+        self.post("/api/extend-template-initialization", request_body)
+            .await
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use pg_tempest_core::models::value_types::template_hash::TemplateHash;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FailTemplateInitializationRequestBody {
+    pub template_hash: TemplateHash,
+    pub reason: Option<Arc<str>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FailTemplateInitializationResponseBody {
+    InitializationIsFailed {},
+    TemplateWasNotFound {},
+    InitializationIsNotStarted {},
+    InitializationIsFinished {},
+}
+
+impl PgTempestClient {
+    pub async fn fail_template_initialization(
+        &self,
+        request_body: &FailTemplateInitializationRequestBody,
+    ) -> Result<FailTemplateInitializationResponseBody, PgTempestClientError> {
+        self.post("/api/fail-template-initialization", request_body)
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use pg_tempest_core::models::value_types::template_hash::TemplateHash;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishTemplateInitializationRequestBody {
+    pub template_hash: TemplateHash,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FinishTemplateInitializationResponseBody {
+    InitializationIsFinished {},
+    TemplateWasNotFound {},
+    InitializationIsNotStarted {},
+    InitializationIsFailed { reason: Option<Arc<str>> },
+}
+
+impl PgTempestClient {
+    pub async fn finish_template_initialization(
+        &self,
+        request_body: &FinishTemplateInitializationRequestBody,
+    ) -> Result<FinishTemplateInitializationResponseBody, PgTempestClientError> {
+        self.post("/api/finish-template-initialization", request_body)
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum GetTemplatesResponseBody {
+    TemplatesWereFound { templates: Vec<TemplateDto> },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDto {
+    pub template_hash: TemplateHash,
+    pub initialization_state: TemplateInitializationStateDto,
+    pub test_dbs: Vec<TestDbDto>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TemplateInitializationStateDto {
+    Creating {},
+    Created {},
+    InProgress {
+        initialization_deadline: DateTime<Utc>,
+    },
+    Finished {},
+    Failed {
+        reason: Option<Arc<str>>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TestDbDto {
+    pub test_db_id: TestDbId,
+    pub state: TestDbStateDto,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TestDbStateDto {
+    Creating {},
+    Ready {},
+    Corrupted {},
+    InUse { usage_deadline: DateTime<Utc> },
+    Retained { retention_deadline: DateTime<Utc> },
+}
+
+impl PgTempestClient {
+    pub async fn get_templates(&self) -> Result<GetTemplatesResponseBody, PgTempestClientError> {
+        self.post_without_body("/api/get-templates").await
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use pg_tempest_core::models::value_types::{
+    pg_identifier::PgIdentifier, template_hash::TemplateHash,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::template_dump_dto::TemplateDumpDto;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTemplateRequestBody {
+    pub template_hash: TemplateHash,
+    pub dump: TemplateDumpDto,
+    pub initialization_duration_ms: u64,
+    pub parent_template_db_name: Option<PgIdentifier>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ImportTemplateResponseBody {
+    TemplateWasImported {},
+    InitializationIsInProgress {},
+    InitializationIsFinished {},
+    InitializationIsFailed { reason: Option<Arc<str>> },
+    ArchiveImportIsDisabled {},
+    ArchivePathIsInvalid {},
+    UnexpectedError { message: Box<str> },
+}
+
+impl PgTempestClient {
+    // Requires the admin token
+    pub async fn import_template(
+        &self,
+        request_body: &ImportTemplateRequestBody,
+    ) -> Result<ImportTemplateResponseBody, PgTempestClientError> {
+        self.post_as_admin("/api/import-template", request_body)
+            .await
+    }
+}
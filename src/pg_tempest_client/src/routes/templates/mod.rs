@@ -0,0 +1,6 @@
+pub mod extend_template_initialization;
+pub mod fail_template_initialization;
+pub mod finish_template_initialization;
+pub mod get_templates;
+pub mod import_template;
+pub mod start_template_initialization;
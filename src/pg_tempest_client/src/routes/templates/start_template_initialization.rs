@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::{
+    db_settings::DbSettings,
+    value_types::{
+        extension_name::ExtensionName, pg_identifier::PgIdentifier, template_hash::TemplateHash,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTemplateInitializationRequestBody {
+    pub template_hash: TemplateHash,
+    pub initialization_duration_ms: u64,
+    pub parent_template_db_name: Option<PgIdentifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_profile: Option<Box<str>>,
+    #[serde(skip_serializing_if = "DbSettings::is_empty")]
+    pub settings: DbSettings,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<ExtensionName>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StartTemplateInitializationResponseBody {
+    InitializationWasStarted {
+        database_connection_options: DbConnectionOptionsDto,
+        initialization_deadline: DateTime<Utc>,
+    },
+    InitializationIsInProgress {},
+    InitializationIsFinished {},
+    InitializationIsFailed {
+        reason: Option<Arc<str>>,
+    },
+    NetworkProfileWasNotFound {},
+    UnexpectedError {
+        message: Box<str>,
+    },
+}
+
+impl PgTempestClient {
+    pub async fn start_template_initialization(
+        &self,
+        request_body: &StartTemplateInitializationRequestBody,
+    ) -> Result<StartTemplateInitializationResponseBody, PgTempestClientError> {
+        self.post("/api/start-template-initialization", request_body)
+            .await
+    }
+}
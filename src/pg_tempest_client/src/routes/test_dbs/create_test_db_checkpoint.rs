@@ -0,0 +1,34 @@
+use pg_tempest_core::models::value_types::{
+    checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTestDbCheckpointRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum CreateTestDbCheckpointResponseBody {
+    CheckpointWasCreated { checkpoint_id: CheckpointId },
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    TestDbHasConnections {},
+    UnexpectedError { message: Box<str> },
+}
+
+impl PgTempestClient {
+    pub async fn create_test_db_checkpoint(
+        &self,
+        request_body: &CreateTestDbCheckpointRequestBody,
+    ) -> Result<CreateTestDbCheckpointResponseBody, PgTempestClientError> {
+        self.post("/api/create-test-db-checkpoint", request_body)
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DropRetainedTestDbRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum DropRetainedTestDbResponseBody {
+    TestDbWasDropped {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotRetained {},
+}
+
+impl PgTempestClient {
+    pub async fn drop_retained_test_db(
+        &self,
+        request_body: &DropRetainedTestDbRequestBody,
+    ) -> Result<DropRetainedTestDbResponseBody, PgTempestClientError> {
+        self.post("/api/drop-retained-test-db", request_body).await
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendTestDbUsageRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+    pub additional_time_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ExtendTestDbUsageResponseBody {
+    UsageWasExtended { new_usage_deadline: DateTime<Utc> },
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    TestDbIsCorrupted {},
+}
+
+impl PgTempestClient {
+    pub async fn extend_test_db_usage(
+        &self,
+        request_body: &ExtendTestDbUsageRequestBody,
+    ) -> Result<ExtendTestDbUsageResponseBody, PgTempestClientError> {
+        self.post("/api/extend-test-db-usage", request_body).await
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishTestDbUsageRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+    pub outcome: TestDbUsageOutcomeDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain_for_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_profile: Option<Box<str>>,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TestDbUsageOutcomeDto {
+    #[default]
+    Succeeded,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FinishTestDbUsageResponseBody {
+    TestDbWasReleased {},
+    TestDbWasRetained {
+        db_connection_options: DbConnectionOptionsDto,
+        retention_deadline: DateTime<Utc>,
+    },
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    NetworkProfileWasNotFound {},
+}
+
+impl PgTempestClient {
+    pub async fn finish_test_db_usage(
+        &self,
+        request_body: &FinishTestDbUsageRequestBody,
+    ) -> Result<FinishTestDbUsageResponseBody, PgTempestClientError> {
+        self.post("/api/finish-test-db-usage", request_body).await
+    }
+}
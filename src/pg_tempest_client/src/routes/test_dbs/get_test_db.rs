@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTestDbRequestBody {
+    pub template_hash: TemplateHash,
+    pub usage_duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_profile: Option<Box<str>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum GetTestDbResponseBody {
+    TestDbWasCreated {
+        test_db_id: TestDbId,
+        db_connection_options: DbConnectionOptionsDto,
+        usage_deadline: DateTime<Utc>,
+    },
+    TemplateWasNotFound {},
+    TemplateIsNotInitialized {},
+    NetworkProfileWasNotFound {},
+    UnknownError {
+        message: Box<str>,
+    },
+}
+
+impl PgTempestClient {
+    pub async fn get_test_db(
+        &self,
+        request_body: &GetTestDbRequestBody,
+    ) -> Result<GetTestDbResponseBody, PgTempestClientError> {
+        self.post("/api/get-test-db", request_body).await
+    }
+}
@@ -0,0 +1,33 @@
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PromoteTestDbRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+    pub new_template_hash: TemplateHash,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum PromoteTestDbResponseBody {
+    TestDbWasPromoted {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    TestDbHasConnections {},
+    NewTemplateAlreadyExists {},
+    UnexpectedError { message: Box<str> },
+}
+
+impl PgTempestClient {
+    pub async fn promote_test_db(
+        &self,
+        request_body: &PromoteTestDbRequestBody,
+    ) -> Result<PromoteTestDbResponseBody, PgTempestClientError> {
+        self.post("/api/promote-test-db", request_body).await
+    }
+}
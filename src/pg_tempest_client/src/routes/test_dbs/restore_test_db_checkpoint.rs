@@ -0,0 +1,36 @@
+use pg_tempest_core::models::value_types::{
+    checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTestDbCheckpointRequestBody {
+    pub template_hash: TemplateHash,
+    pub test_db_id: TestDbId,
+    pub checkpoint_id: CheckpointId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum RestoreTestDbCheckpointResponseBody {
+    TestDbWasRestored {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    CheckpointWasNotFound {},
+    TestDbHasConnections {},
+    UnexpectedError { message: Box<str> },
+}
+
+impl PgTempestClient {
+    pub async fn restore_test_db_checkpoint(
+        &self,
+        request_body: &RestoreTestDbCheckpointRequestBody,
+    ) -> Result<RestoreTestDbCheckpointResponseBody, PgTempestClientError> {
+        self.post("/api/restore-test-db-checkpoint", request_body)
+            .await
+    }
+}
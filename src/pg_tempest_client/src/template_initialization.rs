@@ -0,0 +1,198 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use derive_more::{Debug as DebugV2, Display};
+use pg_tempest_core::models::{
+    db_settings::DbSettings,
+    value_types::{
+        extension_name::ExtensionName, pg_identifier::PgIdentifier, template_hash::TemplateHash,
+    },
+};
+use pg_tempest_core::utils::errors::BoxDynError;
+use thiserror::Error;
+use tokio::time::sleep;
+use tracing::{debug, info, warn};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+use crate::routes::templates::{
+    extend_template_initialization::{
+        ExtendTemplateInitializationRequestBody, ExtendTemplateInitializationResponseBody,
+    },
+    fail_template_initialization::FailTemplateInitializationRequestBody,
+    finish_template_initialization::{
+        FinishTemplateInitializationRequestBody, FinishTemplateInitializationResponseBody,
+    },
+    start_template_initialization::{
+        StartTemplateInitializationRequestBody, StartTemplateInitializationResponseBody,
+    },
+};
+
+const MIN_INITIALIZATION_EXTENSION_INTERVAL: Duration = Duration::from_millis(10);
+
+pub struct TemplateInitializationOptions {
+    pub initialization_duration: Duration,
+    pub parent_template_db_name: Option<PgIdentifier>,
+    // Applied by the server to the template db and to each test db
+    pub settings: DbSettings,
+    // Created by the server in the template db before `initializer` is run
+    pub extensions: Vec<ExtensionName>,
+    pub retries_delay: Duration,
+}
+
+impl Default for TemplateInitializationOptions {
+    fn default() -> Self {
+        TemplateInitializationOptions {
+            initialization_duration: Duration::from_secs(60),
+            parent_template_db_name: None,
+            settings: DbSettings::new(),
+            extensions: Vec::new(),
+            retries_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+impl PgTempestClient {
+    // Runs the template initialization protocol: starts the initialization (waiting while
+    // another client initializes the same template), runs `initializer` against the template db
+    // while extending the initialization deadline, then finishes or fails the initialization
+    pub async fn initialize_template(
+        &self,
+        template_hash: TemplateHash,
+        options: &TemplateInitializationOptions,
+        initializer: impl AsyncFnOnce(DbConnectionOptionsDto) -> Result<(), BoxDynError>,
+    ) -> Result<(), InitializeTemplateError> {
+        let start_request_body = StartTemplateInitializationRequestBody {
+            template_hash,
+            initialization_duration_ms: options.initialization_duration.as_millis() as u64,
+            parent_template_db_name: options.parent_template_db_name.clone(),
+            network_profile: self.network_profile().map(Into::into),
+            settings: options.settings.clone(),
+            extensions: options.extensions.clone(),
+        };
+
+        let database_connection_options = loop {
+            let response_body = self
+                .start_template_initialization(&start_request_body)
+                .await?;
+
+            match response_body {
+                StartTemplateInitializationResponseBody::InitializationWasStarted {
+                    database_connection_options,
+                    ..
+                } => break database_connection_options,
+                StartTemplateInitializationResponseBody::InitializationIsInProgress {} => {
+                    debug!("Template {template_hash} initialization is in progress. Waiting");
+                    sleep(options.retries_delay).await;
+                }
+                StartTemplateInitializationResponseBody::InitializationIsFinished {} => {
+                    debug!("Template {template_hash} initialization is already finished");
+                    return Ok(());
+                }
+                StartTemplateInitializationResponseBody::InitializationIsFailed { reason } => {
+                    return Err(InitializeTemplateError::InitializationIsFailed { reason });
+                }
+                StartTemplateInitializationResponseBody::NetworkProfileWasNotFound {} => {
+                    return Err(InitializeTemplateError::NetworkProfileWasNotFound);
+                }
+                StartTemplateInitializationResponseBody::UnexpectedError { message } => {
+                    return Err(InitializeTemplateError::Unexpected { message });
+                }
+            }
+        };
+
+        info!("Template {template_hash} initialization was started");
+
+        let initialization_extension = tokio::spawn(extend_initialization_periodically(
+            self.clone(),
+            template_hash,
+            (options.initialization_duration / 2).max(MIN_INITIALIZATION_EXTENSION_INTERVAL),
+        ));
+
+        let initializer_result = initializer(database_connection_options).await;
+
+        initialization_extension.abort();
+
+        if let Err(err) = initializer_result {
+            let fail_result = self
+                .fail_template_initialization(&FailTemplateInitializationRequestBody {
+                    template_hash,
+                    reason: Some(err.to_string().into()),
+                })
+                .await;
+
+            if let Err(fail_err) = fail_result {
+                warn!("Failed to fail template {template_hash} initialization: {fail_err}");
+            }
+
+            return Err(InitializeTemplateError::Initializer(err));
+        }
+
+        let response_body = self
+            .finish_template_initialization(&FinishTemplateInitializationRequestBody {
+                template_hash,
+            })
+            .await?;
+
+        match response_body {
+            FinishTemplateInitializationResponseBody::InitializationIsFinished {} => {
+                info!("Template {template_hash} initialization was finished");
+                Ok(())
+            }
+            FinishTemplateInitializationResponseBody::InitializationIsFailed { reason } => {
+                Err(InitializeTemplateError::InitializationIsFailed { reason })
+            }
+            response_body => Err(InitializeTemplateError::Unexpected {
+                message: format!("{response_body:?}").into(),
+            }),
+        }
+    }
+}
+
+async fn extend_initialization_periodically(
+    client: PgTempestClient,
+    template_hash: TemplateHash,
+    extension_interval: Duration,
+) {
+    let request_body = ExtendTemplateInitializationRequestBody {
+        template_hash,
+        additional_time_ms: extension_interval.as_millis() as u64,
+    };
+
+    loop {
+        sleep(extension_interval).await;
+
+        match client.extend_template_initialization(&request_body).await {
+            Ok(ExtendTemplateInitializationResponseBody::InitializationWasExtended { .. }) => {
+                debug!("Template {template_hash} initialization was extended");
+            }
+            Ok(response_body) => {
+                warn!(
+                    "Template {template_hash} initialization can't be extended: {response_body:?}"
+                );
+                return;
+            }
+            Err(err) => {
+                warn!("Failed to extend template {template_hash} initialization: {err}");
+            }
+        }
+    }
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("InitializeTemplateError::{self:?}")]
+pub enum InitializeTemplateError {
+    InitializationIsFailed {
+        reason: Option<Arc<str>>,
+    },
+    NetworkProfileWasNotFound,
+    Initializer(#[debug("{_0}")] BoxDynError),
+    Unexpected {
+        message: Box<str>,
+    },
+    Client(
+        #[from]
+        #[debug("{_0}")]
+        PgTempestClientError,
+    ),
+}
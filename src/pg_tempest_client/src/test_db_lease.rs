@@ -0,0 +1,435 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use derive_more::{Debug as DebugV2, Display};
+use pg_tempest_core::models::value_types::{
+    checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+};
+use thiserror::Error;
+use tokio::runtime::RuntimeFlavor;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::{debug, error, warn};
+
+use crate::client::{PgTempestClient, PgTempestClientError};
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+use crate::routes::test_dbs::{
+    create_test_db_checkpoint::{
+        CreateTestDbCheckpointRequestBody, CreateTestDbCheckpointResponseBody,
+    },
+    extend_test_db_usage::{ExtendTestDbUsageRequestBody, ExtendTestDbUsageResponseBody},
+    finish_test_db_usage::{
+        FinishTestDbUsageRequestBody, FinishTestDbUsageResponseBody, TestDbUsageOutcomeDto,
+    },
+    get_test_db::{GetTestDbRequestBody, GetTestDbResponseBody},
+    promote_test_db::{PromoteTestDbRequestBody, PromoteTestDbResponseBody},
+    restore_test_db_checkpoint::{
+        RestoreTestDbCheckpointRequestBody, RestoreTestDbCheckpointResponseBody,
+    },
+};
+
+const MIN_USAGE_EXTENSION_INTERVAL: Duration = Duration::from_millis(10);
+// The usage is extended every half of its duration, which must be extended before the deadline
+pub const MIN_USAGE_DURATION: Duration = MIN_USAGE_EXTENSION_INTERVAL.saturating_mul(2);
+
+pub struct TestDbLease {
+    client: Arc<PgTempestClient>,
+    template_hash: TemplateHash,
+    test_db_id: TestDbId,
+    db_connection_options: DbConnectionOptionsDto,
+    usage_extension: JoinHandle<()>,
+    is_finished: bool,
+}
+
+impl TestDbLease {
+    pub fn template_hash(&self) -> TemplateHash {
+        self.template_hash
+    }
+
+    pub fn test_db_id(&self) -> TestDbId {
+        self.test_db_id
+    }
+
+    pub fn db_connection_options(&self) -> &DbConnectionOptionsDto {
+        &self.db_connection_options
+    }
+
+    // Connections to the test db must be closed before a checkpoint is created or restored
+    pub async fn checkpoint(&self) -> Result<CheckpointId, TestDbCheckpointError> {
+        let response_body = self
+            .client
+            .create_test_db_checkpoint(&CreateTestDbCheckpointRequestBody {
+                template_hash: self.template_hash,
+                test_db_id: self.test_db_id,
+            })
+            .await?;
+
+        match response_body {
+            CreateTestDbCheckpointResponseBody::CheckpointWasCreated { checkpoint_id } => {
+                Ok(checkpoint_id)
+            }
+            CreateTestDbCheckpointResponseBody::TemplateWasNotFound {} => {
+                Err(TestDbCheckpointError::TemplateWasNotFound)
+            }
+            CreateTestDbCheckpointResponseBody::TestDbWasNotFound {} => {
+                Err(TestDbCheckpointError::TestDbWasNotFound)
+            }
+            CreateTestDbCheckpointResponseBody::TestDbIsNotUsed {} => {
+                Err(TestDbCheckpointError::TestDbIsNotUsed)
+            }
+            CreateTestDbCheckpointResponseBody::TestDbHasConnections {} => {
+                Err(TestDbCheckpointError::TestDbHasConnections)
+            }
+            CreateTestDbCheckpointResponseBody::UnexpectedError { message } => {
+                Err(TestDbCheckpointError::Unexpected { message })
+            }
+        }
+    }
+
+    pub async fn restore(&self, checkpoint_id: CheckpointId) -> Result<(), TestDbCheckpointError> {
+        let response_body = self
+            .client
+            .restore_test_db_checkpoint(&RestoreTestDbCheckpointRequestBody {
+                template_hash: self.template_hash,
+                test_db_id: self.test_db_id,
+                checkpoint_id,
+            })
+            .await?;
+
+        match response_body {
+            RestoreTestDbCheckpointResponseBody::TestDbWasRestored {} => Ok(()),
+            RestoreTestDbCheckpointResponseBody::TemplateWasNotFound {} => {
+                Err(TestDbCheckpointError::TemplateWasNotFound)
+            }
+            RestoreTestDbCheckpointResponseBody::TestDbWasNotFound {} => {
+                Err(TestDbCheckpointError::TestDbWasNotFound)
+            }
+            RestoreTestDbCheckpointResponseBody::TestDbIsNotUsed {} => {
+                Err(TestDbCheckpointError::TestDbIsNotUsed)
+            }
+            RestoreTestDbCheckpointResponseBody::CheckpointWasNotFound {} => {
+                Err(TestDbCheckpointError::CheckpointWasNotFound)
+            }
+            RestoreTestDbCheckpointResponseBody::TestDbHasConnections {} => {
+                Err(TestDbCheckpointError::TestDbHasConnections)
+            }
+            RestoreTestDbCheckpointResponseBody::UnexpectedError { message } => {
+                Err(TestDbCheckpointError::Unexpected { message })
+            }
+        }
+    }
+
+    // The test db becomes the template db of a new finished template, and the usage is finished.
+    // Connections to the test db must be closed before, so it can be retried on failure
+    pub async fn promote(
+        &mut self,
+        new_template_hash: TemplateHash,
+    ) -> Result<(), PromoteTestDbError> {
+        let response_body = self
+            .client
+            .promote_test_db(&PromoteTestDbRequestBody {
+                template_hash: self.template_hash,
+                test_db_id: self.test_db_id,
+                new_template_hash,
+            })
+            .await?;
+
+        match response_body {
+            PromoteTestDbResponseBody::TestDbWasPromoted {} => {
+                self.usage_extension.abort();
+                self.is_finished = true;
+                Ok(())
+            }
+            PromoteTestDbResponseBody::TemplateWasNotFound {} => {
+                Err(PromoteTestDbError::TemplateWasNotFound)
+            }
+            PromoteTestDbResponseBody::TestDbWasNotFound {} => {
+                Err(PromoteTestDbError::TestDbWasNotFound)
+            }
+            PromoteTestDbResponseBody::TestDbIsNotUsed {} => {
+                Err(PromoteTestDbError::TestDbIsNotUsed)
+            }
+            PromoteTestDbResponseBody::TestDbHasConnections {} => {
+                Err(PromoteTestDbError::TestDbHasConnections)
+            }
+            PromoteTestDbResponseBody::NewTemplateAlreadyExists {} => {
+                Err(PromoteTestDbError::NewTemplateAlreadyExists)
+            }
+            PromoteTestDbResponseBody::UnexpectedError { message } => {
+                Err(PromoteTestDbError::Unexpected { message })
+            }
+        }
+    }
+
+    pub async fn finish(self) -> Result<FinishTestDbUsageResponseBody, PgTempestClientError> {
+        self.finish_with_outcome(TestDbUsageOutcomeDto::Succeeded)
+            .await
+    }
+
+    // The test db is retained if the client has failure retention, otherwise it's released
+    pub async fn fail(self) -> Result<FinishTestDbUsageResponseBody, PgTempestClientError> {
+        self.finish_with_outcome(TestDbUsageOutcomeDto::Failed)
+            .await
+    }
+
+    async fn finish_with_outcome(
+        mut self,
+        outcome: TestDbUsageOutcomeDto,
+    ) -> Result<FinishTestDbUsageResponseBody, PgTempestClientError> {
+        self.usage_extension.abort();
+        self.is_finished = true;
+
+        self.client
+            .finish_test_db_usage(&finish_request_body(
+                &self.client,
+                self.template_hash,
+                self.test_db_id,
+                outcome,
+            ))
+            .await
+    }
+}
+
+fn finish_request_body(
+    client: &PgTempestClient,
+    template_hash: TemplateHash,
+    test_db_id: TestDbId,
+    outcome: TestDbUsageOutcomeDto,
+) -> FinishTestDbUsageRequestBody {
+    FinishTestDbUsageRequestBody {
+        template_hash,
+        test_db_id,
+        outcome,
+        retain_for_ms: client
+            .failure_retention()
+            .map(|failure_retention| failure_retention.as_millis() as u64),
+        network_profile: client.network_profile().map(Into::into),
+    }
+}
+
+impl Drop for TestDbLease {
+    fn drop(&mut self) {
+        self.usage_extension.abort();
+
+        if self.is_finished {
+            return;
+        }
+
+        // A lease dropped while panicking belongs to a failed test
+        let outcome = if std::thread::panicking() {
+            TestDbUsageOutcomeDto::Failed
+        } else {
+            TestDbUsageOutcomeDto::Succeeded
+        };
+        let template_hash = self.template_hash;
+        let test_db_id = self.test_db_id;
+
+        // Drop can't await, so the runtime is blocked until the usage is finished. A current_thread
+        // runtime can't be blocked in place and drives pooled connections of the client, so the
+        // usage is finished on a separate thread with its own runtime and http client
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime_handle) if runtime_handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                let finish =
+                    finish_dropped_lease(self.client.clone(), template_hash, test_db_id, outcome);
+                tokio::task::block_in_place(|| runtime_handle.block_on(finish));
+            }
+            _ => {
+                let client = Arc::new(self.client.with_new_http_client());
+                let join_result =
+                    std::thread::spawn(move || match tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                    {
+                        Ok(runtime) => runtime.block_on(finish_dropped_lease(
+                            client,
+                            template_hash,
+                            test_db_id,
+                            outcome,
+                        )),
+                        Err(err) => error!("Failed to create runtime to release test db: {err}"),
+                    })
+                    .join();
+
+                if join_result.is_err() {
+                    error!(
+                        "Failed to release test db {template_hash} {test_db_id}: thread panicked"
+                    );
+                }
+            }
+        }
+    }
+}
+
+async fn finish_dropped_lease(
+    client: Arc<PgTempestClient>,
+    template_hash: TemplateHash,
+    test_db_id: TestDbId,
+    outcome: TestDbUsageOutcomeDto,
+) {
+    let request_body = finish_request_body(&client, template_hash, test_db_id, outcome);
+
+    match client.finish_test_db_usage(&request_body).await {
+        Ok(FinishTestDbUsageResponseBody::TestDbWasReleased {}) => {
+            debug!("Test db {template_hash} {test_db_id} was released on drop");
+        }
+        Ok(FinishTestDbUsageResponseBody::TestDbWasRetained {
+            retention_deadline, ..
+        }) => {
+            warn!("Test db {template_hash} {test_db_id} is retained until {retention_deadline}");
+        }
+        Ok(response_body) => {
+            warn!("Test db {template_hash} {test_db_id} was not released: {response_body:?}");
+        }
+        Err(err) => {
+            error!("Failed to release test db {template_hash} {test_db_id}: {err}");
+        }
+    }
+}
+
+impl PgTempestClient {
+    pub async fn lease_test_db(
+        &self,
+        template_hash: TemplateHash,
+        usage_duration: Duration,
+    ) -> Result<TestDbLease, LeaseTestDbError> {
+        if usage_duration < MIN_USAGE_DURATION {
+            return Err(LeaseTestDbError::UsageDurationIsTooShort {
+                min_usage_duration: MIN_USAGE_DURATION,
+            });
+        }
+
+        let response_body = self
+            .get_test_db(&GetTestDbRequestBody {
+                template_hash,
+                usage_duration_ms: usage_duration.as_millis() as u64,
+                network_profile: self.network_profile().map(Into::into),
+            })
+            .await?;
+
+        let (test_db_id, db_connection_options) = match response_body {
+            GetTestDbResponseBody::TestDbWasCreated {
+                test_db_id,
+                db_connection_options,
+                ..
+            } => (test_db_id, db_connection_options),
+            GetTestDbResponseBody::TemplateWasNotFound {} => {
+                return Err(LeaseTestDbError::TemplateWasNotFound);
+            }
+            GetTestDbResponseBody::TemplateIsNotInitialized {} => {
+                return Err(LeaseTestDbError::TemplateIsNotInitialized);
+            }
+            GetTestDbResponseBody::NetworkProfileWasNotFound {} => {
+                return Err(LeaseTestDbError::NetworkProfileWasNotFound);
+            }
+            GetTestDbResponseBody::UnknownError { message } => {
+                return Err(LeaseTestDbError::Unknown { message });
+            }
+        };
+
+        let client = Arc::new(self.clone());
+        let usage_extension = tokio::spawn(extend_usage_periodically(
+            client.clone(),
+            template_hash,
+            test_db_id,
+            usage_duration / 2,
+        ));
+
+        Ok(TestDbLease {
+            client,
+            template_hash,
+            test_db_id,
+            db_connection_options,
+            usage_extension,
+            is_finished: false,
+        })
+    }
+}
+
+async fn extend_usage_periodically(
+    client: Arc<PgTempestClient>,
+    template_hash: TemplateHash,
+    test_db_id: TestDbId,
+    extension_interval: Duration,
+) {
+    let request_body = ExtendTestDbUsageRequestBody {
+        template_hash,
+        test_db_id,
+        additional_time_ms: extension_interval.as_millis() as u64,
+    };
+
+    loop {
+        sleep(extension_interval).await;
+
+        match client.extend_test_db_usage(&request_body).await {
+            Ok(ExtendTestDbUsageResponseBody::UsageWasExtended { new_usage_deadline }) => {
+                debug!(
+                    "Test db {template_hash} {test_db_id} usage was extended to {new_usage_deadline}"
+                );
+            }
+            Ok(response_body) => {
+                warn!(
+                    "Test db {template_hash} {test_db_id} usage can't be extended: {response_body:?}"
+                );
+                return;
+            }
+            Err(err) => {
+                warn!("Failed to extend test db {template_hash} {test_db_id} usage: {err}");
+            }
+        }
+    }
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("LeaseTestDbError::{self:?}")]
+pub enum LeaseTestDbError {
+    UsageDurationIsTooShort {
+        min_usage_duration: Duration,
+    },
+    TemplateWasNotFound,
+    TemplateIsNotInitialized,
+    NetworkProfileWasNotFound,
+    Unknown {
+        message: Box<str>,
+    },
+    Client(
+        #[from]
+        #[debug("{_0}")]
+        PgTempestClientError,
+    ),
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("TestDbCheckpointError::{self:?}")]
+pub enum TestDbCheckpointError {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotUsed,
+    CheckpointWasNotFound,
+    TestDbHasConnections,
+    Unexpected {
+        message: Box<str>,
+    },
+    Client(
+        #[from]
+        #[debug("{_0}")]
+        PgTempestClientError,
+    ),
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("PromoteTestDbError::{self:?}")]
+pub enum PromoteTestDbError {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotUsed,
+    TestDbHasConnections,
+    NewTemplateAlreadyExists,
+    Unexpected {
+        message: Box<str>,
+    },
+    Client(
+        #[from]
+        #[debug("{_0}")]
+        PgTempestClientError,
+    ),
+}
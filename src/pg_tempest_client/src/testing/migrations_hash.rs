@@ -0,0 +1,82 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use pg_tempest_core::models::value_types::template_hash::{TEMPLATE_HASH_LENGTH, TemplateHash};
+use sha2::{Digest, Sha256};
+
+// Hashes names and contents of all files in the migrations directory,
+// so any change of migrations produces a new template.
+// Migrations are hashed when tests are run rather than when they are compiled,
+// since cargo doesn't rebuild tests when a migration is added
+pub fn hash_migrations(migrations_dir: &Path) -> io::Result<TemplateHash> {
+    let mut migration_paths = Vec::new();
+    collect_files(migrations_dir, &mut migration_paths)?;
+    migration_paths.sort();
+
+    let mut hasher = Sha256::new();
+
+    for migration_path in migration_paths.iter() {
+        let relative_path = migration_path
+            .strip_prefix(migrations_dir)
+            .unwrap_or(migration_path);
+
+        hasher.update(relative_path.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(migration_path)?);
+        hasher.update([0]);
+    }
+
+    let mut hash = [0u8; TEMPLATE_HASH_LENGTH];
+    hash.copy_from_slice(&hasher.finalize()[..TEMPLATE_HASH_LENGTH]);
+
+    Ok(TemplateHash::new(hash))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::testing::migrations_hash::hash_migrations;
+
+    #[test]
+    fn hash_depends_on_migration_contents() {
+        let migrations_dir = std::env::temp_dir().join(format!(
+            "pg_tempest_client_migrations_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&migrations_dir).unwrap();
+
+        fs::write(migrations_dir.join("0001_init.sql"), "create table a();").unwrap();
+        let first_hash = hash_migrations(&migrations_dir).unwrap();
+
+        fs::write(migrations_dir.join("0001_init.sql"), "create table b();").unwrap();
+        let second_hash = hash_migrations(&migrations_dir).unwrap();
+
+        fs::write(migrations_dir.join("0001_init.sql"), "create table a();").unwrap();
+        let third_hash = hash_migrations(&migrations_dir).unwrap();
+
+        fs::write(migrations_dir.join("0002_next.sql"), "create table c();").unwrap();
+        let fourth_hash = hash_migrations(&migrations_dir).unwrap();
+
+        fs::remove_dir_all(&migrations_dir).unwrap();
+
+        assert_ne!(first_hash, second_hash);
+        assert_eq!(first_hash, third_hash);
+        assert_ne!(first_hash, fourth_hash);
+    }
+}
@@ -0,0 +1,168 @@
+use std::env;
+use std::future::{Future, poll_fn};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::pin::pin;
+use std::task::Poll;
+use std::time::Duration;
+
+use pg_tempest_core::utils::errors::BoxDynError;
+use sqlx::migrate::Migrator;
+use sqlx::{Connection, PgConnection};
+use tracing::warn;
+
+use crate::client::PgTempestClient;
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+use crate::routes::test_dbs::finish_test_db_usage::FinishTestDbUsageResponseBody;
+use crate::template_initialization::TemplateInitializationOptions;
+use crate::test_db_lease::TestDbLease;
+use crate::testing::migrations_hash::hash_migrations;
+use crate::testing::test_argument::pg_connect_options;
+
+pub use crate::testing::test_argument::TestArgument;
+
+mod migrations_hash;
+mod test_argument;
+
+pub const PG_TEMPEST_URL_ENV: &str = "PG_TEMPEST_URL";
+const DEFAULT_PG_TEMPEST_URL: &str = "http://localhost:8000";
+pub const PG_TEMPEST_NETWORK_PROFILE_ENV: &str = "PG_TEMPEST_NETWORK_PROFILE";
+// Test dbs of failed tests are retained for the given time instead of being recycled
+pub const PG_TEMPEST_RETAIN_FAILED_FOR_MS_ENV: &str = "PG_TEMPEST_RETAIN_FAILED_FOR_MS";
+
+pub struct TestTemplate {
+    pub migrations_dir: &'static str,
+    pub initialization_duration: Duration,
+    pub usage_duration: Duration,
+    // Overrides PG_TEMPEST_RETAIN_FAILED_FOR_MS
+    pub failure_retention: Option<Duration>,
+}
+
+pub async fn lease_test_db(template: &TestTemplate) -> TestDbLease {
+    let base_url = env::var(PG_TEMPEST_URL_ENV).unwrap_or(DEFAULT_PG_TEMPEST_URL.into());
+    let mut client = PgTempestClient::new(base_url);
+    if let Ok(network_profile) = env::var(PG_TEMPEST_NETWORK_PROFILE_ENV) {
+        client = client.with_network_profile(network_profile);
+    }
+    if let Some(failure_retention) = template
+        .failure_retention
+        .or_else(failure_retention_from_env)
+    {
+        client = client.with_failure_retention(failure_retention);
+    }
+    let template_hash = hash_migrations(Path::new(template.migrations_dir)).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read migrations {}: {err}",
+            template.migrations_dir
+        )
+    });
+
+    let options = TemplateInitializationOptions {
+        initialization_duration: template.initialization_duration,
+        ..Default::default()
+    };
+
+    client
+        .initialize_template(template_hash, &options, async |db_connection_options| {
+            apply_migrations(template.migrations_dir, &db_connection_options).await
+        })
+        .await
+        .unwrap_or_else(|err| panic!("Failed to initialize template {template_hash}: {err}"));
+
+    client
+        .lease_test_db(template_hash, template.usage_duration)
+        .await
+        .unwrap_or_else(|err| panic!("Failed to lease test db {template_hash}: {err}"))
+}
+
+fn failure_retention_from_env() -> Option<Duration> {
+    let retain_failed_for_ms = env::var(PG_TEMPEST_RETAIN_FAILED_FOR_MS_ENV).ok()?;
+    let retain_failed_for_ms = retain_failed_for_ms.parse().unwrap_or_else(|err| {
+        panic!("{PG_TEMPEST_RETAIN_FAILED_FOR_MS_ENV} is not a number of ms: {err}")
+    });
+
+    Some(Duration::from_millis(retain_failed_for_ms))
+}
+
+pub async fn test_argument<T: TestArgument>(lease: &TestDbLease) -> T {
+    T::from_test_db_lease(lease)
+        .await
+        .unwrap_or_else(|err| panic!("Failed to create test argument: {err}"))
+}
+
+// The lease of a panicked test is failed before the panic is resumed,
+// since a lease dropped while the runtime shuts down may be not finished
+pub async fn catch_unwind<F: Future>(test: F) -> std::thread::Result<F::Output> {
+    let mut test = pin!(test);
+
+    poll_fn(
+        |cx| match panic::catch_unwind(AssertUnwindSafe(|| test.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(panic) => Poll::Ready(Err(panic)),
+        },
+    )
+    .await
+}
+
+// Output of a test, which fails it like in the standard test harness
+pub trait TestOutput {
+    fn is_failure(&self) -> bool;
+}
+
+impl TestOutput for () {
+    fn is_failure(&self) -> bool {
+        false
+    }
+}
+
+impl<T, E> TestOutput for Result<T, E> {
+    fn is_failure(&self) -> bool {
+        self.is_err()
+    }
+}
+
+pub async fn finish_test_db_lease(lease: TestDbLease, test_failed: bool) {
+    let template_hash = lease.template_hash();
+    let test_db_id = lease.test_db_id();
+
+    let finish_result = match test_failed {
+        true => lease.fail().await,
+        false => lease.finish().await,
+    };
+
+    match finish_result {
+        Ok(FinishTestDbUsageResponseBody::TestDbWasReleased {}) => {}
+        Ok(FinishTestDbUsageResponseBody::TestDbWasRetained {
+            db_connection_options,
+            retention_deadline,
+        }) => {
+            warn!(
+                "Test db {template_hash} {test_db_id} is retained until {retention_deadline}: {}",
+                db_connection_options.to_url()
+            );
+        }
+        Ok(response_body) => {
+            warn!("Test db {template_hash} {test_db_id} was not released: {response_body:?}");
+        }
+        Err(err) => {
+            warn!("Failed to release test db {template_hash} {test_db_id}: {err}");
+        }
+    }
+}
+
+async fn apply_migrations(
+    migrations_dir: &str,
+    db_connection_options: &DbConnectionOptionsDto,
+) -> Result<(), BoxDynError> {
+    let migrator = Migrator::new(Path::new(migrations_dir)).await?;
+    let mut connection =
+        PgConnection::connect_with(&pg_connect_options(db_connection_options)).await?;
+
+    migrator.run(&mut connection).await?;
+
+    // Test dbs can't be created from the template while it has open connections
+    connection.close().await?;
+
+    Ok(())
+}
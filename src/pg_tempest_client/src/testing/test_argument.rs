@@ -0,0 +1,61 @@
+use pg_tempest_core::utils::errors::BoxDynError;
+use sqlx::PgPool;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+
+use crate::dtos::db_connection_options_dto::DbConnectionOptionsDto;
+use crate::test_db_lease::TestDbLease;
+
+// A value which can be injected into a `#[pg_tempest_client::test]` function.
+// A clone is passed to the test, the original one is closed after the test
+pub trait TestArgument: Clone + Sized {
+    fn from_test_db_lease(
+        lease: &TestDbLease,
+    ) -> impl Future<Output = Result<Self, BoxDynError>> + Send;
+
+    fn close(&self) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+impl TestArgument for DbConnectionOptionsDto {
+    async fn from_test_db_lease(lease: &TestDbLease) -> Result<Self, BoxDynError> {
+        Ok(lease.db_connection_options().clone())
+    }
+}
+
+// Connection url
+impl TestArgument for String {
+    async fn from_test_db_lease(lease: &TestDbLease) -> Result<Self, BoxDynError> {
+        Ok(lease.db_connection_options().to_url())
+    }
+}
+
+impl TestArgument for PgConnectOptions {
+    async fn from_test_db_lease(lease: &TestDbLease) -> Result<Self, BoxDynError> {
+        Ok(pg_connect_options(lease.db_connection_options()))
+    }
+}
+
+impl TestArgument for PgPool {
+    async fn from_test_db_lease(lease: &TestDbLease) -> Result<Self, BoxDynError> {
+        let pg_pool = PgPoolOptions::new()
+            .connect_with(pg_connect_options(lease.db_connection_options()))
+            .await?;
+
+        Ok(pg_pool)
+    }
+
+    // Open connections prevent the test db from being recreated
+    async fn close(&self) {
+        PgPool::close(self).await
+    }
+}
+
+pub fn pg_connect_options(db_connection_options: &DbConnectionOptionsDto) -> PgConnectOptions {
+    PgConnectOptions::new_without_pgpass()
+        .host(&db_connection_options.host)
+        .port(db_connection_options.port)
+        .username(&db_connection_options.username)
+        .password(&db_connection_options.password)
+        .database(&db_connection_options.database.to_string())
+}
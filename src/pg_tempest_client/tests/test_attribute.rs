@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+// Require a pg-tempest server, so they are run with `cargo test --test test_attribute -- --ignored`
+
+#[ignore = "requires a pg-tempest server"]
+#[pg_tempest_client::test(migrations = "tests/migrations")]
+async fn test_db_is_migrated(pg_pool: PgPool) {
+    let name: String = sqlx::query_scalar("select name from items where id = 1;")
+        .fetch_one(&pg_pool)
+        .await
+        .unwrap();
+
+    assert_eq!(name, "first");
+}
+
+#[ignore = "requires a pg-tempest server"]
+#[pg_tempest_client::test(migrations = "tests/migrations")]
+async fn ok_output_is_returned(pg_pool: PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("insert into items values (2, 'second');")
+        .execute(&pg_pool)
+        .await?;
+
+    Ok(())
+}
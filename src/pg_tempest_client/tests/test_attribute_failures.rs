@@ -0,0 +1,78 @@
+use std::env;
+use std::panic;
+
+use pg_tempest_client::{
+    client::PgTempestClient,
+    routes::{
+        templates::get_templates::{GetTemplatesResponseBody, TestDbStateDto},
+        test_dbs::drop_retained_test_db::DropRetainedTestDbRequestBody,
+    },
+    testing::PG_TEMPEST_URL_ENV,
+};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use sqlx::PgPool;
+
+// Run by `failed_tests_retain_test_dbs`, since their failures are expected
+#[ignore = "fails by design, run by failed_tests_retain_test_dbs"]
+#[pg_tempest_client::test(migrations = "tests/migrations", retain_failed_for_ms = 60000)]
+async fn test_returning_err(_pg_pool: PgPool) -> Result<(), &'static str> {
+    Err("test failed")
+}
+
+#[ignore = "fails by design, run by failed_tests_retain_test_dbs"]
+#[pg_tempest_client::test(migrations = "tests/migrations", retain_failed_for_ms = 60000)]
+async fn test_panicking(_pg_pool: PgPool) {
+    panic!("test failed");
+}
+
+// Requires a pg-tempest server, so it's run with
+// `cargo test --test test_attribute_failures -- --ignored failed_tests_retain_test_dbs`
+#[test]
+#[ignore = "requires a pg-tempest server"]
+fn failed_tests_retain_test_dbs() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let initially_retained_test_dbs = runtime.block_on(retained_test_dbs());
+
+    assert!(test_returning_err().is_err());
+    assert!(panic::catch_unwind(test_panicking).is_err());
+
+    let retained_test_dbs = runtime.block_on(retained_test_dbs());
+    let new_retained_test_dbs = retained_test_dbs
+        .into_iter()
+        .filter(|test_db| !initially_retained_test_dbs.contains(test_db))
+        .collect::<Vec<_>>();
+
+    assert_eq!(new_retained_test_dbs.len(), 2);
+
+    runtime.block_on(async {
+        for (template_hash, test_db_id) in new_retained_test_dbs {
+            client()
+                .drop_retained_test_db(&DropRetainedTestDbRequestBody {
+                    template_hash,
+                    test_db_id,
+                })
+                .await
+                .unwrap();
+        }
+    });
+}
+
+fn client() -> PgTempestClient {
+    PgTempestClient::new(env::var(PG_TEMPEST_URL_ENV).unwrap_or("http://localhost:8000".into()))
+}
+
+async fn retained_test_dbs() -> Vec<(TemplateHash, TestDbId)> {
+    let GetTemplatesResponseBody::TemplatesWereFound { templates } =
+        client().get_templates().await.unwrap();
+
+    templates
+        .into_iter()
+        .flat_map(|template| {
+            template
+                .test_dbs
+                .into_iter()
+                .filter(|test_db| matches!(test_db.state, TestDbStateDto::Retained { .. }))
+                .map(move |test_db| (template.template_hash, test_db.test_db_id))
+        })
+        .collect()
+}
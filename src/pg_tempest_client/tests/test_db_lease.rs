@@ -0,0 +1,237 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::{Json, Router, routing::post};
+use chrono::Utc;
+use pg_tempest_client::{
+    client::PgTempestClient,
+    dtos::db_connection_options_dto::DbConnectionOptionsDto,
+    routes::test_dbs::{
+        extend_test_db_usage::ExtendTestDbUsageResponseBody,
+        finish_test_db_usage::FinishTestDbUsageResponseBody, get_test_db::GetTestDbResponseBody,
+    },
+    test_db_lease::{LeaseTestDbError, MIN_USAGE_DURATION},
+};
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use serde_json::Value;
+use tokio::time::sleep;
+
+const TEMPLATE_HASH: TemplateHash = TemplateHash::new([7; 16]);
+
+#[tokio::test]
+async fn dropped_lease_is_finished_on_current_thread_runtime() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    drop(lease);
+
+    assert_eq!(server.finish_outcomes(), ["succeeded"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dropped_lease_is_finished_on_multi_thread_runtime() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    drop(lease);
+
+    assert_eq!(server.finish_outcomes(), ["succeeded"]);
+}
+
+#[tokio::test]
+async fn lease_dropped_while_panicking_is_failed_on_current_thread_runtime() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    let join_result = tokio::spawn(async move {
+        let _lease = lease;
+        panic!("Test failed");
+    })
+    .await;
+
+    assert!(join_result.is_err());
+    assert_eq!(server.finish_outcomes(), ["failed"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn lease_dropped_while_panicking_is_failed_on_multi_thread_runtime() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    let join_result = tokio::spawn(async move {
+        let _lease = lease;
+        panic!("Test failed");
+    })
+    .await;
+
+    assert!(join_result.is_err());
+    assert_eq!(server.finish_outcomes(), ["failed"]);
+}
+
+#[tokio::test]
+async fn finished_lease_is_not_finished_again_on_drop() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    lease.fail().await.unwrap();
+
+    assert_eq!(server.finish_outcomes(), ["failed"]);
+}
+
+#[tokio::test]
+async fn usage_is_extended_until_lease_is_finished() {
+    let server = FakeServer::start();
+    let lease = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, Duration::from_millis(40))
+        .await
+        .unwrap();
+
+    sleep(Duration::from_millis(200)).await;
+    lease.finish().await.unwrap();
+    let extensions = server.requests("/api/extend-test-db-usage");
+    sleep(Duration::from_millis(100)).await;
+
+    assert!(!extensions.is_empty());
+    assert!(
+        extensions
+            .iter()
+            .all(|request_body| request_body["additionalTimeMs"] == 20)
+    );
+    assert_eq!(server.requests("/api/extend-test-db-usage"), extensions);
+}
+
+#[tokio::test]
+async fn too_short_usage_isnt_leased() {
+    let server = FakeServer::start();
+
+    let result = server
+        .client()
+        .lease_test_db(TEMPLATE_HASH, MIN_USAGE_DURATION - Duration::from_millis(1))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(LeaseTestDbError::UsageDurationIsTooShort { .. })
+    ));
+    assert!(server.requests("/api/get-test-db").is_empty());
+}
+
+// Runs on its own thread and runtime, since a lease dropped on a current_thread runtime blocks
+// the runtime until the usage is finished
+struct FakeServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<(&'static str, Value)>>>,
+}
+
+impl FakeServer {
+    fn start() -> FakeServer {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let router = Router::new()
+            .route(
+                "/api/get-test-db",
+                post({
+                    let requests = requests.clone();
+                    async move |Json(request_body): Json<Value>| {
+                        record(&requests, "/api/get-test-db", request_body);
+                        Json(GetTestDbResponseBody::TestDbWasCreated {
+                            test_db_id: TestDbId::new(1),
+                            db_connection_options: DbConnectionOptionsDto {
+                                host: "localhost".into(),
+                                port: 5432,
+                                username: "postgres".into(),
+                                password: "postgres".into(),
+                                database: "test_db".parse().unwrap(),
+                                connection_strings: None,
+                            },
+                            usage_deadline: Utc::now(),
+                        })
+                    }
+                }),
+            )
+            .route(
+                "/api/extend-test-db-usage",
+                post({
+                    let requests = requests.clone();
+                    async move |Json(request_body): Json<Value>| {
+                        record(&requests, "/api/extend-test-db-usage", request_body);
+                        Json(ExtendTestDbUsageResponseBody::UsageWasExtended {
+                            new_usage_deadline: Utc::now(),
+                        })
+                    }
+                }),
+            )
+            .route(
+                "/api/finish-test-db-usage",
+                post({
+                    let requests = requests.clone();
+                    async move |Json(request_body): Json<Value>| {
+                        record(&requests, "/api/finish-test-db-usage", request_body);
+                        Json(FinishTestDbUsageResponseBody::TestDbWasReleased {})
+                    }
+                }),
+            );
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async move {
+                    let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                    axum::serve(listener, router).await.unwrap();
+                });
+        });
+
+        FakeServer { base_url, requests }
+    }
+
+    fn client(&self) -> PgTempestClient {
+        PgTempestClient::new(self.base_url.as_str())
+    }
+
+    fn requests(&self, path: &str) -> Vec<Value> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(request_path, _)| *request_path == path)
+            .map(|(_, request_body)| request_body.clone())
+            .collect()
+    }
+
+    fn finish_outcomes(&self) -> Vec<String> {
+        self.requests("/api/finish-test-db-usage")
+            .into_iter()
+            .map(|request_body| request_body["outcome"].as_str().unwrap().to_string())
+            .collect()
+    }
+}
+
+fn record(requests: &Mutex<Vec<(&'static str, Value)>>, path: &'static str, request_body: Value) {
+    requests.lock().unwrap().push((path, request_body));
+}
@@ -4,4 +4,47 @@ use serde::Deserialize;
 pub struct DbPoolConfigs {
     pub min_size: u8,
     pub creation_retries_delay_in_ms: u64,
+    #[serde(default)]
+    pub reuse: TestDbReuseConfigs,
+    #[serde(default)]
+    pub recycling: TestDbRecyclingConfigs,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TestDbReuseConfigs {
+    pub strictness: TestDbReuseStrictness,
+}
+
+// How a test db is checked for modifications before it's returned to the pool without
+// being recreated. Stats of a db are trusted only when it has no connections
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum TestDbReuseStrictness {
+    // Test dbs are always recreated
+    #[default]
+    Disabled,
+    // Tuple counters of pg_stat_database, which include changes of system catalogs, so DDL
+    // is detected too, and sequence values. Requires Postgres 15+, where counters of a backend
+    // are flushed before it disconnects
+    Counters,
+    // Counters and a fingerprint of relation files and sequence values of the db
+    Fingerprint,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TestDbRecyclingConfigs {
+    pub strategy: TestDbRecyclingStrategy,
+}
+
+// How a modified test db is returned to its pristine state
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum TestDbRecyclingStrategy {
+    // The test db is dropped and copied from the template db again
+    #[default]
+    Recreate,
+    // Modified tables are truncated and their rows are copied from the template db,
+    // modified sequences are reset. Falls back to recreation when catalogs were changed
+    // (DDL, grants, comments, policies, large objects). Requires Postgres 15
+    Truncate,
 }
@@ -1,12 +1,29 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
 use serde::Deserialize;
 
 #[derive(Deserialize)]
 pub struct DbmsConfigs {
     pub inner: InnerDbmsConfigs,
     pub outer: OuterDbmsConfigs,
+    // Named alternatives of `outer` for clients in other networks
+    #[serde(default)]
+    pub profiles: BTreeMap<Box<str>, OuterDbmsConfigs>,
     pub database: Box<str>,
     pub user: Box<str>,
     pub password: Box<str>,
+    #[serde(default)]
+    pub ssl: DbmsSslConfigs,
+}
+
+impl DbmsConfigs {
+    pub fn outer_profile(&self, network_profile: Option<&str>) -> Option<&OuterDbmsConfigs> {
+        match network_profile {
+            Some(network_profile) => self.profiles.get(network_profile),
+            None => Some(&self.outer),
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -19,4 +36,30 @@ pub struct InnerDbmsConfigs {
 pub struct OuterDbmsConfigs {
     pub host: Option<Box<str>>,
     pub port: Option<u16>,
+    // Extra libpq parameters (e.g. sslmode, application_name) added to connection strings
+    #[serde(default)]
+    pub parameters: BTreeMap<Box<str>, Box<str>>,
+}
+
+// SSL of the admin connection to the inner endpoint
+#[derive(Deserialize, Default)]
+pub struct DbmsSslConfigs {
+    #[serde(default)]
+    pub mode: SslMode,
+    pub root_cert_path: Option<PathBuf>,
+    pub client_cert_path: Option<PathBuf>,
+    pub client_key_path: Option<PathBuf>,
+}
+
+// Same semantics as libpq sslmode
+#[derive(Deserialize, Default, Clone, Copy, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum SslMode {
+    Disable,
+    Allow,
+    #[default]
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
 }
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+// Faults injected into create_db, drop_db and alter_db_is_template calls.
+// Probabilities are checked in order: hang, unexpected error, conflict
+#[derive(Deserialize, Clone, Default, Debug)]
+pub struct FaultInjectionConfigs {
+    pub enabled: bool,
+    pub latency_ms: u64,
+    pub latency_jitter_ms: u64,
+    pub hang_probability: f64,
+    pub hang_duration_ms: u64,
+    pub unexpected_error_probability: f64,
+    // Conflicts are DbAlreadyExists for create_db and DbDoesNotExist for the others
+    pub conflict_probability: f64,
+}
+
+impl FaultInjectionConfigs {
+    pub fn validate(&self) -> Result<(), String> {
+        let probabilities = [
+            ("hang_probability", self.hang_probability),
+            (
+                "unexpected_error_probability",
+                self.unexpected_error_probability,
+            ),
+            ("conflict_probability", self.conflict_probability),
+        ];
+
+        for (name, probability) in probabilities {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(format!("{name} must be between 0 and 1"));
+            }
+        }
+
+        if probabilities.iter().map(|(_, x)| x).sum::<f64>() > 1.0 {
+            return Err("Sum of probabilities must not exceed 1".to_string());
+        }
+
+        Ok(())
+    }
+}
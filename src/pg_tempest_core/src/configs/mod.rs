@@ -1,4 +1,5 @@
 pub mod db_pool_configs;
 pub mod dbms_configs;
+pub mod fault_injection_configs;
 pub mod template_initialization_configs;
 pub mod templates_configs;
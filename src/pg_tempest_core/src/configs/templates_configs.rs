@@ -1,10 +1,28 @@
 use crate::configs::template_initialization_configs::TemplateInitializationConfigs;
 use crate::models::value_types::pg_identifier::PgIdentifier;
 use serde::Deserialize;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[derive(Deserialize)]
 pub struct TemplatesConfigs {
     pub initialization: Arc<TemplateInitializationConfigs>,
     pub parent_template_db_name: Option<PgIdentifier>,
+    #[serde(default)]
+    pub import: TemplateImportConfigs,
+    #[serde(default)]
+    pub cache: TemplateCacheConfigs,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TemplateImportConfigs {
+    // Templates can be imported only from pg_dump archives inside of this dir
+    pub archive_dir: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TemplateCacheConfigs {
+    // Finished templates are archived into this dir and restored from it
+    // instead of being initialized again
+    pub dir: Option<PathBuf>,
 }
@@ -0,0 +1,222 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::sleep;
+use tracing::warn;
+
+use crate::{
+    configs::fault_injection_configs::FaultInjectionConfigs,
+    models::{
+        db_settings::DbSettings,
+        value_types::{extension_name::ExtensionName, pg_identifier::PgIdentifier},
+    },
+    pg_client::{
+        AlterDbIsTemplateError, AlterDbSettingsError, CreateDbError, CreateExtensionsError, Db,
+        DbDump, DbDumpFormat, DbDumpReader, DbModificationStats, DbSnapshot, DropDbError, PgClient,
+        ResetDbError, RestoreDbError,
+    },
+    utils::errors::BoxDynError,
+};
+
+// Current fault injection configs, shared between the PgClient decorator
+// and whatever changes them at runtime
+pub struct FaultInjection {
+    configs: RwLock<FaultInjectionConfigs>,
+}
+
+impl FaultInjection {
+    pub fn new(configs: FaultInjectionConfigs) -> Result<FaultInjection, BoxDynError> {
+        configs.validate()?;
+
+        Ok(FaultInjection {
+            configs: RwLock::new(configs),
+        })
+    }
+
+    pub fn configs(&self) -> FaultInjectionConfigs {
+        self.configs.read().unwrap().clone()
+    }
+
+    pub fn set_configs(&self, configs: FaultInjectionConfigs) -> Result<(), String> {
+        configs.validate()?;
+        *self.configs.write().unwrap() = configs;
+
+        Ok(())
+    }
+
+    async fn inject(&self, db_name: &PgIdentifier) -> Option<InjectedFault> {
+        let configs = self.configs();
+        if !configs.enabled {
+            return None;
+        }
+
+        let latency_ms = configs.latency_ms + rand::random_range(0..=configs.latency_jitter_ms);
+        if latency_ms > 0 {
+            sleep(Duration::from_millis(latency_ms)).await;
+        }
+
+        let roll = rand::random::<f64>();
+        let mut threshold = configs.hang_probability;
+
+        if roll < threshold {
+            warn!("Injecting hang into an operation on {db_name}");
+            sleep(Duration::from_millis(configs.hang_duration_ms)).await;
+            return Some(InjectedFault::Unexpected);
+        }
+
+        threshold += configs.unexpected_error_probability;
+        if roll < threshold {
+            warn!("Injecting unexpected error into an operation on {db_name}");
+            return Some(InjectedFault::Unexpected);
+        }
+
+        threshold += configs.conflict_probability;
+        if roll < threshold {
+            warn!("Injecting conflict into an operation on {db_name}");
+            return Some(InjectedFault::Conflict);
+        }
+
+        None
+    }
+}
+
+enum InjectedFault {
+    Unexpected,
+    Conflict,
+}
+
+fn injected_error(db_name: &PgIdentifier) -> BoxDynError {
+    format!("Fault was injected into an operation on {db_name}").into()
+}
+
+pub struct FaultInjectingPgClient {
+    inner: Arc<dyn PgClient>,
+    fault_injection: Arc<FaultInjection>,
+}
+
+impl FaultInjectingPgClient {
+    pub fn new(
+        inner: Arc<dyn PgClient>,
+        fault_injection: Arc<FaultInjection>,
+    ) -> FaultInjectingPgClient {
+        FaultInjectingPgClient {
+            inner,
+            fault_injection,
+        }
+    }
+}
+
+#[async_trait]
+impl PgClient for FaultInjectingPgClient {
+    async fn alter_db_is_template(
+        &self,
+        db_name: PgIdentifier,
+        is_template: bool,
+    ) -> Result<(), AlterDbIsTemplateError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => {
+                Err(AlterDbIsTemplateError::DbDoesNotExists { db_name })
+            }
+            None => self.inner.alter_db_is_template(db_name, is_template).await,
+        }
+    }
+
+    async fn alter_db_settings(
+        &self,
+        db_name: PgIdentifier,
+        settings: &DbSettings,
+    ) -> Result<(), AlterDbSettingsError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => Err(AlterDbSettingsError::DbDoesNotExist { db_name }),
+            None => self.inner.alter_db_settings(db_name, settings).await,
+        }
+    }
+
+    async fn create_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: Option<PgIdentifier>,
+        is_template: bool,
+    ) -> Result<(), CreateDbError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => Err(CreateDbError::DbAlreadyExists { db_name }),
+            None => {
+                self.inner
+                    .create_db(db_name, template_db_name, is_template)
+                    .await
+            }
+        }
+    }
+
+    async fn create_extensions(
+        &self,
+        db_name: PgIdentifier,
+        extension_names: &[ExtensionName],
+    ) -> Result<(), CreateExtensionsError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => Err(CreateExtensionsError::DbDoesNotExist { db_name }),
+            None => self.inner.create_extensions(db_name, extension_names).await,
+        }
+    }
+
+    async fn drop_db(&self, db_name: PgIdentifier) -> Result<(), DropDbError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => Err(DropDbError::DbDoesNotExist { db_name }),
+            None => self.inner.drop_db(db_name).await,
+        }
+    }
+
+    async fn get_dbs(&self) -> Result<Vec<Db>, BoxDynError> {
+        self.inner.get_dbs().await
+    }
+
+    async fn get_db_modification_stats(
+        &self,
+        db_name: PgIdentifier,
+        with_fingerprint: bool,
+    ) -> Result<DbModificationStats, BoxDynError> {
+        self.inner
+            .get_db_modification_stats(db_name, with_fingerprint)
+            .await
+    }
+
+    async fn restore_db(&self, db_name: PgIdentifier, dump: &DbDump) -> Result<(), RestoreDbError> {
+        self.inner.restore_db(db_name, dump).await
+    }
+
+    async fn get_db_snapshot(&self, db_name: PgIdentifier) -> Result<DbSnapshot, BoxDynError> {
+        self.inner.get_db_snapshot(db_name).await
+    }
+
+    async fn reset_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: PgIdentifier,
+        snapshot: &DbSnapshot,
+    ) -> Result<(), ResetDbError> {
+        match self.fault_injection.inject(&db_name).await {
+            Some(InjectedFault::Unexpected) => Err(injected_error(&db_name).into()),
+            Some(InjectedFault::Conflict) => Err(ResetDbError::DbIsUsed { db_name }),
+            None => {
+                self.inner
+                    .reset_db(db_name, template_db_name, snapshot)
+                    .await
+            }
+        }
+    }
+
+    async fn dump_db(
+        &self,
+        db_name: PgIdentifier,
+        format: DbDumpFormat,
+        with_ownership: bool,
+    ) -> Result<DbDumpReader, BoxDynError> {
+        self.inner.dump_db(db_name, format, with_ownership).await
+    }
+}
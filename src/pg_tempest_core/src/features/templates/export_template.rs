@@ -0,0 +1,159 @@
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use tokio::{
+    io::{AsyncRead, ReadBuf},
+    time::sleep,
+};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    metadata::template_metadata::TemplateInitializationState,
+    models::value_types::{
+        export_db_name::ExportDbName, template_db_name::TemplateDbName, template_hash::TemplateHash,
+    },
+    pg_client::{DbDumpFormat, DbDumpReader, DropDbError, PgClient},
+    utils::errors::BoxDynError,
+};
+
+const EXPORT_DB_DROP_ATTEMPTS: u32 = 50;
+const EXPORT_DB_DROP_RETRIES_DELAY: Duration = Duration::from_millis(100);
+
+pub struct ExportTemplateOkResult {
+    pub dump: TemplateExport,
+}
+
+#[derive(Debug)]
+pub enum ExportTemplateErrorResult {
+    TemplateWasNotFound,
+    InitializationIsNotFinished,
+    Unknown { inner: BoxDynError },
+}
+
+impl PgTempestCore {
+    // Exports are restored by other dbms, whose roles differ, so owners and privileges are omitted
+    #[instrument(skip_all)]
+    pub async fn export_template(
+        &self,
+        template_hash: TemplateHash,
+        format: DbDumpFormat,
+    ) -> Result<ExportTemplateOkResult, ExportTemplateErrorResult> {
+        self.dump_template(template_hash, format, false).await
+    }
+
+    // The template db is copied and the copy is dumped, because the template db
+    // can't be copied into test dbs while pg_dump is connected to it
+    pub(crate) async fn dump_template(
+        &self,
+        template_hash: TemplateHash,
+        format: DbDumpFormat,
+        with_ownership: bool,
+    ) -> Result<ExportTemplateOkResult, ExportTemplateErrorResult> {
+        let export_id = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let Some(template) = template else {
+                    warn!("Template {template_hash} was not found");
+                    return Err(ExportTemplateErrorResult::TemplateWasNotFound);
+                };
+
+                if !matches!(
+                    template.initialization_state,
+                    TemplateInitializationState::Finished
+                ) {
+                    warn!("Template {template_hash} initialization is not finished");
+                    return Err(ExportTemplateErrorResult::InitializationIsNotFinished);
+                }
+
+                Ok(template.next_export_id())
+            })
+            .await?;
+
+        let export_db_name = ExportDbName::new(template_hash, export_id);
+
+        self.pg_client
+            .create_db(
+                export_db_name.clone().into(),
+                Some(TemplateDbName::new(template_hash).into()),
+                false,
+            )
+            .await
+            .map_err(|err| ExportTemplateErrorResult::Unknown { inner: err.into() })?;
+
+        let export_db = ExportDb {
+            pg_client: self.pg_client.clone(),
+            export_db_name,
+        };
+
+        let reader = self
+            .pg_client
+            .dump_db(
+                export_db.export_db_name.clone().into(),
+                format,
+                with_ownership,
+            )
+            .await
+            .map_err(|inner| ExportTemplateErrorResult::Unknown { inner })?;
+
+        info!("Template {template_hash} export {export_id:04X} was started");
+
+        Ok(ExportTemplateOkResult {
+            dump: TemplateExport { reader, export_db },
+        })
+    }
+}
+
+// Dump of a template, the export db is dropped when the dump is read or abandoned
+pub struct TemplateExport {
+    reader: DbDumpReader,
+    // Dropped after the reader, which stops pg_dump. The connection of pg_dump can still be open
+    // when the drop of the db is started, so the drop is retried until it's closed
+    export_db: ExportDb,
+}
+
+impl AsyncRead for TemplateExport {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let result = ready!(self.reader.as_mut().poll_read(cx, buf));
+
+        if let Err(err) = &result {
+            warn!("Export {} failed: {err}", self.export_db.export_db_name);
+        }
+
+        Poll::Ready(result)
+    }
+}
+
+struct ExportDb {
+    pg_client: Arc<dyn PgClient>,
+    export_db_name: ExportDbName,
+}
+
+impl Drop for ExportDb {
+    fn drop(&mut self) {
+        let pg_client = self.pg_client.clone();
+        let export_db_name = self.export_db_name.clone();
+
+        tokio::spawn(async move {
+            for _ in 0..EXPORT_DB_DROP_ATTEMPTS {
+                match pg_client.drop_db(export_db_name.clone().into()).await {
+                    Ok(()) | Err(DropDbError::DbDoesNotExist { .. }) => return,
+                    Err(DropDbError::DbIsUsed { .. }) => sleep(EXPORT_DB_DROP_RETRIES_DELAY).await,
+                    Err(err) => {
+                        warn!("Failed to drop {export_db_name}: {err}");
+                        return;
+                    }
+                }
+            }
+
+            warn!("Failed to drop {export_db_name}, since it's still used");
+        });
+    }
+}
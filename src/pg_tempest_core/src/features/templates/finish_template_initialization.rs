@@ -6,11 +6,13 @@ use crate::utils::option_ext::OptionExt;
 use crate::{
     PgTempestCore,
     metadata::template_metadata::{
-        TemplateAwaitingResult, TemplateInitializationState, TestDbMetadata, TestDbState,
+        TemplateAwaitingResult, TemplateInitializationState, TemplateMetadata, TestDbMetadata,
+        TestDbState,
     },
     models::value_types::template_hash::TemplateHash,
 };
 
+#[derive(Debug)]
 pub enum FinishTemplateInitializationErrorResult {
     TemplateWasNotFound,
     InitializationIsFailed { reason: Option<Arc<str>> },
@@ -52,24 +54,7 @@ impl PgTempestCore {
                         Err(FinishTemplateInitializationErrorResult::InitializationIsNotStarted)
                     }
                     TemplateInitializationState::InProgress { .. } => {
-                        template.initialization_state = TemplateInitializationState::Finished;
-
-                        while let Some(awaiter) = template.template_awaiters.pop_front() {
-                            let _ = awaiter
-                                .result_sender
-                                .send(TemplateAwaitingResult::InitializationIsFinished);
-                        }
-
-                        for _ in 0..self.db_pool_configs.min_size {
-                            let test_db = TestDbMetadata {
-                                id: template.next_test_db_id(),
-                                state: TestDbState::Creating {},
-                            };
-
-                            tokio::spawn(self.clone().recreate_test_db(template_hash, test_db.id));
-
-                            template.test_dbs.push(test_db);
-                        }
+                        self.finish_initialization(template);
 
                         info!("Template {template_hash} initialization was finished");
                         Ok(())
@@ -78,4 +63,38 @@ impl PgTempestCore {
             })
             .await
     }
+
+    // Notifies the awaiters, fills the pool and caches the template
+    pub(crate) fn finish_initialization(self: &Arc<Self>, template: &mut TemplateMetadata) {
+        let template_hash = template.template_hash;
+        template.initialization_state = TemplateInitializationState::Finished;
+
+        while let Some(awaiter) = template.template_awaiters.pop_front() {
+            let _ = awaiter
+                .result_sender
+                .send(TemplateAwaitingResult::InitializationIsFinished);
+        }
+
+        for _ in 0..self.db_pool_configs.min_size {
+            let test_db = TestDbMetadata {
+                id: template.next_test_db_id(),
+                state: TestDbState::Creating {},
+                checkpoint_ids: Vec::new(),
+                checkpoint_id_sequence: 0,
+                pristine_stats: None,
+                pristine_snapshot: None,
+            };
+
+            tokio::spawn(self.clone().recreate_test_db(template_hash, test_db.id));
+
+            template.test_dbs.push(test_db);
+        }
+
+        if self.templates_configs.cache.dir.is_some() {
+            tokio::spawn(
+                self.clone()
+                    .cache_template(template_hash, template.parent_template_db_name.clone()),
+            );
+        }
+    }
 }
@@ -0,0 +1,54 @@
+use tracing::instrument;
+
+use crate::{
+    PgTempestCore,
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+
+pub struct TemplateSummary {
+    pub template_hash: TemplateHash,
+    pub initialization_state: TemplateInitializationState,
+    pub test_dbs: Vec<TestDbSummary>,
+}
+
+pub struct TestDbSummary {
+    pub id: TestDbId,
+    pub state: TestDbState,
+}
+
+impl PgTempestCore {
+    #[instrument(skip_all)]
+    pub async fn get_templates(&self) -> Vec<TemplateSummary> {
+        let template_hashes = self.metadata_storage.get_all_template_hashes().await;
+        let mut templates = Vec::with_capacity(template_hashes.len());
+
+        for template_hash in template_hashes {
+            templates.extend(self.get_template(template_hash).await);
+        }
+
+        templates
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_template(&self, template_hash: TemplateHash) -> Option<TemplateSummary> {
+        self.metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let template = template.as_ref()?;
+
+                Some(TemplateSummary {
+                    template_hash,
+                    initialization_state: template.initialization_state.clone(),
+                    test_dbs: template
+                        .test_dbs
+                        .iter()
+                        .map(|test_db| TestDbSummary {
+                            id: test_db.id,
+                            state: test_db.state.clone(),
+                        })
+                        .collect(),
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::{Component, Path};
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::sleep;
+use tracing::{debug, error, info, instrument, warn};
+
+use crate::PgTempestCore;
+use crate::features::templates::finish_template_initialization::FinishTemplateInitializationErrorResult;
+use crate::features::templates::start_template_initialization::StartTemplateInitializationResult;
+use crate::models::db_settings::DbSettings;
+use crate::models::value_types::pg_identifier::PgIdentifier;
+use crate::models::value_types::template_db_name::TemplateDbName;
+use crate::models::value_types::template_hash::TemplateHash;
+use crate::pg_client::{DbDump, RestoreDbError};
+use crate::utils::errors::BoxDynError;
+
+pub enum TemplateDump {
+    Sql { script: Box<str> },
+    // Path relative to the archive dir of the configs
+    Archive { path: Box<Path> },
+}
+
+pub enum ImportTemplateResult {
+    TemplateWasImported,
+    InitializationIsInProgress,
+    InitializationIsFinished,
+    InitializationIsFailed { reason: Option<Arc<str>> },
+    ArchiveImportIsDisabled,
+    ArchivePathIsInvalid,
+}
+
+impl PgTempestCore {
+    // The core acts as the initializer of the template: the template db is created as usual,
+    // the dump is restored into it and the initialization is finished or failed by its result
+    #[instrument(skip_all)]
+    pub async fn import_template(
+        self: Arc<Self>,
+        template_hash: TemplateHash,
+        dump: TemplateDump,
+        initialization_duration: Duration,
+        parent_template_db_name: Option<PgIdentifier>,
+    ) -> Result<ImportTemplateResult, BoxDynError> {
+        // Checked before initialization is started so that it isn't left to time out
+        let dump = match dump {
+            TemplateDump::Sql { script } => DbDump::Sql { script },
+            TemplateDump::Archive { path } => {
+                let Some(archive_dir) = &self.templates_configs.import.archive_dir else {
+                    warn!("Template {template_hash} can't be imported from an archive");
+                    return Ok(ImportTemplateResult::ArchiveImportIsDisabled);
+                };
+
+                // Archives outside of the dir aren't exposed to clients
+                if path.as_os_str().is_empty()
+                    || !path
+                        .components()
+                        .all(|component| matches!(component, Component::Normal(_)))
+                {
+                    warn!("Archive path {path:?} of template {template_hash} is invalid");
+                    return Ok(ImportTemplateResult::ArchivePathIsInvalid);
+                }
+
+                DbDump::Archive {
+                    path: archive_dir.join(path),
+                    with_ownership: false,
+                }
+            }
+        };
+
+        let start_result = self
+            .clone()
+            .start_template_initialization(
+                template_hash,
+                initialization_duration,
+                parent_template_db_name,
+                None,
+                DbSettings::new(),
+                Vec::new(),
+            )
+            .await?;
+
+        match start_result {
+            StartTemplateInitializationResult::InitializationWasStarted { .. } => {}
+            StartTemplateInitializationResult::InitializationIsInProgress => {
+                return Ok(ImportTemplateResult::InitializationIsInProgress);
+            }
+            StartTemplateInitializationResult::InitializationIsFinished => {
+                return Ok(ImportTemplateResult::InitializationIsFinished);
+            }
+            StartTemplateInitializationResult::InitializationIsFailed { reason } => {
+                return Ok(ImportTemplateResult::InitializationIsFailed { reason });
+            }
+            StartTemplateInitializationResult::NetworkProfileWasNotFound => {
+                return Err("Default network profile was not found".into());
+            }
+        }
+
+        let template_db_name = TemplateDbName::new(template_hash);
+        let restoration_result = {
+            let mut restoration = pin!(self.pg_client.restore_db(template_db_name.into(), &dump));
+
+            // Like any initializer, the core extends the initialization while it's busy, so that
+            // the template db isn't recreated under a restoration longer than the initialization duration
+            tokio::select! {
+                restoration_result = &mut restoration => restoration_result,
+                () = self.extend_initialization_during_import(template_hash, initialization_duration) => {
+                    restoration.await
+                }
+            }
+        };
+
+        if let Err(err) = restoration_result {
+            let reason: Arc<str> = match err {
+                RestoreDbError::DumpWasNotRestored { reason }
+                | RestoreDbError::ArchiveIsUnreadable { reason } => reason.into(),
+                RestoreDbError::Unexpected(err) => err.to_string().into(),
+            };
+            info!("Template {template_hash} dump was not restored: {reason}");
+
+            if let Err(err) = self
+                .clone()
+                .fail_template_initialization(template_hash, Some(reason.clone()))
+                .await
+            {
+                error!("Failed to fail template {template_hash} initialization: {err}");
+            }
+
+            return Ok(ImportTemplateResult::InitializationIsFailed {
+                reason: Some(reason),
+            });
+        }
+
+        match self.finish_template_initialization(template_hash).await {
+            Ok(()) => {
+                info!("Template {template_hash} was imported");
+                Ok(ImportTemplateResult::TemplateWasImported)
+            }
+            // The initialization deadline could pass during the restoration
+            Err(FinishTemplateInitializationErrorResult::InitializationIsFailed { reason }) => {
+                debug!("Template {template_hash} initialization was failed during import");
+                Ok(ImportTemplateResult::InitializationIsFailed { reason })
+            }
+            Err(err) => {
+                Err(format!("Template {template_hash} import was interrupted: {err:?}").into())
+            }
+        }
+    }
+
+    // Returns only when the initialization can't be extended, e.g. it was failed by a client
+    async fn extend_initialization_during_import(
+        &self,
+        template_hash: TemplateHash,
+        initialization_duration: Duration,
+    ) {
+        let extension_interval = initialization_duration / 2;
+
+        if extension_interval.is_zero() {
+            return;
+        }
+
+        loop {
+            sleep(extension_interval).await;
+
+            if self
+                .extend_template_initialization(template_hash, extension_interval)
+                .await
+                .is_err()
+            {
+                warn!("Template {template_hash} initialization can't be extended during import");
+                return;
+            }
+        }
+    }
+}
@@ -1,6 +1,13 @@
+pub mod export_template;
 pub mod extend_template_initialization;
 pub mod fail_template_initialization;
 pub mod finish_template_initialization;
+pub mod get_templates;
+pub mod import_template;
+pub mod purge_templates;
 mod recreate_template_db;
 pub mod start_template_initialization;
+mod template_cache;
+mod template_extensions;
 mod template_initialization_deadline_processing;
+mod template_settings;
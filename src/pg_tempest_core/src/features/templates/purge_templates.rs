@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    models::value_types::{
+        checkpoint_db_name::CheckpointDbName, export_db_name::ExportDbName,
+        pg_identifier::PgIdentifier, template_db_name::TemplateDbName, template_hash::TemplateHash,
+        test_db_name::TestDbName,
+    },
+    pg_client::DropDbError,
+    pg_client_extensions::PgClientExtensions,
+    utils::errors::BoxDynError,
+};
+
+pub struct PurgeTemplatesOkResult {
+    pub purged_template_hashes: Vec<TemplateHash>,
+    pub dropped_dbs: Vec<PgIdentifier>,
+    pub failed_dbs: Vec<DbDropFailure>,
+}
+
+pub struct DbDropFailure {
+    pub db_name: PgIdentifier,
+    pub reason: Arc<str>,
+}
+
+impl PgTempestCore {
+    // Forgets all templates and drops their dbs. Dbs of unknown templates, e.g. left by previous
+    // runs of the server or belonging to other servers sharing the dbms, are dropped only if
+    // `include_unknown_dbs` is set
+    #[instrument(skip_all)]
+    pub async fn purge_templates(
+        &self,
+        include_unknown_dbs: bool,
+    ) -> Result<PurgeTemplatesOkResult, BoxDynError> {
+        let template_hashes = self.metadata_storage.get_all_template_hashes().await;
+        let mut purged_template_hashes = Vec::new();
+
+        for template_hash in template_hashes {
+            // Dropping of the metadata drops its awaiters, so their requests are interrupted
+            let template = self
+                .metadata_storage
+                .execute_under_lock(template_hash, |template| template.take())
+                .await;
+
+            if template.is_some() {
+                info!("Template {template_hash} was purged");
+                purged_template_hashes.push(template_hash);
+            }
+        }
+
+        let mut test_db_names = Vec::new();
+        let mut template_db_names = Vec::new();
+
+        for db in self.pg_client.get_dbs().await? {
+            let (template_hash, db_name, is_template_db) =
+                if let Ok(checkpoint_db_name) = CheckpointDbName::try_from(db.name.clone()) {
+                    let template_hash = *checkpoint_db_name.as_ref();
+                    (template_hash, PgIdentifier::from(checkpoint_db_name), false)
+                } else if let Ok(export_db_name) = ExportDbName::try_from(db.name.clone()) {
+                    let template_hash = *export_db_name.as_ref();
+                    (template_hash, PgIdentifier::from(export_db_name), false)
+                } else if let Ok(test_db_name) = TestDbName::try_from(db.name.clone()) {
+                    let template_hash = *test_db_name.as_ref();
+                    (template_hash, PgIdentifier::from(test_db_name), false)
+                } else if let Ok(template_db_name) = TemplateDbName::try_from(db.name) {
+                    let template_hash = TemplateHash::from(template_db_name.clone());
+                    (template_hash, PgIdentifier::from(template_db_name), true)
+                } else {
+                    continue;
+                };
+
+            if !include_unknown_dbs && !purged_template_hashes.contains(&template_hash) {
+                continue;
+            }
+
+            match is_template_db {
+                true => template_db_names.push(db_name),
+                false => test_db_names.push(db_name),
+            }
+        }
+
+        let mut dropped_dbs = Vec::new();
+        let mut failed_dbs = Vec::new();
+
+        // Test, checkpoint and export dbs are dropped first,
+        // because a template can't be dropped while it's copied
+        for db_name in test_db_names {
+            match self.pg_client.drop_db(db_name.clone()).await {
+                Ok(()) | Err(DropDbError::DbDoesNotExist { .. }) => dropped_dbs.push(db_name),
+                Err(err) => {
+                    warn!("Failed to drop {db_name}: {err}");
+                    failed_dbs.push(DbDropFailure {
+                        db_name,
+                        reason: err.to_string().into(),
+                    });
+                }
+            }
+        }
+
+        for db_name in template_db_names {
+            match self.pg_client.drop_template_db(db_name.clone()).await {
+                Ok(()) => dropped_dbs.push(db_name),
+                Err(err) => {
+                    warn!("Failed to drop {db_name}: {err}");
+                    failed_dbs.push(DbDropFailure {
+                        db_name,
+                        reason: err.to_string().into(),
+                    });
+                }
+            }
+        }
+
+        info!(
+            "{} templates were purged, {} dbs were dropped, {} dbs were not dropped",
+            purged_template_hashes.len(),
+            dropped_dbs.len(),
+            failed_dbs.len()
+        );
+
+        Ok(PurgeTemplatesOkResult {
+            purged_template_hashes,
+            dropped_dbs,
+            failed_dbs,
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use std::sync::Arc;
-use tracing::{debug, error};
+use tracing::{debug, error, info};
 
 use crate::models::value_types::pg_identifier::PgIdentifier;
+use crate::pg_client::{AlterDbSettingsError, CreateExtensionsError};
 use crate::pg_client_extensions::RecreateTemplateDbError;
 use crate::utils::errors::{ArcDynError, BoxDynError};
 use crate::{
@@ -17,6 +18,14 @@ impl PgTempestCore {
         template_hash: TemplateHash,
         parent_template_db_name: Option<PgIdentifier>,
     ) {
+        if self
+            .restore_cached_template(template_hash, parent_template_db_name.as_ref())
+            .await
+        {
+            finish_cached_template_initialization(&self, template_hash).await;
+            return;
+        }
+
         let template_db_name = TemplateDbName::new(template_hash);
         let parent_template_db_name =
             parent_template_db_name.or(self.templates_configs.parent_template_db_name.clone());
@@ -29,21 +38,14 @@ impl PgTempestCore {
         match db_creation_result {
             Ok(_) => {
                 debug!("{template_db_name} was created");
-                send_template_awaiting_results(&self, template_hash).await
+                prepare_template_db(&self, template_hash, template_db_name).await
             }
             Err(RecreateTemplateDbError::ParentTemplateDbDoesNotExist {
                 parent_template_db_name,
             }) => {
                 let fail_reason =
-                    format!("Parent template db {parent_template_db_name} was not found").into();
-
-                let fail_result = self
-                    .fail_template_initialization(template_hash, Some(fail_reason))
-                    .await;
-
-                if let Err(err) = fail_result {
-                    error!("{err}");
-                };
+                    format!("Parent template db {parent_template_db_name} was not found");
+                fail_initialization(&self, template_hash, fail_reason).await;
             }
             Err(err) => {
                 send_template_awaiting_unexpected_error(&self, template_hash, err.into()).await;
@@ -52,6 +54,87 @@ impl PgTempestCore {
     }
 }
 
+// Extensions are created before settings are applied, so that a setting like
+// statement_timeout doesn't affect their creation
+async fn prepare_template_db(
+    pg_tempest_core: &Arc<PgTempestCore>,
+    template_hash: TemplateHash,
+    template_db_name: TemplateDbName,
+) {
+    let extensions_creation_result = pg_tempest_core
+        .create_template_extensions(template_hash, template_db_name.clone().into())
+        .await;
+
+    match extensions_creation_result {
+        Ok(()) => {}
+        Err(CreateExtensionsError::ExtensionIsNotAvailable {
+            extension_name,
+            reason,
+        }) => {
+            let fail_reason = format!("Extension {extension_name} is not available: {reason}");
+            fail_initialization(pg_tempest_core, template_hash, fail_reason).await;
+            return;
+        }
+        Err(err) => {
+            send_template_awaiting_unexpected_error(pg_tempest_core, template_hash, err.into())
+                .await;
+            return;
+        }
+    }
+
+    let settings_application_result = pg_tempest_core
+        .apply_template_settings(template_hash, template_db_name.into())
+        .await;
+
+    match settings_application_result {
+        Ok(()) => send_template_awaiting_results(pg_tempest_core, template_hash).await,
+        Err(AlterDbSettingsError::SettingIsInvalid {
+            setting_name,
+            reason,
+        }) => {
+            let fail_reason = format!("Setting {setting_name} is invalid: {reason}");
+            fail_initialization(pg_tempest_core, template_hash, fail_reason).await;
+        }
+        Err(err) => {
+            send_template_awaiting_unexpected_error(pg_tempest_core, template_hash, err.into())
+                .await;
+        }
+    }
+}
+
+async fn fail_initialization(
+    pg_tempest_core: &Arc<PgTempestCore>,
+    template_hash: TemplateHash,
+    reason: String,
+) {
+    let fail_result = pg_tempest_core
+        .clone()
+        .fail_template_initialization(template_hash, Some(reason.into()))
+        .await;
+
+    if let Err(err) = fail_result {
+        error!("{err}");
+    };
+}
+
+async fn finish_cached_template_initialization(
+    pg_tempest_core: &Arc<PgTempestCore>,
+    template_hash: TemplateHash,
+) {
+    pg_tempest_core
+        .metadata_storage
+        .execute_under_lock(template_hash, |template| {
+            let Some(template) = template else {
+                error!("Template {template_hash} was not found after db was restored");
+                return;
+            };
+
+            pg_tempest_core.finish_initialization(template);
+            info!("Template {template_hash} initialization was skipped");
+        })
+        .await
+}
+
 async fn send_template_awaiting_results(
     pg_tempest_core: &PgTempestCore,
     template_hash: TemplateHash,
@@ -8,6 +8,8 @@ use crate::metadata::template_metadata::TemplateAwaitingResult;
 use crate::metadata::template_metadata::TemplateInitializationState;
 use crate::metadata::template_metadata::TemplateMetadata;
 use crate::models::db_connection_options::DbConnectionOptions;
+use crate::models::db_settings::DbSettings;
+use crate::models::value_types::extension_name::ExtensionName;
 use crate::models::value_types::pg_identifier::PgIdentifier;
 use crate::models::value_types::template_db_name::TemplateDbName;
 use crate::models::value_types::template_hash::TemplateHash;
@@ -20,6 +22,7 @@ use tracing::debug;
 use tracing::error;
 use tracing::info;
 use tracing::instrument;
+use tracing::warn;
 
 pub enum StartTemplateInitializationResult {
     InitializationWasStarted {
@@ -31,6 +34,7 @@ pub enum StartTemplateInitializationResult {
     InitializationIsFailed {
         reason: Option<Arc<str>>,
     },
+    NetworkProfileWasNotFound,
 }
 
 impl PgTempestCore {
@@ -40,7 +44,20 @@ impl PgTempestCore {
         template_hash: TemplateHash,
         initialization_duration: Duration,
         parent_template_db_name: Option<PgIdentifier>,
+        network_profile: Option<Box<str>>,
+        settings: DbSettings,
+        extension_names: Vec<ExtensionName>,
     ) -> Result<StartTemplateInitializationResult, BoxDynError> {
+        // Checked before initialization is started so that it isn't left to time out
+        if self
+            .dbms_configs
+            .outer_profile(network_profile.as_deref())
+            .is_none()
+        {
+            warn!("Network profile {network_profile:?} was not found");
+            return Ok(StartTemplateInitializationResult::NetworkProfileWasNotFound);
+        }
+
         let result_receiver: oneshot::Receiver<TemplateAwaitingResult> = self
             .metadata_storage
             .execute_under_lock(template_hash, |template| {
@@ -56,10 +73,14 @@ impl PgTempestCore {
                     *template = Some(TemplateMetadata {
                         template_hash,
                         initialization_state: TemplateInitializationState::Creating,
+                        settings: Arc::new(settings),
+                        extension_names: extension_names.into(),
+                        parent_template_db_name: parent_template_db_name.clone(),
                         template_awaiters,
                         test_dbs: Vec::new(),
                         test_db_awaiters: VecDeque::new(),
                         test_db_id_sequence: 0,
+                        export_id_sequence: 0,
                     });
 
                     tokio::spawn(
@@ -99,6 +120,11 @@ impl PgTempestCore {
                     }
                     TemplateInitializationState::Failed { .. } => {
                         *initialization_state = TemplateInitializationState::Creating;
+                        // Settings, extensions or the parent may be the reason of the failure,
+                        // so the new ones are used
+                        template.settings = Arc::new(settings);
+                        template.extension_names = extension_names.into();
+                        template.parent_template_db_name = parent_template_db_name.clone();
 
                         template.template_awaiters.push_back(TemplateAwaiter {
                             initialization_duration,
@@ -129,20 +155,29 @@ impl PgTempestCore {
             awaiting_result = result_receiver => { awaiting_result }
         };
 
-        // TODO: Remove unwrap
-        match awaiting_result.unwrap() {
+        // An awaiter is dropped without a result only if its template was purged
+        let Ok(awaiting_result) = awaiting_result else {
+            error!("Template {template_hash} was purged during initialization awaiting");
+            return Err(format!("Template {template_hash} was purged").into());
+        };
+
+        match awaiting_result {
             TemplateAwaitingResult::InitializationIsStarted {
                 initialization_deadline,
             } => {
                 info!("Template {template_hash} initialization was started");
                 let template_db_name = TemplateDbName::new(template_hash);
+                let Ok(database_connection_options) = DbConnectionOptions::new_outer(
+                    &self.dbms_configs,
+                    network_profile.as_deref(),
+                    template_db_name.into(),
+                ) else {
+                    return Ok(StartTemplateInitializationResult::NetworkProfileWasNotFound);
+                };
 
                 Ok(
                     StartTemplateInitializationResult::InitializationWasStarted {
-                        database_connection_options: DbConnectionOptions::new_outer(
-                            &self.dbms_configs,
-                            template_db_name.into(),
-                        ),
+                        database_connection_options,
                         initialization_deadline,
                     },
                 )
@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    models::value_types::{
+        pg_identifier::PgIdentifier, template_db_name::TemplateDbName, template_hash::TemplateHash,
+    },
+    pg_client::{DbDump, DbDumpFormat, RestoreDbError},
+    pg_client_extensions::PgClientExtensions,
+    utils::errors::BoxDynError,
+};
+
+// Parent of template dbs restored from the cache. Archives contain objects
+// of the parent template db already, so they are restored into a pristine db
+const CACHED_TEMPLATE_PARENT_DB_NAME: &str = "template0";
+
+// Parent of template dbs when neither the initializer nor the configs set one
+const DEFAULT_PARENT_TEMPLATE_DB_NAME: &str = "template1";
+
+impl PgTempestCore {
+    // Template dbs with the same hash but different parents contain different objects,
+    // so the parent is a part of the key
+    fn cached_template_path(
+        &self,
+        template_hash: TemplateHash,
+        parent_template_db_name: Option<&PgIdentifier>,
+    ) -> Option<PathBuf> {
+        let cache_dir = self.templates_configs.cache.dir.as_ref()?;
+        let parent_template_db_name = match parent_template_db_name
+            .or(self.templates_configs.parent_template_db_name.as_ref())
+        {
+            Some(parent_template_db_name) => parent_template_db_name.to_string(),
+            None => DEFAULT_PARENT_TEMPLATE_DB_NAME.to_string(),
+        };
+
+        Some(cache_dir.join(format!("{template_hash}.{parent_template_db_name}.dump")))
+    }
+
+    // Recreates the template db from the cache. The template db is left in an unknown state
+    // if false is returned, so it must be recreated by the caller
+    pub(crate) async fn restore_cached_template(
+        &self,
+        template_hash: TemplateHash,
+        parent_template_db_name: Option<&PgIdentifier>,
+    ) -> bool {
+        let Some(cached_template_path) =
+            self.cached_template_path(template_hash, parent_template_db_name)
+        else {
+            return false;
+        };
+
+        match tokio::fs::try_exists(&cached_template_path).await {
+            Ok(true) => {}
+            Ok(false) => return false,
+            Err(err) => {
+                warn!("Failed to check cache of template {template_hash}: {err}");
+                return false;
+            }
+        }
+
+        let template_db_name = TemplateDbName::new(template_hash);
+        let db_creation_result = self
+            .pg_client
+            .recreate_template_db(
+                template_db_name.clone().into(),
+                Some(PgIdentifier::new(CACHED_TEMPLATE_PARENT_DB_NAME).unwrap()),
+            )
+            .await;
+
+        if let Err(err) = db_creation_result {
+            warn!("Template db {template_hash} was not created for the cached template: {err}");
+            return false;
+        }
+
+        let dump = DbDump::Archive {
+            path: cached_template_path.clone(),
+            with_ownership: true,
+        };
+        let restoration_result = self
+            .pg_client
+            .restore_db(template_db_name.clone().into(), &dump)
+            .await;
+
+        match restoration_result {
+            Ok(()) => {}
+            // The unreadable archive is replaced when the template is initialized again.
+            // Other errors, like a role missing since the archive was dumped, keep it
+            Err(RestoreDbError::ArchiveIsUnreadable { reason }) => {
+                warn!("Cache of template {template_hash} is unreadable: {reason}");
+
+                if let Err(err) = tokio::fs::remove_file(&cached_template_path).await {
+                    warn!("Failed to remove cache of template {template_hash}: {err}");
+                }
+
+                return false;
+            }
+            Err(err) => {
+                warn!("Template {template_hash} was not restored from the cache: {err}");
+                return false;
+            }
+        }
+
+        if let Err(err) = self
+            .apply_template_settings(template_hash, template_db_name.into())
+            .await
+        {
+            warn!("Settings of cached template {template_hash} were not applied: {err}");
+            return false;
+        }
+
+        info!("Template {template_hash} was restored from the cache");
+        true
+    }
+
+    #[instrument(skip_all)]
+    pub(crate) async fn cache_template(
+        self: Arc<Self>,
+        template_hash: TemplateHash,
+        parent_template_db_name: Option<PgIdentifier>,
+    ) {
+        let Some(cached_template_path) =
+            self.cached_template_path(template_hash, parent_template_db_name.as_ref())
+        else {
+            return;
+        };
+
+        if let Ok(true) = tokio::fs::try_exists(&cached_template_path).await {
+            debug!("Template {template_hash} is already cached");
+            return;
+        }
+
+        // The archive is renamed when it's complete, so that a partial one is never restored.
+        // It's restored by the same dbms, so owners and privileges are kept
+        let partial_path = cached_template_path.with_extension("dump.partial");
+        let caching_result: Result<(), BoxDynError> = async {
+            let mut dump = self
+                .dump_template(template_hash, DbDumpFormat::Custom, true)
+                .await
+                .map_err(|err| format!("Template {template_hash} was not exported: {err:?}"))?
+                .dump;
+
+            let mut file = tokio::fs::File::create(&partial_path).await?;
+            tokio::io::copy(&mut dump, &mut file).await?;
+            file.sync_all().await?;
+            tokio::fs::rename(&partial_path, &cached_template_path).await?;
+
+            Ok(())
+        }
+        .await;
+
+        match caching_result {
+            Ok(()) => info!("Template {template_hash} was cached"),
+            Err(err) => {
+                warn!("Failed to cache template {template_hash}: {err}");
+                let _ = tokio::fs::remove_file(&partial_path).await;
+            }
+        }
+    }
+}
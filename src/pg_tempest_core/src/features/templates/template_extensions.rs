@@ -0,0 +1,32 @@
+use crate::{
+    PgTempestCore,
+    models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash},
+    pg_client::CreateExtensionsError,
+};
+
+impl PgTempestCore {
+    // Extensions are created with the admin connection, so that initializers don't need
+    // privileges for it. Test dbs get them along with the rest of the template db
+    pub(crate) async fn create_template_extensions(
+        &self,
+        template_hash: TemplateHash,
+        template_db_name: PgIdentifier,
+    ) -> Result<(), CreateExtensionsError> {
+        let extension_names = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                template
+                    .as_ref()
+                    .map(|template| template.extension_names.clone())
+            })
+            .await;
+
+        let Some(extension_names) = extension_names.filter(|names| !names.is_empty()) else {
+            return Ok(());
+        };
+
+        self.pg_client
+            .create_extensions(template_db_name, &extension_names)
+            .await
+    }
+}
@@ -3,24 +3,36 @@ use crate::metadata::template_metadata::TemplateInitializationState;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 impl PgTempestCore {
     pub fn start_template_initialization_deadline_handling(self: Arc<Self>) {
+        let delay = Duration::from_millis(
+            self.templates_configs
+                .initialization
+                .max_deadline_handling_delay_ms,
+        );
+        // The loop must not keep the core alive, so it stops when the core is dropped
+        let weak_tempest_core = Arc::downgrade(&self);
+
         tokio::spawn(async move {
             loop {
-                let delay = Duration::from_millis(
-                    self.templates_configs
-                        .initialization
-                        .max_deadline_handling_delay_ms,
-                );
-
                 sleep(delay).await;
 
-                let template_hashes = self.metadata_storage.get_all_template_hashes().await;
+                let Some(tempest_core) = weak_tempest_core.upgrade() else {
+                    debug!(
+                        "Core was dropped. Template initialization deadline handling is stopped"
+                    );
+                    return;
+                };
+
+                let template_hashes = tempest_core
+                    .metadata_storage
+                    .get_all_template_hashes()
+                    .await;
 
                 for template_hash in template_hashes {
-                    self.metadata_storage
+                    tempest_core.metadata_storage
                         .execute_under_lock(template_hash, |template| {
                             let Some(template) = template else {
                                 error!("Template {template_hash} was not found");
@@ -30,11 +42,11 @@ impl PgTempestCore {
                             if let TemplateInitializationState::InProgress {
                                 initialization_deadline
                             } = &template.initialization_state
-                                && *initialization_deadline <= self.clock.now()
+                                && *initialization_deadline <= tempest_core.clock.now()
                             {
                                 info!("Template {template_hash} initialization deadline  was exceeded. Failing");
                                 tokio::spawn(
-                                    self.clone().fail_template_initialization(
+                                    tempest_core.clone().fail_template_initialization(
                                         template_hash,
                                         Some(format!("Template {template_hash} initialization deadline was exceeded").into()),
                                     ),
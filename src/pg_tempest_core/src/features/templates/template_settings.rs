@@ -0,0 +1,27 @@
+use crate::{
+    PgTempestCore,
+    models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash},
+    pg_client::AlterDbSettingsError,
+};
+
+impl PgTempestCore {
+    // Settings aren't copied by CREATE DATABASE, so they are applied to each db of a template
+    pub(crate) async fn apply_template_settings(
+        &self,
+        template_hash: TemplateHash,
+        db_name: PgIdentifier,
+    ) -> Result<(), AlterDbSettingsError> {
+        let settings = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                template.as_ref().map(|template| template.settings.clone())
+            })
+            .await;
+
+        let Some(settings) = settings.filter(|settings| !settings.is_empty()) else {
+            return Ok(());
+        };
+
+        self.pg_client.alter_db_settings(db_name, &settings).await
+    }
+}
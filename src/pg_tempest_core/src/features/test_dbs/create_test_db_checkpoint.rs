@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    metadata::template_metadata::TestDbState,
+    models::value_types::{
+        checkpoint_db_name::CheckpointDbName, checkpoint_id::CheckpointId,
+        template_hash::TemplateHash, test_db_id::TestDbId, test_db_name::TestDbName,
+    },
+    pg_client::CreateDbError,
+    utils::errors::BoxDynError,
+};
+
+pub struct CreateTestDbCheckpointOkResult {
+    pub checkpoint_id: CheckpointId,
+}
+
+#[derive(Debug)]
+pub enum CreateTestDbCheckpointErrorResult {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotUsed,
+    TestDbHasConnections,
+    Unknown { inner: BoxDynError },
+}
+
+impl PgTempestCore {
+    // A checkpoint is a copy of the leased test db, so the test db must have no connections
+    // while it's created
+    #[instrument(skip_all)]
+    pub async fn create_test_db_checkpoint(
+        self: Arc<PgTempestCore>,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+    ) -> Result<CreateTestDbCheckpointOkResult, CreateTestDbCheckpointErrorResult> {
+        // The id is reserved before the db is created, so that the db is dropped
+        // if the test db is recreated in the meantime
+        let checkpoint_id = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let Some(template) = template else {
+                    warn!("Template {template_hash} was not found");
+                    return Err(CreateTestDbCheckpointErrorResult::TemplateWasNotFound);
+                };
+
+                let test_db = template
+                    .test_dbs
+                    .iter_mut()
+                    .find(|test_db| test_db.id == test_db_id);
+
+                let Some(test_db) = test_db else {
+                    warn!("Test db {template_hash} {test_db_id} was not found");
+                    return Err(CreateTestDbCheckpointErrorResult::TestDbWasNotFound);
+                };
+
+                if !matches!(test_db.state, TestDbState::InUse { .. }) {
+                    warn!("Test db {template_hash} {test_db_id} is not used");
+                    return Err(CreateTestDbCheckpointErrorResult::TestDbIsNotUsed);
+                }
+
+                let checkpoint_id = test_db.next_checkpoint_id();
+                test_db.checkpoint_ids.push(checkpoint_id);
+
+                Ok(checkpoint_id)
+            })
+            .await?;
+
+        let checkpoint_db_name = CheckpointDbName::new(template_hash, test_db_id, checkpoint_id);
+        let test_db_name = TestDbName::new(template_hash, test_db_id);
+
+        let creation_result = self
+            .pg_client
+            .create_db(
+                checkpoint_db_name.clone().into(),
+                Some(test_db_name.into()),
+                false,
+            )
+            .await;
+
+        let is_checkpoint_reserved =
+            self.metadata_storage
+                .execute_under_lock(template_hash, |template| {
+                    let Some(test_db) = template.as_mut().and_then(|template| {
+                        template.test_dbs.iter_mut().find(|x| x.id == test_db_id)
+                    }) else {
+                        return false;
+                    };
+
+                    if creation_result.is_err() {
+                        test_db.checkpoint_ids.retain(|x| *x != checkpoint_id);
+                    }
+
+                    test_db.checkpoint_ids.contains(&checkpoint_id)
+                })
+                .await;
+
+        match creation_result {
+            Ok(()) => {}
+            Err(CreateDbError::TemplateDbIsUsed { .. }) => {
+                warn!("Test db {template_hash} {test_db_id} has connections");
+                return Err(CreateTestDbCheckpointErrorResult::TestDbHasConnections);
+            }
+            Err(err) => {
+                return Err(CreateTestDbCheckpointErrorResult::Unknown { inner: err.into() });
+            }
+        }
+
+        if !is_checkpoint_reserved {
+            warn!("Test db {template_hash} {test_db_id} usage was finished during checkpointing");
+
+            if let Err(err) = self.pg_client.drop_db(checkpoint_db_name.into()).await {
+                warn!(
+                    "Failed to drop checkpoint {template_hash} {test_db_id} {checkpoint_id}: {err}"
+                );
+            }
+
+            return Err(CreateTestDbCheckpointErrorResult::TestDbIsNotUsed);
+        }
+
+        info!("Checkpoint {template_hash} {test_db_id} {checkpoint_id} was created");
+
+        Ok(CreateTestDbCheckpointOkResult { checkpoint_id })
+    }
+}
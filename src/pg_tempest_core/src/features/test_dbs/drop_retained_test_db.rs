@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    metadata::template_metadata::TestDbState,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+
+#[derive(Debug)]
+pub enum DropRetainedTestDbErrorResult {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotRetained,
+}
+
+impl PgTempestCore {
+    // The retained db is dropped by its recreation, so the test db returns to the pool
+    #[instrument(skip_all)]
+    pub async fn drop_retained_test_db(
+        self: Arc<PgTempestCore>,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+    ) -> Result<(), DropRetainedTestDbErrorResult> {
+        self.metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let Some(template) = template else {
+                    warn!("Template {template_hash} was not found");
+                    return Err(DropRetainedTestDbErrorResult::TemplateWasNotFound);
+                };
+
+                let test_db = template
+                    .test_dbs
+                    .iter_mut()
+                    .find(|test_db| test_db.id == test_db_id);
+
+                let Some(test_db) = test_db else {
+                    warn!("Test db {template_hash} {test_db_id} was not found");
+                    return Err(DropRetainedTestDbErrorResult::TestDbWasNotFound);
+                };
+
+                if !matches!(test_db.state, TestDbState::Retained { .. }) {
+                    warn!("Test db {template_hash} {test_db_id} is not retained");
+                    return Err(DropRetainedTestDbErrorResult::TestDbIsNotRetained);
+                }
+
+                test_db.state = TestDbState::Creating;
+
+                tokio::spawn(self.clone().recreate_test_db(template_hash, test_db_id));
+
+                info!("Retained test db {template_hash} {test_db_id} was dropped");
+
+                Ok(())
+            })
+            .await
+    }
+}
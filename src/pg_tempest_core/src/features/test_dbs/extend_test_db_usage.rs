@@ -13,6 +13,7 @@ pub struct ExtendTestDbUsageOkResult {
     pub new_usage_deadline: DateTime<Utc>,
 }
 
+#[derive(Debug)]
 pub enum ExtendTestDbUsageErrorResult {
     TemplateWasNotFound,
     TestDbWasNotFound,
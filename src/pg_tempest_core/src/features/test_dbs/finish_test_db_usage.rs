@@ -1,26 +1,69 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use chrono::{DateTime, Utc};
 use tracing::{info, instrument, warn};
 
 use crate::{
     PgTempestCore,
     metadata::template_metadata::TestDbState,
-    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    models::{
+        db_connection_options::{DbConnectionOptions, NewOuterDbConnectionOptionsErrorResult},
+        value_types::{
+            template_hash::TemplateHash, test_db_id::TestDbId, test_db_name::TestDbName,
+        },
+    },
 };
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TestDbUsageOutcome {
+    Succeeded,
+    Failed,
+}
+
+pub struct TestDbRetention {
+    pub retention_duration: Duration,
+    pub network_profile: Option<Box<str>>,
+}
+
+pub enum FinishTestDbUsageOkResult {
+    TestDbWasReleased,
+    TestDbWasRetained {
+        connection_options: DbConnectionOptions,
+        retention_deadline: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug)]
 pub enum FinishTestDbUsageErrorResult {
     TemplateWasNotFound,
     TestDbWasNotFound,
     TestDbIsNotUsed,
+    NetworkProfileWasNotFound,
 }
 
 impl PgTempestCore {
+    // A test db is retained for debugging only if its usage failed and retention was requested,
+    // otherwise it's recreated and returned to the pool
     #[instrument(skip_all)]
     pub async fn finish_test_db_usage(
         self: Arc<PgTempestCore>,
         template_hash: TemplateHash,
         test_db_id: TestDbId,
-    ) -> Result<(), FinishTestDbUsageErrorResult> {
+        outcome: TestDbUsageOutcome,
+        retention: Option<TestDbRetention>,
+    ) -> Result<FinishTestDbUsageOkResult, FinishTestDbUsageErrorResult> {
+        let retention = retention.filter(|_| outcome == TestDbUsageOutcome::Failed);
+
+        // Built before the state is changed so that a test db isn't retained without connection options
+        let connection_options = match &retention {
+            Some(retention) => Some(DbConnectionOptions::new_outer(
+                &self.dbms_configs,
+                retention.network_profile.as_deref(),
+                TestDbName::new(template_hash, test_db_id).into(),
+            )?),
+            None => None,
+        };
+
         self.metadata_storage
             .execute_under_lock(template_hash, |template| {
                 let Some(template) = template else {
@@ -43,14 +86,39 @@ impl PgTempestCore {
                     return Err(FinishTestDbUsageErrorResult::TestDbIsNotUsed);
                 }
 
+                if let (Some(retention), Some(connection_options)) = (retention, connection_options)
+                {
+                    let retention_deadline = self.clock.now() + retention.retention_duration;
+                    test_db.state = TestDbState::Retained { retention_deadline };
+
+                    info!(
+                        "Test db {template_hash} {test_db_id} usage failed. It's retained until {retention_deadline}"
+                    );
+
+                    return Ok(FinishTestDbUsageOkResult::TestDbWasRetained {
+                        connection_options,
+                        retention_deadline,
+                    });
+                }
+
                 test_db.state = TestDbState::Creating;
 
                 tokio::spawn(self.clone().recreate_test_db(template_hash, test_db_id));
 
                 info!("Test db {template_hash} {test_db_id} usage was finished");
 
-                Ok(())
+                Ok(FinishTestDbUsageOkResult::TestDbWasReleased)
             })
             .await
     }
 }
+
+impl From<NewOuterDbConnectionOptionsErrorResult> for FinishTestDbUsageErrorResult {
+    fn from(value: NewOuterDbConnectionOptionsErrorResult) -> Self {
+        match value {
+            NewOuterDbConnectionOptionsErrorResult::NetworkProfileWasNotFound => {
+                FinishTestDbUsageErrorResult::NetworkProfileWasNotFound
+            }
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
         TemplateInitializationState, TestDbAwaiter, TestDbMetadata, TestDbState, TestDbUsage,
     },
     models::{
-        db_connection_options::DbConnectionOptions,
+        db_connection_options::{DbConnectionOptions, NewOuterDbConnectionOptionsErrorResult},
         value_types::{
             template_hash::TemplateHash, test_db_id::TestDbId, test_db_name::TestDbName,
         },
@@ -23,9 +23,11 @@ pub struct GetTestDbOkResult {
     pub usage_deadline: DateTime<Utc>,
 }
 
+#[derive(Debug)]
 pub enum GetTestDbErrorResult {
     TemplateWasNotFound,
     TemplateIsNotInitialized,
+    NetworkProfileWasNotFound,
     Unknown { inner: BoxDynError },
 }
 
@@ -35,7 +37,18 @@ impl PgTempestCore {
         self: Arc<PgTempestCore>,
         template_hash: TemplateHash,
         usage_duration: Duration,
+        network_profile: Option<Box<str>>,
     ) -> Result<GetTestDbOkResult, GetTestDbErrorResult> {
+        // Checked before a test db is taken from pool so that it isn't leaked
+        if self
+            .dbms_configs
+            .outer_profile(network_profile.as_deref())
+            .is_none()
+        {
+            warn!("Network profile {network_profile:?} was not found");
+            return Err(GetTestDbErrorResult::NetworkProfileWasNotFound);
+        }
+
         let test_db_usage_or_receiver: TestDbUsageOrReceiver = self
             .metadata_storage
             .execute_under_lock(template_hash, |template| {
@@ -94,6 +107,10 @@ impl PgTempestCore {
                     let test_db = TestDbMetadata {
                         id: test_db_id,
                         state: TestDbState::Creating,
+                        checkpoint_ids: Vec::new(),
+                        checkpoint_id_sequence: 0,
+                        pristine_stats: None,
+                        pristine_snapshot: None,
                     };
 
                     tokio::spawn(self.clone().recreate_test_db(template_hash, test_db_id));
@@ -109,8 +126,10 @@ impl PgTempestCore {
 
         let usage = match test_db_usage_or_receiver {
             TestDbUsageOrReceiver::Usage(usage) => usage,
-            // TODO: Remove unwrap
-            TestDbUsageOrReceiver::Receiver(receiver) => receiver.await.unwrap(),
+            // An awaiter is dropped without a test db only if its template was purged
+            TestDbUsageOrReceiver::Receiver(receiver) => receiver
+                .await
+                .map_err(|_| GetTestDbErrorResult::TemplateWasNotFound)?,
         };
 
         info!(
@@ -119,18 +138,30 @@ impl PgTempestCore {
         );
 
         let test_db_name = TestDbName::new(template_hash, usage.test_db_id);
+        let connection_options = DbConnectionOptions::new_outer(
+            &self.dbms_configs,
+            network_profile.as_deref(),
+            test_db_name.into(),
+        )?;
 
         Ok(GetTestDbOkResult {
             test_db_id: usage.test_db_id,
-            connection_options: DbConnectionOptions::new_outer(
-                &self.dbms_configs,
-                test_db_name.into(),
-            ),
+            connection_options,
             usage_deadline: usage.deadline,
         })
     }
 }
 
+impl From<NewOuterDbConnectionOptionsErrorResult> for GetTestDbErrorResult {
+    fn from(value: NewOuterDbConnectionOptionsErrorResult) -> Self {
+        match value {
+            NewOuterDbConnectionOptionsErrorResult::NetworkProfileWasNotFound => {
+                GetTestDbErrorResult::NetworkProfileWasNotFound
+            }
+        }
+    }
+}
+
 enum TestDbUsageOrReceiver {
     Usage(TestDbUsage),
     Receiver(oneshot::Receiver<TestDbUsage>),
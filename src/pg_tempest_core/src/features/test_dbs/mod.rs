@@ -1,5 +1,11 @@
+pub mod create_test_db_checkpoint;
+pub mod drop_retained_test_db;
 pub mod extend_test_db_usage;
 pub mod finish_test_db_usage;
 pub mod get_test_db;
+pub mod promote_test_db;
 pub mod recreate_test_db;
+pub mod restore_test_db_checkpoint;
 pub mod test_db_creation_retries;
+mod test_db_reset;
+mod test_db_reuse;
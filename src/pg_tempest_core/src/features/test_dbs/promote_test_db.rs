@@ -0,0 +1,171 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    features::test_dbs::finish_test_db_usage::TestDbUsageOutcome,
+    metadata::template_metadata::{TemplateInitializationState, TemplateMetadata, TestDbState},
+    models::value_types::{
+        template_db_name::TemplateDbName, template_hash::TemplateHash, test_db_id::TestDbId,
+        test_db_name::TestDbName,
+    },
+    pg_client::CreateDbError,
+    utils::errors::BoxDynError,
+};
+
+#[derive(Debug)]
+pub enum PromoteTestDbErrorResult {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotUsed,
+    TestDbHasConnections,
+    NewTemplateAlreadyExists,
+    Unknown { inner: BoxDynError },
+}
+
+impl PgTempestCore {
+    // The test db is copied into the template db of the new template, which is finished
+    // right away, and the usage of the test db is finished
+    #[instrument(skip_all)]
+    pub async fn promote_test_db(
+        self: Arc<PgTempestCore>,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+        new_template_hash: TemplateHash,
+    ) -> Result<(), PromoteTestDbErrorResult> {
+        // The new template keeps settings, extensions and the parent of the template
+        // the test db was copied from
+        let (settings, extension_names, parent_template_db_name) = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let Some(template) = template else {
+                    warn!("Template {template_hash} was not found");
+                    return Err(PromoteTestDbErrorResult::TemplateWasNotFound);
+                };
+
+                let test_db = template
+                    .test_dbs
+                    .iter()
+                    .find(|test_db| test_db.id == test_db_id);
+
+                let Some(test_db) = test_db else {
+                    warn!("Test db {template_hash} {test_db_id} was not found");
+                    return Err(PromoteTestDbErrorResult::TestDbWasNotFound);
+                };
+
+                if !matches!(test_db.state, TestDbState::InUse { .. }) {
+                    warn!("Test db {template_hash} {test_db_id} is not used");
+                    return Err(PromoteTestDbErrorResult::TestDbIsNotUsed);
+                }
+
+                Ok((
+                    template.settings.clone(),
+                    template.extension_names.clone(),
+                    template.parent_template_db_name.clone(),
+                ))
+            })
+            .await?;
+
+        // The new template is reserved while the test db is copied, so that initializers
+        // of the new hash await the promotion instead of creating the template db
+        self.metadata_storage
+            .execute_under_lock(new_template_hash, |new_template| {
+                if new_template.is_some() {
+                    warn!("Template {new_template_hash} already exists");
+                    return Err(PromoteTestDbErrorResult::NewTemplateAlreadyExists);
+                }
+
+                *new_template = Some(TemplateMetadata {
+                    template_hash: new_template_hash,
+                    initialization_state: TemplateInitializationState::Creating,
+                    settings,
+                    extension_names,
+                    parent_template_db_name,
+                    template_awaiters: VecDeque::new(),
+                    test_dbs: Vec::new(),
+                    test_db_awaiters: VecDeque::new(),
+                    test_db_id_sequence: 0,
+                    export_id_sequence: 0,
+                });
+
+                Ok(())
+            })
+            .await?;
+
+        let new_template_db_name = TemplateDbName::new(new_template_hash);
+        let copying_result = self
+            .pg_client
+            .create_db(
+                new_template_db_name.clone().into(),
+                Some(TestDbName::new(template_hash, test_db_id).into()),
+                true,
+            )
+            .await;
+
+        if let Err(err) = copying_result {
+            self.clone().release_new_template(new_template_hash).await;
+
+            return match err {
+                CreateDbError::TemplateDbIsUsed { .. } => {
+                    warn!("Test db {template_hash} {test_db_id} has connections");
+                    Err(PromoteTestDbErrorResult::TestDbHasConnections)
+                }
+                err => Err(PromoteTestDbErrorResult::Unknown { inner: err.into() }),
+            };
+        }
+
+        if let Err(err) = self
+            .apply_template_settings(new_template_hash, new_template_db_name.into())
+            .await
+        {
+            self.clone().release_new_template(new_template_hash).await;
+            return Err(PromoteTestDbErrorResult::Unknown { inner: err.into() });
+        }
+
+        self.metadata_storage
+            .execute_under_lock(new_template_hash, |new_template| {
+                let Some(new_template) = new_template else {
+                    error!("Template {new_template_hash} was purged during promotion");
+                    return;
+                };
+
+                self.finish_initialization(new_template);
+            })
+            .await;
+
+        info!("Test db {template_hash} {test_db_id} was promoted to template {new_template_hash}");
+
+        if let Err(err) = self
+            .finish_test_db_usage(
+                template_hash,
+                test_db_id,
+                TestDbUsageOutcome::Succeeded,
+                None,
+            )
+            .await
+        {
+            warn!(
+                "Failed to finish usage of promoted test db {template_hash} {test_db_id}: {err:?}"
+            );
+        }
+
+        Ok(())
+    }
+
+    // Awaiters of the new template initialize it as usual, when the promotion is failed
+    async fn release_new_template(self: Arc<PgTempestCore>, new_template_hash: TemplateHash) {
+        self.metadata_storage
+            .execute_under_lock(new_template_hash, |new_template| match new_template {
+                Some(template) if !template.template_awaiters.is_empty() => {
+                    tokio::spawn(self.clone().recreate_template_db(
+                        new_template_hash,
+                        template.parent_template_db_name.clone(),
+                    ));
+                }
+                _ => *new_template = None,
+            })
+            .await;
+    }
+}
@@ -5,12 +5,13 @@ use crate::{
     PgTempestCore,
     metadata::template_metadata::{TestDbState, TestDbUsage},
     models::value_types::{
-        template_db_name::TemplateDbName, template_hash::TemplateHash, test_db_id::TestDbId,
-        test_db_name::TestDbName,
+        checkpoint_db_name::CheckpointDbName, template_db_name::TemplateDbName,
+        template_hash::TemplateHash, test_db_id::TestDbId, test_db_name::TestDbName,
     },
+    pg_client::{DbModificationStats, DbSnapshot, DropDbError},
     pg_client_extensions::PgClientExtensions,
 };
-use tracing::{debug, error, instrument};
+use tracing::{debug, error, instrument, warn};
 
 impl PgTempestCore {
     #[instrument(skip_all)]
@@ -22,11 +23,74 @@ impl PgTempestCore {
         let test_db_name = TestDbName::new(template_hash, test_db_id);
         let template_db_name = TemplateDbName::new(template_hash);
 
-        let db_creation_result = self
-            .pg_client
-            .recreate_db(test_db_name.clone().into(), Some(template_db_name.into()))
+        // Checkpoints belong to the finished usage
+        let (checkpoint_ids, pristine_stats, pristine_snapshot) = self
+            .metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                template
+                    .as_mut()
+                    .and_then(|template| template.test_dbs.iter_mut().find(|x| x.id == test_db_id))
+                    .map(|test_db| {
+                        (
+                            std::mem::take(&mut test_db.checkpoint_ids),
+                            test_db.pristine_stats.take(),
+                            test_db.pristine_snapshot.take(),
+                        )
+                    })
+                    .unwrap_or_default()
+            })
             .await;
 
+        for checkpoint_id in checkpoint_ids {
+            let checkpoint_db_name =
+                CheckpointDbName::new(template_hash, test_db_id, checkpoint_id);
+
+            match self.pg_client.drop_db(checkpoint_db_name.into()).await {
+                Ok(()) | Err(DropDbError::DbDoesNotExist { .. }) => {}
+                Err(err) => warn!(
+                    "Failed to drop checkpoint {template_hash} {test_db_id} {checkpoint_id}: {err}"
+                ),
+            }
+        }
+
+        let db_creation_result: Result<
+            (Option<DbModificationStats>, Option<DbSnapshot>),
+            BoxDynError,
+        > = async {
+            if let Some(pristine_stats) = pristine_stats
+                && self
+                    .test_db_is_unmodified(&test_db_name, &pristine_stats)
+                    .await
+            {
+                debug!("Test db {template_hash} {test_db_id} is unmodified, it's reused");
+                return Ok((Some(pristine_stats), pristine_snapshot));
+            }
+
+            if let Some(pristine_snapshot) = pristine_snapshot
+                && self
+                    .reset_test_db(&test_db_name, &template_db_name, &pristine_snapshot)
+                    .await
+            {
+                // Stats and snapshot are collected again since relfilenodes of truncated tables changed
+                return Ok((
+                    self.collect_pristine_stats(&test_db_name).await,
+                    self.collect_pristine_snapshot(&test_db_name).await,
+                ));
+            }
+
+            self.pg_client
+                .recreate_db(test_db_name.clone().into(), Some(template_db_name.into()))
+                .await?;
+            self.apply_template_settings(template_hash, test_db_name.clone().into())
+                .await?;
+
+            Ok((
+                self.collect_pristine_stats(&test_db_name).await,
+                self.collect_pristine_snapshot(&test_db_name).await,
+            ))
+        }
+        .await;
+
         let result: Result<(), BoxDynError> = self
             .metadata_storage
             .execute_under_lock(template_hash, |template| {
@@ -40,10 +104,12 @@ impl PgTempestCore {
                     .find(|x| x.id == test_db_id)
                     .ok_or("Test db {test_db_id} was not found")?;
 
-                if let Err(_) = db_creation_result {
+                let Ok((pristine_stats, pristine_snapshot)) = db_creation_result else {
                     test_db.state = TestDbState::Corrupted;
                     return Err("Failed to create {test_db_name}".into());
-                }
+                };
+                test_db.pristine_stats = pristine_stats;
+                test_db.pristine_snapshot = pristine_snapshot;
 
                 while let Some(test_db_awaiter) = template.test_db_awaiters.pop_front() {
                     let usage_deadline = self.clock.now() + test_db_awaiter.usage_duration;
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    PgTempestCore,
+    metadata::template_metadata::TestDbState,
+    models::value_types::{
+        checkpoint_db_name::CheckpointDbName, checkpoint_id::CheckpointId,
+        template_hash::TemplateHash, test_db_id::TestDbId, test_db_name::TestDbName,
+    },
+    pg_client::DropDbError,
+    utils::errors::BoxDynError,
+};
+
+#[derive(Debug)]
+pub enum RestoreTestDbCheckpointErrorResult {
+    TemplateWasNotFound,
+    TestDbWasNotFound,
+    TestDbIsNotUsed,
+    CheckpointWasNotFound,
+    TestDbHasConnections,
+    Unknown { inner: BoxDynError },
+}
+
+impl PgTempestCore {
+    // The test db is recreated from the checkpoint, which is kept for further restorations
+    #[instrument(skip_all)]
+    pub async fn restore_test_db_checkpoint(
+        self: Arc<PgTempestCore>,
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+        checkpoint_id: CheckpointId,
+    ) -> Result<(), RestoreTestDbCheckpointErrorResult> {
+        self.metadata_storage
+            .execute_under_lock(template_hash, |template| {
+                let Some(template) = template else {
+                    warn!("Template {template_hash} was not found");
+                    return Err(RestoreTestDbCheckpointErrorResult::TemplateWasNotFound);
+                };
+
+                let test_db = template
+                    .test_dbs
+                    .iter_mut()
+                    .find(|test_db| test_db.id == test_db_id);
+
+                let Some(test_db) = test_db else {
+                    warn!("Test db {template_hash} {test_db_id} was not found");
+                    return Err(RestoreTestDbCheckpointErrorResult::TestDbWasNotFound);
+                };
+
+                if !matches!(test_db.state, TestDbState::InUse { .. }) {
+                    warn!("Test db {template_hash} {test_db_id} is not used");
+                    return Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed);
+                }
+
+                if !test_db.checkpoint_ids.contains(&checkpoint_id) {
+                    warn!("Checkpoint {template_hash} {test_db_id} {checkpoint_id} was not found");
+                    return Err(RestoreTestDbCheckpointErrorResult::CheckpointWasNotFound);
+                }
+
+                // The test db won't match its pristine stats and snapshot after it's recreated
+                test_db.pristine_stats = None;
+                test_db.pristine_snapshot = None;
+
+                Ok(())
+            })
+            .await?;
+
+        let test_db_name = TestDbName::new(template_hash, test_db_id);
+        let checkpoint_db_name = CheckpointDbName::new(template_hash, test_db_id, checkpoint_id);
+
+        match self.pg_client.drop_db(test_db_name.clone().into()).await {
+            Ok(()) | Err(DropDbError::DbDoesNotExist { .. }) => {}
+            Err(DropDbError::DbIsUsed { .. }) => {
+                warn!("Test db {template_hash} {test_db_id} has connections");
+                return Err(RestoreTestDbCheckpointErrorResult::TestDbHasConnections);
+            }
+            Err(err) => {
+                return Err(RestoreTestDbCheckpointErrorResult::Unknown { inner: err.into() });
+            }
+        }
+
+        let creation_result: Result<(), BoxDynError> = async {
+            self.pg_client
+                .create_db(
+                    test_db_name.clone().into(),
+                    Some(checkpoint_db_name.into()),
+                    false,
+                )
+                .await?;
+            self.apply_template_settings(template_hash, test_db_name.into())
+                .await?;
+
+            Ok(())
+        }
+        .await;
+
+        // The usage could be finished while the test db was recreated. Checkpoints are taken
+        // from the test db when it's recreated, so the checkpoint is kept only by the same usage
+        let is_usage_continued =
+            self.metadata_storage
+                .execute_under_lock(template_hash, |template| {
+                    let Some(test_db) = template.as_mut().and_then(|template| {
+                        template.test_dbs.iter_mut().find(|x| x.id == test_db_id)
+                    }) else {
+                        return false;
+                    };
+
+                    match test_db.state {
+                        TestDbState::InUse { .. }
+                            if test_db.checkpoint_ids.contains(&checkpoint_id) =>
+                        {
+                            true
+                        }
+                        // The returned test db may hold the checkpoint instead of the template
+                        TestDbState::Ready => {
+                            test_db.state = TestDbState::Creating;
+                            tokio::spawn(self.clone().recreate_test_db(template_hash, test_db_id));
+                            false
+                        }
+                        _ => false,
+                    }
+                })
+                .await;
+
+        if !is_usage_continued {
+            warn!("Test db {template_hash} {test_db_id} usage was finished during restoration");
+            return Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed);
+        }
+
+        if let Err(err) = creation_result {
+            error!(
+                "Failed to restore checkpoint {template_hash} {test_db_id} {checkpoint_id}: {err}"
+            );
+
+            // The usage isn't interrupted, the test db is recreated from the template
+            // when it's finished
+            return Err(RestoreTestDbCheckpointErrorResult::Unknown { inner: err });
+        }
+
+        info!("Checkpoint {template_hash} {test_db_id} {checkpoint_id} was restored");
+
+        Ok(())
+    }
+}
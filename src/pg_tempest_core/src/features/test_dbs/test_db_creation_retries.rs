@@ -1,29 +1,40 @@
 use std::{sync::Arc, time::Duration};
 
 use tokio::time::sleep;
-use tracing::info;
+use tracing::{debug, info};
 
 use crate::{PgTempestCore, metadata::template_metadata::TestDbState};
 
 impl PgTempestCore {
     pub fn start_test_db_creation_retries_in_background(self: Arc<Self>) {
-        tokio::spawn(async move {
-            let retries_delay =
-                Duration::from_millis(self.db_pool_configs.creation_retries_delay_in_ms);
+        let retries_delay =
+            Duration::from_millis(self.db_pool_configs.creation_retries_delay_in_ms);
+        // The loop must not keep the core alive, so it stops when the core is dropped
+        let weak_tempest_core = Arc::downgrade(&self);
 
+        tokio::spawn(async move {
             loop {
                 sleep(retries_delay).await;
 
-                let template_hashes = self.metadata_storage.get_all_template_hashes().await;
+                let Some(tempest_core) = weak_tempest_core.upgrade() else {
+                    debug!("Core was dropped. Test db creation retries are stopped");
+                    return;
+                };
+
+                let template_hashes = tempest_core
+                    .metadata_storage
+                    .get_all_template_hashes()
+                    .await;
 
                 for template_hash in template_hashes {
-                    self.metadata_storage
+                    tempest_core
+                        .metadata_storage
                         .execute_under_lock(template_hash, |template| {
                             let Some(template) = template else {
                                 return;
                             };
 
-                            let now = self.clock.now();
+                            let now = tempest_core.clock.now();
 
                             for test_db in template.test_dbs.iter_mut() {
                                 match test_db.state {
@@ -41,12 +52,22 @@ impl PgTempestCore {
                                             template_hash, test_db.id
                                         );
                                     }
+                                    TestDbState::Retained { retention_deadline }
+                                        if retention_deadline <= now =>
+                                    {
+                                        info!(
+                                            "Test db {} {} retention deadline is now. Recreating",
+                                            template_hash, test_db.id
+                                        );
+                                    }
                                     _ => continue,
                                 }
 
                                 test_db.state = TestDbState::Creating;
                                 tokio::spawn(
-                                    self.clone().recreate_test_db(template_hash, test_db.id),
+                                    tempest_core
+                                        .clone()
+                                        .recreate_test_db(template_hash, test_db.id),
                                 );
                             }
                         })
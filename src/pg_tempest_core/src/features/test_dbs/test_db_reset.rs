@@ -0,0 +1,64 @@
+use tracing::{info, warn};
+
+use crate::{
+    PgTempestCore,
+    configs::db_pool_configs::TestDbRecyclingStrategy,
+    models::value_types::{template_db_name::TemplateDbName, test_db_name::TestDbName},
+    pg_client::{DbSnapshot, ResetDbError},
+};
+
+impl PgTempestCore {
+    // Taken right after a test db is created, like its pristine stats
+    pub(crate) async fn collect_pristine_snapshot(
+        &self,
+        test_db_name: &TestDbName,
+    ) -> Option<DbSnapshot> {
+        if self.db_pool_configs.recycling.strategy != TestDbRecyclingStrategy::Truncate {
+            return None;
+        }
+
+        match self
+            .pg_client
+            .get_db_snapshot(test_db_name.clone().into())
+            .await
+        {
+            Ok(snapshot) => Some(snapshot),
+            Err(err) => {
+                warn!("Failed to take snapshot of {test_db_name}, it'll be recreated: {err}");
+                None
+            }
+        }
+    }
+
+    // Returns false if the test db must be recreated instead
+    pub(crate) async fn reset_test_db(
+        &self,
+        test_db_name: &TestDbName,
+        template_db_name: &TemplateDbName,
+        pristine_snapshot: &DbSnapshot,
+    ) -> bool {
+        let reset_result = self
+            .pg_client
+            .reset_db(
+                test_db_name.clone().into(),
+                template_db_name.clone().into(),
+                pristine_snapshot,
+            )
+            .await;
+
+        match reset_result {
+            Ok(()) => {
+                info!("{test_db_name} was reset in place");
+                true
+            }
+            Err(ResetDbError::SchemaWasChanged { .. }) => {
+                info!("Schema of {test_db_name} was changed, it'll be recreated");
+                false
+            }
+            Err(err) => {
+                warn!("Failed to reset {test_db_name}, it'll be recreated: {err}");
+                false
+            }
+        }
+    }
+}
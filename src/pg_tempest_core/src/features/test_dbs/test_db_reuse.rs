@@ -0,0 +1,60 @@
+use tracing::{debug, warn};
+
+use crate::{
+    PgTempestCore, configs::db_pool_configs::TestDbReuseStrictness,
+    models::value_types::test_db_name::TestDbName, pg_client::DbModificationStats,
+};
+
+impl PgTempestCore {
+    // Collected right after a test db is created, so that leases aren't slowed down.
+    // Nothing connects to a test db before it's leased, so the stats are the same at lease start
+    pub(crate) async fn collect_pristine_stats(
+        &self,
+        test_db_name: &TestDbName,
+    ) -> Option<DbModificationStats> {
+        let with_fingerprint = match self.db_pool_configs.reuse.strictness {
+            TestDbReuseStrictness::Disabled => return None,
+            TestDbReuseStrictness::Counters => false,
+            TestDbReuseStrictness::Fingerprint => true,
+        };
+
+        let stats_result = self
+            .pg_client
+            .get_db_modification_stats(test_db_name.clone().into(), with_fingerprint)
+            .await;
+
+        match stats_result {
+            Ok(stats) => Some(stats),
+            Err(err) => {
+                warn!("Failed to collect stats of {test_db_name}, it won't be reused: {err}");
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn test_db_is_unmodified(
+        &self,
+        test_db_name: &TestDbName,
+        pristine_stats: &DbModificationStats,
+    ) -> bool {
+        let stats_result = self
+            .pg_client
+            .get_db_modification_stats(
+                test_db_name.clone().into(),
+                pristine_stats.fingerprint.is_some(),
+            )
+            .await;
+
+        match stats_result {
+            Ok(stats) => {
+                let is_unmodified = stats.is_unmodified_since(pristine_stats);
+                debug!("{test_db_name} is unmodified: {is_unmodified}, stats: {stats:?}");
+                is_unmodified
+            }
+            Err(err) => {
+                warn!("Failed to collect stats of {test_db_name}: {err}");
+                false
+            }
+        }
+    }
+}
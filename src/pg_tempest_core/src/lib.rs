@@ -10,6 +10,7 @@ use crate::{
 };
 
 pub mod configs;
+pub mod fault_injection;
 pub mod features;
 pub mod metadata;
 pub mod models;
@@ -29,12 +30,12 @@ pub struct PgTempestCore {
 impl PgTempestCore {
     pub async fn new(
         pg_client: Arc<dyn PgClient>,
+        clock: Arc<dyn Clock>,
         dbms_configs: Arc<DbmsConfigs>,
         db_pool_configs: Arc<DbPoolConfigs>,
         templates_configs: Arc<TemplatesConfigs>,
     ) -> Result<PgTempestCore, BoxDynError> {
         let metadata_storage = Arc::new(MetadataStorage::new());
-        let clock = Arc::new(SystemClock);
 
         Ok(PgTempestCore {
             metadata_storage,
@@ -45,4 +46,51 @@ impl PgTempestCore {
             templates_configs,
         })
     }
+
+    // Creates the core and starts its background processing, which stops when the core is dropped.
+    // It's enough to embed pg-tempest into a process without the http server
+    pub async fn start(
+        pg_client: Arc<dyn PgClient>,
+        dbms_configs: Arc<DbmsConfigs>,
+        db_pool_configs: Arc<DbPoolConfigs>,
+        templates_configs: Arc<TemplatesConfigs>,
+    ) -> Result<Arc<PgTempestCore>, BoxDynError> {
+        PgTempestCore::start_with_clock(
+            pg_client,
+            Arc::new(SystemClock),
+            dbms_configs,
+            db_pool_configs,
+            templates_configs,
+        )
+        .await
+    }
+
+    // Same as `start`, but deadlines are computed with the given clock instead of the system one
+    pub async fn start_with_clock(
+        pg_client: Arc<dyn PgClient>,
+        clock: Arc<dyn Clock>,
+        dbms_configs: Arc<DbmsConfigs>,
+        db_pool_configs: Arc<DbPoolConfigs>,
+        templates_configs: Arc<TemplatesConfigs>,
+    ) -> Result<Arc<PgTempestCore>, BoxDynError> {
+        let tempest_core = Arc::new(
+            PgTempestCore::new(
+                pg_client,
+                clock,
+                dbms_configs,
+                db_pool_configs,
+                templates_configs,
+            )
+            .await?,
+        );
+
+        tempest_core
+            .clone()
+            .start_test_db_creation_retries_in_background();
+        tempest_core
+            .clone()
+            .start_template_initialization_deadline_handling();
+
+        Ok(tempest_core)
+    }
 }
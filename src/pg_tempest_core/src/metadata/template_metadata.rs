@@ -3,16 +3,28 @@ use std::sync::Arc;
 use std::{collections::VecDeque, time::Duration};
 use tokio::sync::oneshot;
 
-use crate::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+use crate::models::db_settings::DbSettings;
+use crate::models::value_types::{
+    checkpoint_id::CheckpointId, extension_name::ExtensionName, pg_identifier::PgIdentifier,
+    template_hash::TemplateHash, test_db_id::TestDbId,
+};
+use crate::pg_client::{DbModificationStats, DbSnapshot};
 use crate::utils::errors::ArcDynError;
 
 pub struct TemplateMetadata {
     pub template_hash: TemplateHash,
     pub initialization_state: TemplateInitializationState,
+    // Applied to the template db and to each test db after it is copied
+    pub settings: Arc<DbSettings>,
+    // Created in the template db before the initialization is started
+    pub extension_names: Arc<[ExtensionName]>,
+    // Requested by initializers, the configured parent is used when it's missing
+    pub parent_template_db_name: Option<PgIdentifier>,
     pub template_awaiters: VecDeque<TemplateAwaiter>,
     pub test_dbs: Vec<TestDbMetadata>,
     pub test_db_awaiters: VecDeque<TestDbAwaiter>,
     pub test_db_id_sequence: u16,
+    pub export_id_sequence: u16,
 }
 
 impl TemplateMetadata {
@@ -20,8 +32,14 @@ impl TemplateMetadata {
         self.test_db_id_sequence += 1;
         TestDbId::new(self.test_db_id_sequence)
     }
+
+    pub fn next_export_id(&mut self) -> u16 {
+        self.export_id_sequence = self.export_id_sequence.wrapping_add(1);
+        self.export_id_sequence
+    }
 }
 
+#[derive(Clone)]
 pub enum TemplateInitializationState {
     Creating,
     Created,
@@ -54,13 +72,31 @@ pub enum TemplateAwaitingResult {
 pub struct TestDbMetadata {
     pub id: TestDbId,
     pub state: TestDbState,
+    // Checkpoint dbs of the current usage, dropped when the test db is recreated
+    pub checkpoint_ids: Vec<CheckpointId>,
+    pub checkpoint_id_sequence: u16,
+    // Stats of the pristine test db, which are compared with its stats after a usage
+    // to skip recreation of an unmodified db
+    pub pristine_stats: Option<DbModificationStats>,
+    // Snapshot of the pristine test db, which lets to reset it in place after a usage
+    pub pristine_snapshot: Option<DbSnapshot>,
+}
+
+impl TestDbMetadata {
+    pub fn next_checkpoint_id(&mut self) -> CheckpointId {
+        self.checkpoint_id_sequence += 1;
+        CheckpointId::new(self.checkpoint_id_sequence)
+    }
 }
 
+#[derive(Clone)]
 pub enum TestDbState {
     Creating,
     Ready,
     Corrupted,
     InUse { usage_deadline: DateTime<Utc> },
+    // Kept untouched after a failed usage and excluded from the pool until the deadline
+    Retained { retention_deadline: DateTime<Utc> },
 }
 
 pub struct TestDbAwaiter {
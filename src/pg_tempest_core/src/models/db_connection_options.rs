@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::{DbmsConfigs, models::value_types::pg_identifier::PgIdentifier};
 
 pub struct DbConnectionOptions {
@@ -6,20 +8,229 @@ pub struct DbConnectionOptions {
     pub username: Box<str>,
     pub password: Box<str>,
     pub database: PgIdentifier,
+    pub parameters: BTreeMap<Box<str>, Box<str>>,
+}
+
+pub enum NewOuterDbConnectionOptionsErrorResult {
+    NetworkProfileWasNotFound,
 }
 
 impl DbConnectionOptions {
-    pub fn new_outer(configs: &DbmsConfigs, database: PgIdentifier) -> DbConnectionOptions {
-        DbConnectionOptions {
-            host: configs
-                .outer
+    pub fn new_outer(
+        configs: &DbmsConfigs,
+        network_profile: Option<&str>,
+        database: PgIdentifier,
+    ) -> Result<DbConnectionOptions, NewOuterDbConnectionOptionsErrorResult> {
+        let outer = configs
+            .outer_profile(network_profile)
+            .ok_or(NewOuterDbConnectionOptionsErrorResult::NetworkProfileWasNotFound)?;
+
+        // A profile without a host or a port takes it from the default outer endpoint,
+        // since the inner one is not reachable from the client network.
+        // Parameters of the default outer endpoint are overridden by the profile ones,
+        // so that clients don't lose sslmode and the like by selecting a profile
+        let mut parameters = configs.outer.parameters.clone();
+        parameters.extend(outer.parameters.clone());
+
+        Ok(DbConnectionOptions {
+            host: outer
                 .host
-                .clone()
-                .unwrap_or_else(|| configs.inner.host.clone()),
-            port: configs.outer.port.unwrap_or_else(|| configs.inner.port),
+                .as_ref()
+                .or(configs.outer.host.as_ref())
+                .unwrap_or(&configs.inner.host)
+                .clone(),
+            port: outer
+                .port
+                .or(configs.outer.port)
+                .unwrap_or(configs.inner.port),
             username: configs.user.clone(),
             password: configs.password.clone(),
-            database: database.into(),
+            database,
+            parameters,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        DbmsConfigs,
+        configs::dbms_configs::{InnerDbmsConfigs, OuterDbmsConfigs},
+        models::{
+            db_connection_options::{DbConnectionOptions, NewOuterDbConnectionOptionsErrorResult},
+            value_types::pg_identifier::PgIdentifier,
+        },
+    };
+
+    fn create_configs(
+        outer_host: Option<&str>,
+        outer_port: Option<u16>,
+        profiles: Vec<(&str, OuterDbmsConfigs)>,
+    ) -> DbmsConfigs {
+        DbmsConfigs {
+            inner: InnerDbmsConfigs {
+                host: "inner".into(),
+                port: 5432,
+            },
+            outer: create_outer(outer_host, outer_port, &[("sslmode", "disable")]),
+            profiles: profiles
+                .into_iter()
+                .map(|(name, profile)| (name.into(), profile))
+                .collect(),
+            database: "postgres".into(),
+            user: "postgres".into(),
+            password: "postgres".into(),
+            ssl: Default::default(),
+        }
+    }
+
+    fn create_outer(
+        host: Option<&str>,
+        port: Option<u16>,
+        parameters: &[(&str, &str)],
+    ) -> OuterDbmsConfigs {
+        OuterDbmsConfigs {
+            host: host.map(Into::into),
+            port,
+            parameters: parameters
+                .iter()
+                .map(|(name, value)| ((*name).into(), (*value).into()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    fn new_outer(
+        configs: &DbmsConfigs,
+        network_profile: Option<&str>,
+    ) -> Result<DbConnectionOptions, NewOuterDbConnectionOptionsErrorResult> {
+        DbConnectionOptions::new_outer(
+            configs,
+            network_profile,
+            PgIdentifier::new("TEMPEST_TEST_DB").unwrap(),
+        )
+    }
+
+    #[test]
+    fn default_outer_falls_back_to_inner() {
+        let configs = create_configs(None, None, Vec::new());
+
+        let Ok(options) = new_outer(&configs, None) else {
+            panic!("The default outer profile wasn't resolved");
+        };
+
+        assert_eq!((&*options.host, options.port), ("inner", 5432));
+        assert_eq!(options.parameters.len(), 1);
+    }
+
+    #[test]
+    fn default_outer_overrides_inner() {
+        let configs = create_configs(Some("outer"), Some(15432), Vec::new());
+
+        let Ok(options) = new_outer(&configs, None) else {
+            panic!("The default outer profile wasn't resolved");
+        };
+
+        assert_eq!((&*options.host, options.port), ("outer", 15432));
+    }
+
+    #[test]
+    fn profile_overrides_default_outer() {
+        let configs = create_configs(
+            Some("outer"),
+            Some(15432),
+            vec![(
+                "compose",
+                create_outer(Some("db"), Some(5433), &[("application_name", "tests")]),
+            )],
+        );
+
+        let Ok(options) = new_outer(&configs, Some("compose")) else {
+            panic!("The network profile wasn't resolved");
+        };
+
+        assert_eq!((&*options.host, options.port), ("db", 5433));
+        assert_eq!(
+            options
+                .parameters
+                .get("application_name")
+                .map(|value| &**value),
+            Some("tests")
+        );
+        assert_eq!(
+            options.parameters.get("sslmode").map(|value| &**value),
+            Some("disable")
+        );
+    }
+
+    #[test]
+    fn profile_parameters_override_default_outer_ones() {
+        let configs = create_configs(
+            None,
+            None,
+            vec![(
+                "compose",
+                create_outer(Some("db"), None, &[("sslmode", "require")]),
+            )],
+        );
+
+        let Ok(options) = new_outer(&configs, Some("compose")) else {
+            panic!("The network profile wasn't resolved");
+        };
+
+        assert_eq!(options.parameters.len(), 1);
+        assert_eq!(
+            options.parameters.get("sslmode").map(|value| &**value),
+            Some("require")
+        );
+    }
+
+    #[test]
+    fn profile_without_host_and_port_falls_back_to_default_outer() {
+        let configs = create_configs(
+            Some("outer"),
+            Some(15432),
+            vec![("compose", create_outer(None, None, &[]))],
+        );
+
+        let Ok(options) = new_outer(&configs, Some("compose")) else {
+            panic!("The network profile wasn't resolved");
+        };
+
+        assert_eq!((&*options.host, options.port), ("outer", 15432));
+    }
+
+    #[test]
+    fn profile_falls_back_to_inner_when_default_outer_is_empty() {
+        let configs = create_configs(
+            None,
+            None,
+            vec![("compose", create_outer(Some("db"), None, &[]))],
+        );
+
+        let Ok(options) = new_outer(&configs, Some("compose")) else {
+            panic!("The network profile wasn't resolved");
+        };
+
+        assert_eq!((&*options.host, options.port), ("db", 5432));
+    }
+
+    #[test]
+    fn unknown_profile_is_not_found() {
+        let configs = create_configs(
+            Some("outer"),
+            Some(15432),
+            vec![("compose", create_outer(Some("db"), None, &[]))],
+        );
+
+        let result = new_outer(&configs, Some("unknown"));
+
+        assert! {
+            matches!(
+                result,
+                Err(NewOuterDbConnectionOptionsErrorResult::NetworkProfileWasNotFound)
+            )
         }
     }
 }
@@ -0,0 +1,6 @@
+use std::collections::BTreeMap;
+
+use crate::models::value_types::db_setting_name::DbSettingName;
+
+// Values of `ALTER DATABASE ... SET`, which aren't copied along with a template db
+pub type DbSettings = BTreeMap<DbSettingName, Box<str>>;
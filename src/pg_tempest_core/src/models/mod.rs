@@ -1,2 +1,4 @@
-﻿pub mod db_connection_options;
+﻿pub mod connection_strings;
+pub mod db_connection_options;
+pub mod db_settings;
 pub mod value_types;
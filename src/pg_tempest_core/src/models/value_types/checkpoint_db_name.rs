@@ -0,0 +1,119 @@
+use derive_more::{AsRef, Display, Into};
+use regex::Regex;
+use std::{str::FromStr, sync::LazyLock};
+
+use crate::models::value_types::{
+    checkpoint_id::CheckpointId, pg_identifier::PgIdentifier, template_hash::TemplateHash,
+    test_db_id::TestDbId,
+};
+
+static CHECKPOINT_DB_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^TEMPEST_([0-9a-fA-F]{32})_TEST_DB_([0-9a-fA-F]{4})_CP_([0-9a-fA-F]{4})$"#)
+        .unwrap()
+});
+
+// Name of a copy of a test db, which the test db can be restored from.
+// It's 61 characters long, so it fits into the 63 characters limit of identifiers
+#[derive(AsRef, Display, Debug, Into, Clone)]
+#[display("{pg_identifier}")]
+pub struct CheckpointDbName {
+    #[as_ref]
+    #[into]
+    pg_identifier: PgIdentifier,
+    #[as_ref]
+    template_hash: TemplateHash,
+    #[as_ref]
+    test_db_id: TestDbId,
+    #[as_ref]
+    checkpoint_id: CheckpointId,
+}
+
+impl CheckpointDbName {
+    pub fn new(
+        template_hash: TemplateHash,
+        test_db_id: TestDbId,
+        checkpoint_id: CheckpointId,
+    ) -> CheckpointDbName {
+        let identifier = format!("TEMPEST_{template_hash}_TEST_DB_{test_db_id}_CP_{checkpoint_id}");
+
+        CheckpointDbName {
+            pg_identifier: PgIdentifier::new(identifier).unwrap(),
+            template_hash,
+            test_db_id,
+            checkpoint_id,
+        }
+    }
+}
+
+impl TryFrom<PgIdentifier> for CheckpointDbName {
+    type Error = String;
+
+    fn try_from(identifier: PgIdentifier) -> Result<Self, Self::Error> {
+        let (_, [template_hash, test_db_id, checkpoint_id]) = CHECKPOINT_DB_NAME_REGEX
+            .captures(identifier.as_ref())
+            .ok_or(format!(r#""{identifier}" is invalid checkpoint db name"#))?
+            .extract();
+
+        // Format of the parts is validated by CHECKPOINT_DB_NAME_REGEX
+        let template_hash = TemplateHash::from_str(template_hash).unwrap();
+        let test_db_id = TestDbId::from_str(test_db_id).unwrap();
+        let checkpoint_id = CheckpointId::from_str(checkpoint_id).unwrap();
+
+        Ok(CheckpointDbName {
+            pg_identifier: identifier,
+            template_hash,
+            test_db_id,
+            checkpoint_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::value_types::{
+        checkpoint_db_name::CheckpointDbName,
+        checkpoint_id::CheckpointId,
+        pg_identifier::PgIdentifier,
+        template_hash::{TEMPLATE_HASH_LENGTH, TemplateHash},
+        test_db_id::TestDbId,
+    };
+
+    #[test]
+    fn new_checkpoint_db_name_formats_correctly() {
+        let template_hash = TemplateHash::new([0xFF; TEMPLATE_HASH_LENGTH]);
+
+        let checkpoint_db_name =
+            CheckpointDbName::new(template_hash, TestDbId::new(0xFFFF), CheckpointId::new(1));
+
+        assert_eq!(
+            checkpoint_db_name.to_string(),
+            "TEMPEST_FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF_TEST_DB_FFFF_CP_0001".to_string()
+        );
+    }
+
+    #[test]
+    fn checkpoint_db_name_is_parsed_from_identifier() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_TEST_DB_0100_CP_000A")
+                .unwrap();
+
+        let checkpoint_db_name = CheckpointDbName::try_from(identifier).unwrap();
+
+        assert_eq!(
+            *AsRef::<TestDbId>::as_ref(&checkpoint_db_name),
+            TestDbId::new(0x0100)
+        );
+        assert_eq!(
+            *AsRef::<CheckpointId>::as_ref(&checkpoint_db_name),
+            CheckpointId::new(0x000A)
+        );
+    }
+
+    #[test]
+    fn test_db_name_is_not_parsed_as_checkpoint_db_name() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_TEST_DB_0100").unwrap();
+
+        assert!(CheckpointDbName::try_from(identifier).is_err());
+    }
+}
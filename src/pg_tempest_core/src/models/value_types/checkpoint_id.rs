@@ -0,0 +1,54 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::num::ParseIntError;
+use std::{fmt::Debug, str::FromStr};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Display, Deserialize, Serialize, Default)]
+#[display("{self:?}")]
+#[serde(try_from = "&str")]
+#[serde(into = "Box<str>")]
+pub struct CheckpointId {
+    value: u16,
+}
+
+impl CheckpointId {
+    pub fn new(value: u16) -> CheckpointId {
+        CheckpointId { value }
+    }
+}
+
+impl Debug for CheckpointId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04X}", self.value)
+    }
+}
+
+impl TryFrom<&str> for CheckpointId {
+    type Error = ParseIntError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        CheckpointId::from_str(s)
+    }
+}
+
+impl From<CheckpointId> for Box<str> {
+    fn from(hash: CheckpointId) -> Self {
+        hash.to_string().into()
+    }
+}
+
+impl From<CheckpointId> for String {
+    fn from(hash: CheckpointId) -> Self {
+        hash.to_string()
+    }
+}
+
+impl FromStr for CheckpointId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: u16 = u16::from_str_radix(s, 16)?;
+
+        Ok(CheckpointId::new(value))
+    }
+}
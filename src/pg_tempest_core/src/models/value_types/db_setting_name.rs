@@ -0,0 +1,103 @@
+use derive_more::{
+    AsRef,
+    // Alias is used as a work-around to
+    // https://youtrack.jetbrains.com/issue/RUST-9732/Derive-macros-have-wrong-priorities-in-name-resolution
+    Debug as DebugV2,
+    Display,
+    Into,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::{str::FromStr, sync::LazyLock};
+use thiserror::Error;
+
+// Name of a configuration parameter, optionally prefixed by an extension name
+static DB_SETTING_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^[A-Za-z_][A-Za-z0-9_$]*(\.[A-Za-z_][A-Za-z0-9_$]*)?$"#).unwrap()
+});
+
+#[derive(
+    DebugV2, AsRef, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Into, Serialize, Deserialize,
+)]
+#[display("{value}")]
+#[debug("{value}")]
+#[serde(try_from = "Arc<str>")]
+#[serde(into = "Arc<str>")]
+pub struct DbSettingName {
+    value: Arc<str>,
+}
+
+impl DbSettingName {
+    pub fn new(value: impl Into<Arc<str>>) -> Result<DbSettingName, DbSettingNameParseError> {
+        let value = value.into();
+        if DB_SETTING_NAME_REGEX.is_match(&value) {
+            Ok(DbSettingName { value })
+        } else {
+            Err(DbSettingNameParseError { value })
+        }
+    }
+
+    // Parts of a name are quoted separately, otherwise a prefixed name is read as one identifier
+    pub fn quoted_parts(&self) -> impl Iterator<Item = String> {
+        self.value.split('.').map(|part| format!(r#""{part}""#))
+    }
+}
+
+impl FromStr for DbSettingName {
+    type Err = DbSettingNameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DbSettingName::new(s)
+    }
+}
+
+impl From<DbSettingName> for String {
+    fn from(value: DbSettingName) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<Arc<str>> for DbSettingName {
+    type Error = DbSettingNameParseError;
+
+    fn try_from(s: Arc<str>) -> Result<Self, Self::Error> {
+        DbSettingName::new(s)
+    }
+}
+
+#[derive(Debug, Display, Error)]
+#[display("{self:?}")]
+pub struct DbSettingNameParseError {
+    pub value: Arc<str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_plain_and_prefixed_names() {
+        assert!(DbSettingName::new("statement_timeout").is_ok());
+        assert!(DbSettingName::new("TimeZone").is_ok());
+        assert!(DbSettingName::new("app.tenant_id").is_ok());
+    }
+
+    #[test]
+    fn rejects_names_that_need_escaping() {
+        assert!(DbSettingName::new("").is_err());
+        assert!(DbSettingName::new("search_path\" = x").is_err());
+        assert!(DbSettingName::new("a.b.c").is_err());
+        assert!(DbSettingName::new("1timeout").is_err());
+    }
+
+    #[test]
+    fn quotes_parts_separately() {
+        let name = DbSettingName::new("app.tenant_id").unwrap();
+
+        assert_eq!(
+            name.quoted_parts().collect::<Vec<_>>().join("."),
+            r#""app"."tenant_id""#
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use derive_more::{AsRef, Display, Into};
+use regex::Regex;
+use std::{str::FromStr, sync::LazyLock};
+
+use crate::models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash};
+
+static EXPORT_DB_NAME_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^TEMPEST_([0-9a-fA-F]{32})_EXPORT_([0-9a-fA-F]{4})$"#).unwrap());
+
+// Name of a short-lived copy of a template db, which is dumped instead of the template db,
+// so that test dbs can be copied from the template db during the dump
+#[derive(AsRef, Display, Debug, Into, Clone)]
+#[display("{pg_identifier}")]
+pub struct ExportDbName {
+    #[as_ref]
+    #[into]
+    pg_identifier: PgIdentifier,
+    #[as_ref]
+    template_hash: TemplateHash,
+    export_id: u16,
+}
+
+impl ExportDbName {
+    pub fn new(template_hash: TemplateHash, export_id: u16) -> ExportDbName {
+        let identifier = format!("TEMPEST_{template_hash}_EXPORT_{export_id:04X}");
+
+        ExportDbName {
+            pg_identifier: PgIdentifier::new(identifier).unwrap(),
+            template_hash,
+            export_id,
+        }
+    }
+
+    pub fn export_id(&self) -> u16 {
+        self.export_id
+    }
+}
+
+impl TryFrom<PgIdentifier> for ExportDbName {
+    type Error = String;
+
+    fn try_from(identifier: PgIdentifier) -> Result<Self, Self::Error> {
+        let (_, [template_hash, export_id]) = EXPORT_DB_NAME_REGEX
+            .captures(identifier.as_ref())
+            .ok_or(format!(r#""{identifier}" is invalid export db name"#))?
+            .extract();
+
+        // Format of the parts is validated by EXPORT_DB_NAME_REGEX
+        let template_hash = TemplateHash::from_str(template_hash).unwrap();
+        let export_id = u16::from_str_radix(export_id, 16).unwrap();
+
+        Ok(ExportDbName {
+            pg_identifier: identifier,
+            template_hash,
+            export_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::value_types::{
+        export_db_name::ExportDbName,
+        pg_identifier::PgIdentifier,
+        template_hash::{TEMPLATE_HASH_LENGTH, TemplateHash},
+    };
+
+    #[test]
+    fn new_export_db_name_formats_correctly() {
+        let template_hash = TemplateHash::new([0xFF; TEMPLATE_HASH_LENGTH]);
+
+        let export_db_name = ExportDbName::new(template_hash, 0x001A);
+
+        assert_eq!(
+            export_db_name.to_string(),
+            "TEMPEST_FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF_EXPORT_001A".to_string()
+        );
+    }
+
+    #[test]
+    fn export_db_name_is_parsed_from_identifier() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_EXPORT_0100").unwrap();
+
+        let export_db_name = ExportDbName::try_from(identifier).unwrap();
+
+        assert_eq!(export_db_name.export_id(), 0x0100);
+    }
+
+    #[test]
+    fn template_db_name_is_not_parsed_as_export_db_name() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_TEMPLATE").unwrap();
+
+        assert!(ExportDbName::try_from(identifier).is_err());
+    }
+}
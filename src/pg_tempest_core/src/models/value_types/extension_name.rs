@@ -0,0 +1,86 @@
+use derive_more::{
+    AsRef,
+    // Alias is used as a work-around to
+    // https://youtrack.jetbrains.com/issue/RUST-9732/Derive-macros-have-wrong-priorities-in-name-resolution
+    Debug as DebugV2,
+    Display,
+    Into,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::{str::FromStr, sync::LazyLock};
+use thiserror::Error;
+
+// Unlike other identifiers, names of extensions may contain hyphens, e.g. uuid-ossp
+static EXTENSION_NAME_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^[A-Za-z_][A-Za-z0-9_\-]*$"#).unwrap());
+
+#[derive(
+    DebugV2, AsRef, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Into, Serialize, Deserialize,
+)]
+#[display("{value}")]
+#[debug("{value}")]
+#[serde(try_from = "Arc<str>")]
+#[serde(into = "Arc<str>")]
+pub struct ExtensionName {
+    value: Arc<str>,
+}
+
+impl ExtensionName {
+    pub fn new(value: impl Into<Arc<str>>) -> Result<ExtensionName, ExtensionNameParseError> {
+        let value = value.into();
+        if EXTENSION_NAME_REGEX.is_match(&value) {
+            Ok(ExtensionName { value })
+        } else {
+            Err(ExtensionNameParseError { value })
+        }
+    }
+}
+
+impl FromStr for ExtensionName {
+    type Err = ExtensionNameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExtensionName::new(s)
+    }
+}
+
+impl From<ExtensionName> for String {
+    fn from(value: ExtensionName) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<Arc<str>> for ExtensionName {
+    type Error = ExtensionNameParseError;
+
+    fn try_from(s: Arc<str>) -> Result<Self, Self::Error> {
+        ExtensionName::new(s)
+    }
+}
+
+#[derive(Debug, Display, Error)]
+#[display("{self:?}")]
+pub struct ExtensionNameParseError {
+    pub value: Arc<str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_names_with_hyphens() {
+        assert!(ExtensionName::new("pgcrypto").is_ok());
+        assert!(ExtensionName::new("uuid-ossp").is_ok());
+        assert!(ExtensionName::new("pg_trgm").is_ok());
+    }
+
+    #[test]
+    fn rejects_names_that_need_escaping() {
+        assert!(ExtensionName::new("").is_err());
+        assert!(ExtensionName::new("pgcrypto\"; DROP").is_err());
+        assert!(ExtensionName::new("-ossp").is_err());
+    }
+}
@@ -1,3 +1,8 @@
+pub mod checkpoint_db_name;
+pub mod checkpoint_id;
+pub mod db_setting_name;
+pub mod export_db_name;
+pub mod extension_name;
 pub mod pg_identifier;
 pub mod template_db_name;
 pub mod template_hash;
@@ -5,7 +5,7 @@ use std::{str::FromStr, sync::LazyLock};
 use crate::models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash};
 
 static TEMPLATE_DB_NAME_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"TEMPEST_([0-9a-fA-F]{32})_TEMPLATE"#).unwrap());
+    LazyLock::new(|| Regex::new(r#"^TEMPEST_([0-9a-fA-F]{32})_TEMPLATE$"#).unwrap());
 
 #[derive(AsRef, Display, Debug, Into, Clone)]
 #[display("{pg_identifier}")]
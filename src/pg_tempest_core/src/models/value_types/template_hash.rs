@@ -15,7 +15,7 @@ pub struct TemplateHash {
 }
 
 impl TemplateHash {
-    pub fn new(value: [u8; TEMPLATE_HASH_LENGTH]) -> TemplateHash {
+    pub const fn new(value: [u8; TEMPLATE_HASH_LENGTH]) -> TemplateHash {
         TemplateHash { value }
     }
 }
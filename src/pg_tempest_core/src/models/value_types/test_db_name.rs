@@ -6,8 +6,9 @@ use crate::models::value_types::{
     pg_identifier::PgIdentifier, template_hash::TemplateHash, test_db_id::TestDbId,
 };
 
-static TEMPLATE_DB_NAME_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"TEMPEST_([0-9a-fA-F]{32})_TEST_DB([0-9a-fA-F]{4})"#).unwrap());
+static TEMPLATE_DB_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^TEMPEST_([0-9a-fA-F]{32})_TEST_DB_([0-9a-fA-F]{4})$"#).unwrap()
+});
 
 #[derive(AsRef, Display, Debug, Into, Clone)]
 #[display("{pg_identifier}")]
@@ -57,6 +58,7 @@ impl TryFrom<PgIdentifier> for TestDbName {
 #[cfg(test)]
 mod tests {
     use crate::models::value_types::{
+        pg_identifier::PgIdentifier,
         template_hash::{TEMPLATE_HASH_LENGTH, TemplateHash},
         test_db_id::TestDbId,
         test_db_name::TestDbName,
@@ -78,4 +80,29 @@ mod tests {
             "TEMPEST_0102030405060708090A0B0C0D0E0F10_TEST_DB_0100".to_string()
         );
     }
+
+    #[test]
+    fn test_db_name_is_parsed_from_identifier() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_TEST_DB_0100").unwrap();
+
+        let test_db_name = TestDbName::try_from(identifier).unwrap();
+
+        assert_eq!(
+            test_db_name.to_string(),
+            "TEMPEST_0102030405060708090A0B0C0D0E0F10_TEST_DB_0100".to_string()
+        );
+        assert_eq!(
+            *AsRef::<TestDbId>::as_ref(&test_db_name),
+            TestDbId::new(0x0100)
+        );
+    }
+
+    #[test]
+    fn template_db_name_is_not_parsed_as_test_db_name() {
+        let identifier =
+            PgIdentifier::new("TEMPEST_0102030405060708090A0B0C0D0E0F10_TEMPLATE").unwrap();
+
+        assert!(TestDbName::try_from(identifier).is_err());
+    }
 }
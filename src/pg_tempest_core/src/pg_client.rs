@@ -1,7 +1,14 @@
+use std::path::PathBuf;
+use std::pin::Pin;
+
 use async_trait::async_trait;
 use derive_more::{Debug as DebugV2, Display};
 use thiserror::Error;
+use tokio::io::AsyncRead;
 
+use crate::models::db_settings::DbSettings;
+use crate::models::value_types::db_setting_name::DbSettingName;
+use crate::models::value_types::extension_name::ExtensionName;
 use crate::models::value_types::pg_identifier::PgIdentifier;
 use crate::utils::errors::BoxDynError;
 
@@ -13,6 +20,19 @@ pub trait PgClient: Send + Sync {
         is_template: bool,
     ) -> Result<(), AlterDbIsTemplateError>;
 
+    async fn alter_db_settings(
+        &self,
+        db_name: PgIdentifier,
+        settings: &DbSettings,
+    ) -> Result<(), AlterDbSettingsError>;
+
+    // Extensions are created inside of the db, together with extensions they depend on
+    async fn create_extensions(
+        &self,
+        db_name: PgIdentifier,
+        extension_names: &[ExtensionName],
+    ) -> Result<(), CreateExtensionsError>;
+
     async fn create_db(
         &self,
         db_name: PgIdentifier,
@@ -23,8 +43,54 @@ pub trait PgClient: Send + Sync {
     async fn drop_db(&self, db_name: PgIdentifier) -> Result<(), DropDbError>;
 
     async fn get_dbs(&self) -> Result<Vec<Db>, BoxDynError>;
+
+    // Sequence values are read from the db itself, and its fingerprint is collected only on demand
+    async fn get_db_modification_stats(
+        &self,
+        db_name: PgIdentifier,
+        with_fingerprint: bool,
+    ) -> Result<DbModificationStats, BoxDynError>;
+
+    async fn restore_db(&self, db_name: PgIdentifier, dump: &DbDump) -> Result<(), RestoreDbError>;
+
+    async fn get_db_snapshot(&self, db_name: PgIdentifier) -> Result<DbSnapshot, BoxDynError>;
+
+    // Undoes changes of tables and sequences made since the snapshot was taken,
+    // copying rows of modified tables from the template db
+    async fn reset_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: PgIdentifier,
+        snapshot: &DbSnapshot,
+    ) -> Result<(), ResetDbError>;
+
+    // Owners and privileges refer to roles of the dbms, so they are dumped only on demand
+    async fn dump_db(
+        &self,
+        db_name: PgIdentifier,
+        format: DbDumpFormat,
+        with_ownership: bool,
+    ) -> Result<DbDumpReader, BoxDynError>;
 }
 
+#[derive(Clone, Debug)]
+pub enum DbDump {
+    // Plain SQL output of pg_dump, executed without psql meta-commands
+    Sql { script: Box<str> },
+    // Archive of pg_dump in custom, directory or tar format, restored by pg_restore.
+    // Owners and privileges are restored only for archives dumped by the same dbms
+    Archive { path: PathBuf, with_ownership: bool },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum DbDumpFormat {
+    Sql,
+    Custom,
+}
+
+// Output of pg_dump, which ends with an error if pg_dump fails
+pub type DbDumpReader = Pin<Box<dyn AsyncRead + Send>>;
+
 #[derive(DebugV2, Display, Error)]
 #[display("AlterDbIsTemplateError::{self:?}")]
 pub enum AlterDbIsTemplateError {
@@ -38,6 +104,24 @@ pub enum AlterDbIsTemplateError {
     ),
 }
 
+#[derive(DebugV2, Display, Error)]
+#[display("AlterDbSettingsError::{self:?}")]
+pub enum AlterDbSettingsError {
+    DbDoesNotExist {
+        db_name: PgIdentifier,
+    },
+    // Unknown parameter, invalid value or a parameter which can't be set per db
+    SettingIsInvalid {
+        setting_name: DbSettingName,
+        reason: Box<str>,
+    },
+    Unexpected(
+        #[from]
+        #[debug("{_0}")]
+        BoxDynError,
+    ),
+}
+
 #[derive(DebugV2, Display, Error)]
 #[display("CreateDbError::{self:?}")]
 pub enum CreateDbError {
@@ -47,6 +131,28 @@ pub enum CreateDbError {
     TemplateDbDoesNotExist {
         template_db_name: PgIdentifier,
     },
+    // A db can't be copied while it has connections
+    TemplateDbIsUsed {
+        template_db_name: PgIdentifier,
+    },
+    Unexpected(
+        #[from]
+        #[debug("{_0}")]
+        BoxDynError,
+    ),
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("CreateExtensionsError::{self:?}")]
+pub enum CreateExtensionsError {
+    DbDoesNotExist {
+        db_name: PgIdentifier,
+    },
+    // Files of the extension aren't installed on the cluster
+    ExtensionIsNotAvailable {
+        extension_name: ExtensionName,
+        reason: Box<str>,
+    },
     Unexpected(
         #[from]
         #[debug("{_0}")]
@@ -63,6 +169,26 @@ pub enum DropDbError {
     DbIsTemplate {
         db_name: PgIdentifier,
     },
+    DbIsUsed {
+        db_name: PgIdentifier,
+    },
+    Unexpected(
+        #[from]
+        #[debug("{_0}")]
+        BoxDynError,
+    ),
+}
+
+#[derive(DebugV2, Display, Error)]
+#[display("ResetDbError::{self:?}")]
+pub enum ResetDbError {
+    // Counters of a db with connections may be not flushed yet
+    DbIsUsed {
+        db_name: PgIdentifier,
+    },
+    SchemaWasChanged {
+        db_name: PgIdentifier,
+    },
     Unexpected(
         #[from]
         #[debug("{_0}")]
@@ -70,6 +196,77 @@ pub enum DropDbError {
     ),
 }
 
+#[derive(DebugV2, Display, Error)]
+#[display("RestoreDbError::{self:?}")]
+pub enum RestoreDbError {
+    DumpWasNotRestored {
+        reason: Box<str>,
+    },
+    // The archive is truncated or isn't an archive of pg_dump at all
+    ArchiveIsUnreadable {
+        reason: Box<str>,
+    },
+    Unexpected(
+        #[from]
+        #[debug("{_0}")]
+        BoxDynError,
+    ),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DbModificationStats {
+    pub connection_count: u32,
+    pub tuples_inserted: u64,
+    pub tuples_updated: u64,
+    pub tuples_deleted: u64,
+    // Changes when counters are reset by pg_stat_reset, which would hide earlier modifications
+    pub stats_reset: Option<Box<str>>,
+    // Digest of sequence values, which aren't counted as modified tuples on nextval
+    pub sequence_values: Box<str>,
+    pub fingerprint: Option<Box<str>>,
+}
+
+impl DbModificationStats {
+    // Counters of a db with connections may be not flushed yet
+    pub fn is_unmodified_since(&self, baseline: &DbModificationStats) -> bool {
+        self.connection_count == 0
+            && self.tuples_inserted == baseline.tuples_inserted
+            && self.tuples_updated == baseline.tuples_updated
+            && self.tuples_deleted == baseline.tuples_deleted
+            && self.stats_reset == baseline.stats_reset
+            && self.sequence_values == baseline.sequence_values
+            && self.fingerprint == baseline.fingerprint
+    }
+}
+
+// Objects are identified by oids, which are the same in a template db and its copies
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DbSnapshot {
+    // Tuples inserted, updated or deleted in catalogs of the db, which are changed by DDL
+    // and other changes a reset of user tables can't undo
+    pub catalog_tuples_modified: u64,
+    pub tables: Vec<TableSnapshot>,
+    pub sequences: Vec<SequenceSnapshot>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TableSnapshot {
+    pub oid: u32,
+    // Changed by TRUNCATE, which isn't counted as deleted tuples
+    pub file_node: u32,
+    pub tuples_inserted: u64,
+    pub tuples_updated: u64,
+    pub tuples_deleted: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SequenceSnapshot {
+    pub oid: u32,
+    // None until nextval or setval is called
+    pub last_value: Option<i64>,
+    pub start_value: i64,
+}
+
 pub struct Db {
     pub oid: u32,
     pub name: PgIdentifier,
@@ -2,3 +2,4 @@
 pub mod clock;
 pub mod errors;
 pub mod option_ext;
+pub mod tokens;
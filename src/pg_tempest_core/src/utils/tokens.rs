@@ -0,0 +1,9 @@
+// Compares all bytes regardless of where they differ, so timing doesn't reveal the token
+pub fn tokens_are_equal(left: &str, right: &str) -> bool {
+    left.len() == right.len()
+        && left
+            .bytes()
+            .zip(right.bytes())
+            .fold(0, |difference, (l, r)| difference | (l ^ r))
+            == 0
+}
@@ -0,0 +1,10 @@
+// Protos are compiled with protox, so building doesn't require protoc to be installed
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let file_descriptors = protox::compile(["proto/pg_tempest.proto"], ["proto"])?;
+
+    tonic_prost_build::configure().compile_fds(file_descriptors)?;
+
+    println!("cargo:rerun-if-changed=proto");
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use std::{net::Ipv4Addr, path::PathBuf};
+
+#[derive(Deserialize)]
+pub struct GrpcServerConfigs {
+    pub enabled: bool,
+    pub ipv4: Ipv4Addr,
+    pub port: u16,
+    pub wait_polling_interval_ms: u64,
+    // Limit of incoming messages, which carry SQL dumps of template imports
+    pub max_message_size_mb: usize,
+}
+
+// Built by the host from the TLS configs of the http server
+pub struct GrpcTlsConfigs {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    // Requires clients to present a certificate signed by this CA
+    pub client_ca_path: Option<PathBuf>,
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    features::templates::get_templates::TemplateSummary,
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+    models::{
+        connection_strings::ConnectionStrings,
+        db_connection_options::DbConnectionOptions,
+        db_settings::DbSettings,
+        value_types::{
+            checkpoint_id::CheckpointId, extension_name::ExtensionName,
+            pg_identifier::PgIdentifier, template_hash::TemplateHash, test_db_id::TestDbId,
+        },
+    },
+};
+use prost_types::Timestamp;
+use tonic::Status;
+
+use crate::proto::{
+    self, Empty, InitializationIsFailed, template_initialization_state, test_db_state,
+};
+
+pub fn parse_template_hash(value: &str) -> Result<TemplateHash, Status> {
+    value
+        .parse()
+        .map_err(|err| Status::invalid_argument(format!("Invalid template hash {value:?}: {err}")))
+}
+
+pub fn parse_test_db_id(value: &str) -> Result<TestDbId, Status> {
+    value
+        .parse()
+        .map_err(|err| Status::invalid_argument(format!("Invalid test db id {value:?}: {err}")))
+}
+
+pub fn parse_checkpoint_id(value: &str) -> Result<CheckpointId, Status> {
+    value
+        .parse()
+        .map_err(|err| Status::invalid_argument(format!("Invalid checkpoint id {value:?}: {err}")))
+}
+
+pub fn parse_pg_identifier(value: &str) -> Result<PgIdentifier, Status> {
+    value
+        .parse()
+        .map_err(|err| Status::invalid_argument(format!("Invalid identifier {value:?}: {err}")))
+}
+
+pub fn parse_db_settings(values: HashMap<String, String>) -> Result<DbSettings, Status> {
+    values
+        .into_iter()
+        .map(|(name, value)| {
+            let name = name.parse().map_err(|err| {
+                Status::invalid_argument(format!("Invalid setting name {name:?}: {err}"))
+            })?;
+
+            Ok((name, value.into()))
+        })
+        .collect()
+}
+
+pub fn parse_extension_names(values: Vec<String>) -> Result<Vec<ExtensionName>, Status> {
+    values
+        .into_iter()
+        .map(|value| {
+            value.parse().map_err(|err| {
+                Status::invalid_argument(format!("Invalid extension name {value:?}: {err}"))
+            })
+        })
+        .collect()
+}
+
+pub fn to_timestamp(value: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: value.timestamp(),
+        nanos: value.timestamp_subsec_nanos() as i32,
+    }
+}
+
+impl From<DbConnectionOptions> for proto::DbConnectionOptions {
+    fn from(value: DbConnectionOptions) -> Self {
+        let connection_strings = ConnectionStrings::new(&value);
+
+        proto::DbConnectionOptions {
+            host: value.host.into(),
+            port: value.port.into(),
+            username: value.username.into(),
+            password: value.password.into(),
+            database: value.database.into(),
+            connection_strings: Some(connection_strings.into()),
+        }
+    }
+}
+
+impl From<ConnectionStrings> for proto::ConnectionStrings {
+    fn from(value: ConnectionStrings) -> Self {
+        proto::ConnectionStrings {
+            uri: value.uri,
+            key_value: value.key_value,
+            jdbc: value.jdbc,
+            ado_net: value.ado_net,
+        }
+    }
+}
+
+impl From<TemplateInitializationState> for proto::TemplateInitializationState {
+    fn from(value: TemplateInitializationState) -> Self {
+        let state = match value {
+            TemplateInitializationState::Creating => {
+                template_initialization_state::State::Creating(Empty {})
+            }
+            TemplateInitializationState::Created => {
+                template_initialization_state::State::Created(Empty {})
+            }
+            TemplateInitializationState::InProgress {
+                initialization_deadline,
+            } => template_initialization_state::State::InProgress(
+                template_initialization_state::InProgress {
+                    initialization_deadline: Some(to_timestamp(initialization_deadline)),
+                },
+            ),
+            TemplateInitializationState::Finished => {
+                template_initialization_state::State::Finished(Empty {})
+            }
+            TemplateInitializationState::Failed { reason } => {
+                template_initialization_state::State::Failed(InitializationIsFailed {
+                    reason: reason.map(|x| x.to_string()),
+                })
+            }
+        };
+
+        proto::TemplateInitializationState { state: Some(state) }
+    }
+}
+
+impl From<TestDbState> for proto::TestDbState {
+    fn from(value: TestDbState) -> Self {
+        let state = match value {
+            TestDbState::Creating => test_db_state::State::Creating(Empty {}),
+            TestDbState::Ready => test_db_state::State::Ready(Empty {}),
+            TestDbState::Corrupted => test_db_state::State::Corrupted(Empty {}),
+            TestDbState::InUse { usage_deadline } => {
+                test_db_state::State::InUse(test_db_state::InUse {
+                    usage_deadline: Some(to_timestamp(usage_deadline)),
+                })
+            }
+            TestDbState::Retained { retention_deadline } => {
+                test_db_state::State::Retained(test_db_state::Retained {
+                    retention_deadline: Some(to_timestamp(retention_deadline)),
+                })
+            }
+        };
+
+        proto::TestDbState { state: Some(state) }
+    }
+}
+
+impl From<TemplateSummary> for proto::Template {
+    fn from(value: TemplateSummary) -> Self {
+        proto::Template {
+            template_hash: value.template_hash.to_string(),
+            initialization_state: Some(value.initialization_state.into()),
+            test_dbs: value
+                .test_dbs
+                .into_iter()
+                .map(|test_db| proto::TestDb {
+                    test_db_id: test_db.id.to_string(),
+                    state: Some(test_db.state.into()),
+                })
+                .collect(),
+        }
+    }
+}
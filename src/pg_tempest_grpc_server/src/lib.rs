@@ -0,0 +1,253 @@
+use std::{net::SocketAddrV4, sync::Arc, time::Duration};
+
+use pg_tempest_core::PgTempestCore;
+use pg_tempest_core::utils::errors::BoxDynError;
+use tonic::transport::Server;
+
+use crate::{
+    configs::{GrpcServerConfigs, GrpcTlsConfigs},
+    proto::pg_tempest_server::PgTempestServer,
+    services::PgTempestService,
+    tls::build_tls_config,
+};
+
+pub mod configs;
+mod conversions;
+mod proto;
+mod services;
+mod tls;
+
+pub struct GrpcServer {
+    service: PgTempestService,
+    configs: Arc<GrpcServerConfigs>,
+    tls: Option<GrpcTlsConfigs>,
+}
+
+impl GrpcServer {
+    pub fn new(tempest_core: Arc<PgTempestCore>, configs: Arc<GrpcServerConfigs>) -> GrpcServer {
+        let service = PgTempestService {
+            tempest_core,
+            wait_polling_interval: Duration::from_millis(configs.wait_polling_interval_ms),
+            admin_token: None,
+        };
+
+        GrpcServer {
+            service,
+            configs,
+            tls: None,
+        }
+    }
+
+    pub fn with_tls(mut self, tls: GrpcTlsConfigs) -> GrpcServer {
+        self.tls = Some(tls);
+        self
+    }
+
+    // Enables admin rpcs, which require `authorization: Bearer <token>` metadata
+    pub fn with_admin_token(mut self, admin_token: Arc<str>) -> GrpcServer {
+        self.service.admin_token = Some(admin_token);
+        self
+    }
+
+    pub async fn start(self) -> Result<(), BoxDynError> {
+        let socket_addr = SocketAddrV4::new(self.configs.ipv4, self.configs.port);
+
+        let mut builder = Server::builder();
+
+        match &self.tls {
+            Some(tls) => {
+                builder = builder.tls_config(build_tls_config(tls)?)?;
+                tracing::info!("Starting grpc server with TLS on {socket_addr}");
+            }
+            None => tracing::info!("Starting grpc server on {socket_addr}"),
+        }
+
+        builder
+            .add_service(
+                PgTempestServer::new(self.service)
+                    .max_decoding_message_size(self.configs.max_message_size_mb * 1024 * 1024),
+            )
+            .serve(socket_addr.into())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+    use pg_tempest_testkit::{TestContext, TestContextBuilder};
+    use tokio::net::TcpListener;
+    use tokio_stream::StreamExt;
+    use tonic::{
+        Code, Request,
+        transport::{Channel, Server, server::TcpIncoming},
+    };
+
+    use crate::{
+        proto::{
+            Empty, FinishTemplateInitializationRequest, ImportTemplateRequest,
+            StartTemplateInitializationRequest, TemplateInitializationState,
+            WaitForTemplateInitializationRequest, import_template_request::Dump,
+            import_template_response, pg_tempest_client::PgTempestClient,
+            pg_tempest_server::PgTempestServer, start_template_initialization_response,
+            template_initialization_state::State as InitializationState,
+            wait_for_template_initialization_response::State,
+        },
+        services::PgTempestService,
+    };
+
+    const TEMPLATE_HASH: &str = "0123456789abcdef0123456789abcdef";
+    const ADMIN_TOKEN: &str = "admin-token";
+
+    #[tokio::test]
+    async fn waiting_streams_initialization_states_until_it_is_finished() {
+        let context = TestContextBuilder::new().start().await;
+        let mut client = serve(&context, None).await;
+
+        let response = client
+            .start_template_initialization(StartTemplateInitializationRequest {
+                template_hash: TEMPLATE_HASH.to_string(),
+                initialization_duration_ms: 60_000,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(matches!(
+            response.result,
+            Some(start_template_initialization_response::Result::InitializationWasStarted(_))
+        ));
+
+        let mut states = client
+            .wait_for_template_initialization(WaitForTemplateInitializationRequest {
+                template_hash: TEMPLATE_HASH.to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        let first_state = states.next().await.unwrap().unwrap();
+        assert!(matches!(
+            first_state.state,
+            Some(State::InitializationState(TemplateInitializationState {
+                state: Some(InitializationState::InProgress(_))
+            }))
+        ));
+
+        client
+            .finish_template_initialization(FinishTemplateInitializationRequest {
+                template_hash: TEMPLATE_HASH.to_string(),
+            })
+            .await
+            .unwrap();
+
+        let last_state = states.next().await.unwrap().unwrap();
+        assert!(matches!(
+            last_state.state,
+            Some(State::InitializationState(TemplateInitializationState {
+                state: Some(InitializationState::Finished(Empty {}))
+            }))
+        ));
+        assert!(states.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn waiting_for_unknown_template_streams_not_found() {
+        let context = TestContextBuilder::new().start().await;
+        let mut client = serve(&context, None).await;
+
+        let mut states = client
+            .wait_for_template_initialization(WaitForTemplateInitializationRequest {
+                template_hash: TEMPLATE_HASH.to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        let state = states.next().await.unwrap().unwrap();
+        assert_eq!(state.state, Some(State::TemplateWasNotFound(Empty {})));
+    }
+
+    #[tokio::test]
+    async fn import_is_denied_without_configured_admin_token() {
+        let context = TestContextBuilder::new().start().await;
+        let mut client = serve(&context, None).await;
+
+        let status = client
+            .import_template(with_bearer(import_request(), ADMIN_TOKEN))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn import_requires_admin_token() {
+        let context = TestContextBuilder::new().start().await;
+        let mut client = serve(&context, Some(ADMIN_TOKEN)).await;
+
+        let missing_token_status = client
+            .import_template(Request::new(import_request()))
+            .await
+            .unwrap_err();
+        let invalid_token_status = client
+            .import_template(with_bearer(import_request(), "invalid-token"))
+            .await
+            .unwrap_err();
+        let response = client
+            .import_template(with_bearer(import_request(), ADMIN_TOKEN))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(missing_token_status.code(), Code::Unauthenticated);
+        assert_eq!(invalid_token_status.code(), Code::Unauthenticated);
+        assert_eq!(
+            response.result,
+            Some(import_template_response::Result::TemplateWasImported(
+                Empty {}
+            ))
+        );
+    }
+
+    async fn serve(context: &TestContext, admin_token: Option<&str>) -> PgTempestClient<Channel> {
+        let service = PgTempestService {
+            tempest_core: context.tempest_core.clone(),
+            wait_polling_interval: Duration::from_millis(10),
+            admin_token: admin_token.map(Arc::from),
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr: SocketAddr = listener.local_addr().unwrap();
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(PgTempestServer::new(service))
+                .serve_with_incoming(TcpIncoming::from(listener)),
+        );
+
+        PgTempestClient::connect(format!("http://{addr}"))
+            .await
+            .unwrap()
+    }
+
+    fn import_request() -> ImportTemplateRequest {
+        ImportTemplateRequest {
+            template_hash: TEMPLATE_HASH.to_string(),
+            dump: Some(Dump::SqlScript("CREATE TABLE users (id int);".to_string())),
+            initialization_duration_ms: 60_000,
+            parent_template_db_name: None,
+        }
+    }
+
+    fn with_bearer<T>(message: T, token: &str) -> Request<T> {
+        let mut request = Request::new(message);
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        request
+    }
+}
@@ -0,0 +1,3 @@
+#![allow(clippy::all)]
+
+tonic::include_proto!("pg_tempest.v1");
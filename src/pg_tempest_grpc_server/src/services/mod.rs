@@ -0,0 +1,204 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{PgTempestCore, utils::tokens::tokens_are_equal};
+use tonic::{Request, Response, Status};
+
+use crate::proto::{
+    CreateTestDbCheckpointRequest, CreateTestDbCheckpointResponse, DropRetainedTestDbRequest,
+    DropRetainedTestDbResponse, ExtendTemplateInitializationRequest,
+    ExtendTemplateInitializationResponse, ExtendTestDbUsageRequest, ExtendTestDbUsageResponse,
+    FailTemplateInitializationRequest, FailTemplateInitializationResponse,
+    FinishTemplateInitializationRequest, FinishTemplateInitializationResponse,
+    FinishTestDbUsageRequest, FinishTestDbUsageResponse, GetTemplatesRequest, GetTemplatesResponse,
+    GetTestDbRequest, GetTestDbResponse, ImportTemplateRequest, ImportTemplateResponse,
+    PromoteTestDbRequest, PromoteTestDbResponse, RestoreTestDbCheckpointRequest,
+    RestoreTestDbCheckpointResponse, StartTemplateInitializationRequest,
+    StartTemplateInitializationResponse, WaitForTemplateInitializationRequest,
+    pg_tempest_server::PgTempest,
+};
+use crate::services::templates::{
+    extend_template_initialization::extend_template_initialization,
+    fail_template_initialization::fail_template_initialization,
+    finish_template_initialization::finish_template_initialization,
+    get_templates::get_templates,
+    import_template::import_template,
+    start_template_initialization::start_template_initialization,
+    wait_for_template_initialization::{
+        WaitForTemplateInitializationStream, wait_for_template_initialization,
+    },
+};
+use crate::services::test_dbs::{
+    create_test_db_checkpoint::create_test_db_checkpoint,
+    drop_retained_test_db::drop_retained_test_db, extend_test_db_usage::extend_test_db_usage,
+    finish_test_db_usage::finish_test_db_usage, get_test_db::get_test_db,
+    promote_test_db::promote_test_db, restore_test_db_checkpoint::restore_test_db_checkpoint,
+};
+
+mod templates;
+mod test_dbs;
+
+pub struct PgTempestService {
+    pub tempest_core: Arc<PgTempestCore>,
+    pub wait_polling_interval: Duration,
+    pub admin_token: Option<Arc<str>>,
+}
+
+impl PgTempestService {
+    // Admin rpcs are rejected unless the server has an admin token and
+    // the request carries it in `authorization: Bearer <token>` metadata
+    fn authorize_admin<T>(&self, request: &Request<T>) -> Result<(), Status> {
+        let Some(admin_token) = &self.admin_token else {
+            return Err(Status::permission_denied(
+                "Admin rpcs are disabled, since server.admin.token isn't configured",
+            ));
+        };
+
+        let bearer_token = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        match bearer_token {
+            Some(bearer_token) if tokens_are_equal(bearer_token, admin_token) => Ok(()),
+            _ => Err(Status::unauthenticated("Admin token is missing or invalid")),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl PgTempest for PgTempestService {
+    async fn start_template_initialization(
+        &self,
+        request: Request<StartTemplateInitializationRequest>,
+    ) -> Result<Response<StartTemplateInitializationResponse>, Status> {
+        start_template_initialization(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn finish_template_initialization(
+        &self,
+        request: Request<FinishTemplateInitializationRequest>,
+    ) -> Result<Response<FinishTemplateInitializationResponse>, Status> {
+        finish_template_initialization(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn fail_template_initialization(
+        &self,
+        request: Request<FailTemplateInitializationRequest>,
+    ) -> Result<Response<FailTemplateInitializationResponse>, Status> {
+        fail_template_initialization(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn extend_template_initialization(
+        &self,
+        request: Request<ExtendTemplateInitializationRequest>,
+    ) -> Result<Response<ExtendTemplateInitializationResponse>, Status> {
+        extend_template_initialization(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    type WaitForTemplateInitializationStream = WaitForTemplateInitializationStream;
+
+    async fn wait_for_template_initialization(
+        &self,
+        request: Request<WaitForTemplateInitializationRequest>,
+    ) -> Result<Response<Self::WaitForTemplateInitializationStream>, Status> {
+        wait_for_template_initialization(
+            self.tempest_core.clone(),
+            request.into_inner(),
+            self.wait_polling_interval,
+        )
+        .await
+        .map(Response::new)
+    }
+
+    async fn get_templates(
+        &self,
+        request: Request<GetTemplatesRequest>,
+    ) -> Result<Response<GetTemplatesResponse>, Status> {
+        get_templates(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn import_template(
+        &self,
+        request: Request<ImportTemplateRequest>,
+    ) -> Result<Response<ImportTemplateResponse>, Status> {
+        self.authorize_admin(&request)?;
+
+        import_template(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn get_test_db(
+        &self,
+        request: Request<GetTestDbRequest>,
+    ) -> Result<Response<GetTestDbResponse>, Status> {
+        get_test_db(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn extend_test_db_usage(
+        &self,
+        request: Request<ExtendTestDbUsageRequest>,
+    ) -> Result<Response<ExtendTestDbUsageResponse>, Status> {
+        extend_test_db_usage(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn finish_test_db_usage(
+        &self,
+        request: Request<FinishTestDbUsageRequest>,
+    ) -> Result<Response<FinishTestDbUsageResponse>, Status> {
+        finish_test_db_usage(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn drop_retained_test_db(
+        &self,
+        request: Request<DropRetainedTestDbRequest>,
+    ) -> Result<Response<DropRetainedTestDbResponse>, Status> {
+        drop_retained_test_db(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn create_test_db_checkpoint(
+        &self,
+        request: Request<CreateTestDbCheckpointRequest>,
+    ) -> Result<Response<CreateTestDbCheckpointResponse>, Status> {
+        create_test_db_checkpoint(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn restore_test_db_checkpoint(
+        &self,
+        request: Request<RestoreTestDbCheckpointRequest>,
+    ) -> Result<Response<RestoreTestDbCheckpointResponse>, Status> {
+        restore_test_db_checkpoint(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+
+    async fn promote_test_db(
+        &self,
+        request: Request<PromoteTestDbRequest>,
+    ) -> Result<Response<PromoteTestDbResponse>, Status> {
+        promote_test_db(self.tempest_core.clone(), request.into_inner())
+            .await
+            .map(Response::new)
+    }
+}
@@ -0,0 +1,54 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::extend_template_initialization::ExtendTemplateInitializationErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, to_timestamp};
+use crate::proto::{
+    Empty, ExtendTemplateInitializationRequest, ExtendTemplateInitializationResponse,
+    InitializationIsFailed,
+    extend_template_initialization_response::{
+        InitializationWasExtended, Result as ResponseResult,
+    },
+};
+
+pub async fn extend_template_initialization(
+    tempest_core: Arc<PgTempestCore>,
+    request: ExtendTemplateInitializationRequest,
+) -> Result<ExtendTemplateInitializationResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+
+    let result = tempest_core
+        .extend_template_initialization(
+            template_hash,
+            Duration::from_millis(request.additional_time_ms),
+        )
+        .await;
+
+    let result = match result {
+        Ok(result) => ResponseResult::InitializationWasExtended(InitializationWasExtended {
+            new_initialization_deadline: Some(to_timestamp(result.new_initialization_deadline)),
+        }),
+        Err(ExtendTemplateInitializationErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsFinished) => {
+            ResponseResult::InitializationIsFinished(Empty {})
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsFailed { reason }) => {
+            ResponseResult::InitializationIsFailed(InitializationIsFailed {
+                reason: reason.map(|x| x.to_string()),
+            })
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsNotStarted) => {
+            ResponseResult::InitializationIsNotStarted(Empty {})
+        }
+    };
+
+    Ok(ExtendTemplateInitializationResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::fail_template_initialization::FailTemplateInitializationError,
+};
+use tonic::Status;
+
+use crate::conversions::parse_template_hash;
+use crate::proto::{
+    Empty, FailTemplateInitializationRequest, FailTemplateInitializationResponse,
+    fail_template_initialization_response::Result as ResponseResult,
+};
+
+pub async fn fail_template_initialization(
+    tempest_core: Arc<PgTempestCore>,
+    request: FailTemplateInitializationRequest,
+) -> Result<FailTemplateInitializationResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+
+    let result = tempest_core
+        .fail_template_initialization(template_hash, request.reason.map(Into::into))
+        .await;
+
+    let result = match result {
+        Ok(()) => ResponseResult::InitializationIsFailed(Empty {}),
+        Err(FailTemplateInitializationError::InitializationIsFinished) => {
+            ResponseResult::InitializationIsFinished(Empty {})
+        }
+        Err(FailTemplateInitializationError::TemplateWasNotFound { .. }) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(FailTemplateInitializationError::InitializationIsNotStarted) => {
+            ResponseResult::InitializationIsNotStarted(Empty {})
+        }
+    };
+
+    Ok(FailTemplateInitializationResponse {
+        result: Some(result),
+    })
+}
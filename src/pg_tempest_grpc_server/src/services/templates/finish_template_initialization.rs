@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::finish_template_initialization::FinishTemplateInitializationErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::parse_template_hash;
+use crate::proto::{
+    Empty, FinishTemplateInitializationRequest, FinishTemplateInitializationResponse,
+    InitializationIsFailed, finish_template_initialization_response::Result as ResponseResult,
+};
+
+pub async fn finish_template_initialization(
+    tempest_core: Arc<PgTempestCore>,
+    request: FinishTemplateInitializationRequest,
+) -> Result<FinishTemplateInitializationResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+
+    let result = tempest_core
+        .finish_template_initialization(template_hash)
+        .await;
+
+    let result = match result {
+        Ok(()) => ResponseResult::InitializationIsFinished(Empty {}),
+        Err(FinishTemplateInitializationErrorResult::InitializationIsFailed { reason }) => {
+            ResponseResult::InitializationIsFailed(InitializationIsFailed {
+                reason: reason.map(|x| x.to_string()),
+            })
+        }
+        Err(FinishTemplateInitializationErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(FinishTemplateInitializationErrorResult::InitializationIsNotStarted) => {
+            ResponseResult::InitializationIsNotStarted(Empty {})
+        }
+    };
+
+    Ok(FinishTemplateInitializationResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use pg_tempest_core::PgTempestCore;
+use tonic::Status;
+
+use crate::proto::{GetTemplatesRequest, GetTemplatesResponse};
+
+pub async fn get_templates(
+    tempest_core: Arc<PgTempestCore>,
+    _request: GetTemplatesRequest,
+) -> Result<GetTemplatesResponse, Status> {
+    let templates = tempest_core.get_templates().await;
+
+    Ok(GetTemplatesResponse {
+        templates: templates.into_iter().map(Into::into).collect(),
+    })
+}
@@ -0,0 +1,73 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::import_template::{ImportTemplateResult, TemplateDump},
+};
+use tonic::Status;
+
+use crate::conversions::{parse_pg_identifier, parse_template_hash};
+use crate::proto::{
+    Empty, ImportTemplateRequest, ImportTemplateResponse, InitializationIsFailed, UnexpectedError,
+    import_template_request::Dump, import_template_response::Result as ResponseResult,
+};
+
+pub async fn import_template(
+    tempest_core: Arc<PgTempestCore>,
+    request: ImportTemplateRequest,
+) -> Result<ImportTemplateResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let parent_template_db_name = request
+        .parent_template_db_name
+        .as_deref()
+        .map(parse_pg_identifier)
+        .transpose()?;
+    let dump = match request.dump {
+        Some(Dump::SqlScript(script)) => TemplateDump::Sql {
+            script: script.into(),
+        },
+        Some(Dump::ArchivePath(path)) => TemplateDump::Archive {
+            path: PathBuf::from(path).into(),
+        },
+        None => return Err(Status::invalid_argument("Dump is required")),
+    };
+
+    let result = tempest_core
+        .import_template(
+            template_hash,
+            dump,
+            Duration::from_millis(request.initialization_duration_ms),
+            parent_template_db_name,
+        )
+        .await;
+
+    let result = match result {
+        Ok(ImportTemplateResult::TemplateWasImported) => {
+            ResponseResult::TemplateWasImported(Empty {})
+        }
+        Ok(ImportTemplateResult::InitializationIsInProgress) => {
+            ResponseResult::InitializationIsInProgress(Empty {})
+        }
+        Ok(ImportTemplateResult::InitializationIsFinished) => {
+            ResponseResult::InitializationIsFinished(Empty {})
+        }
+        Ok(ImportTemplateResult::InitializationIsFailed { reason }) => {
+            ResponseResult::InitializationIsFailed(InitializationIsFailed {
+                reason: reason.map(|x| x.to_string()),
+            })
+        }
+        Ok(ImportTemplateResult::ArchiveImportIsDisabled) => {
+            ResponseResult::ArchiveImportIsDisabled(Empty {})
+        }
+        Ok(ImportTemplateResult::ArchivePathIsInvalid) => {
+            ResponseResult::ArchivePathIsInvalid(Empty {})
+        }
+        Err(err) => ResponseResult::UnexpectedError(UnexpectedError {
+            message: err.to_string(),
+        }),
+    };
+
+    Ok(ImportTemplateResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,73 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::start_template_initialization::StartTemplateInitializationResult,
+};
+use tonic::Status;
+
+use crate::conversions::{
+    parse_db_settings, parse_extension_names, parse_pg_identifier, parse_template_hash,
+    to_timestamp,
+};
+use crate::proto::{
+    Empty, InitializationIsFailed, StartTemplateInitializationRequest,
+    StartTemplateInitializationResponse, UnexpectedError,
+    start_template_initialization_response::{InitializationWasStarted, Result as ResponseResult},
+};
+
+pub async fn start_template_initialization(
+    tempest_core: Arc<PgTempestCore>,
+    request: StartTemplateInitializationRequest,
+) -> Result<StartTemplateInitializationResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let parent_template_db_name = request
+        .parent_template_db_name
+        .as_deref()
+        .map(parse_pg_identifier)
+        .transpose()?;
+    let settings = parse_db_settings(request.settings)?;
+    let extension_names = parse_extension_names(request.extensions)?;
+
+    let result = tempest_core
+        .start_template_initialization(
+            template_hash,
+            Duration::from_millis(request.initialization_duration_ms),
+            parent_template_db_name,
+            request.network_profile.map(Into::into),
+            settings,
+            extension_names,
+        )
+        .await;
+
+    let result = match result {
+        Ok(StartTemplateInitializationResult::InitializationWasStarted {
+            database_connection_options,
+            initialization_deadline,
+        }) => ResponseResult::InitializationWasStarted(InitializationWasStarted {
+            database_connection_options: Some(database_connection_options.into()),
+            initialization_deadline: Some(to_timestamp(initialization_deadline)),
+        }),
+        Ok(StartTemplateInitializationResult::InitializationIsInProgress) => {
+            ResponseResult::InitializationIsInProgress(Empty {})
+        }
+        Ok(StartTemplateInitializationResult::InitializationIsFinished) => {
+            ResponseResult::InitializationIsFinished(Empty {})
+        }
+        Ok(StartTemplateInitializationResult::InitializationIsFailed { reason }) => {
+            ResponseResult::InitializationIsFailed(InitializationIsFailed {
+                reason: reason.map(|x| x.to_string()),
+            })
+        }
+        Ok(StartTemplateInitializationResult::NetworkProfileWasNotFound) => {
+            ResponseResult::NetworkProfileWasNotFound(Empty {})
+        }
+        Err(err) => ResponseResult::UnexpectedError(UnexpectedError {
+            message: err.to_string(),
+        }),
+    };
+
+    Ok(StartTemplateInitializationResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,85 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{PgTempestCore, models::value_types::template_hash::TemplateHash};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+use tracing::debug;
+
+use crate::conversions::parse_template_hash;
+use crate::proto::{
+    Empty, WaitForTemplateInitializationRequest, WaitForTemplateInitializationResponse,
+    template_initialization_state, wait_for_template_initialization_response::State,
+};
+
+pub type WaitForTemplateInitializationStream =
+    ReceiverStream<Result<WaitForTemplateInitializationResponse, Status>>;
+
+pub async fn wait_for_template_initialization(
+    tempest_core: Arc<PgTempestCore>,
+    request: WaitForTemplateInitializationRequest,
+    polling_interval: Duration,
+) -> Result<WaitForTemplateInitializationStream, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+
+    let (sender, receiver) = mpsc::channel(1);
+
+    tokio::spawn(send_state_changes(
+        tempest_core,
+        template_hash,
+        polling_interval,
+        sender,
+    ));
+
+    Ok(ReceiverStream::new(receiver))
+}
+
+// Core doesn't notify about state changes, so the state is polled
+// and sent to the client only when it differs from the previous one
+async fn send_state_changes(
+    tempest_core: Arc<PgTempestCore>,
+    template_hash: TemplateHash,
+    polling_interval: Duration,
+    sender: mpsc::Sender<Result<WaitForTemplateInitializationResponse, Status>>,
+) {
+    let mut last_state = None;
+
+    loop {
+        let state = match tempest_core.get_template(template_hash).await {
+            Some(template) => State::InitializationState(template.initialization_state.into()),
+            None => State::TemplateWasNotFound(Empty {}),
+        };
+
+        let is_completed = matches!(
+            &state,
+            State::InitializationState(initialization_state) if matches!(
+                initialization_state.state,
+                Some(template_initialization_state::State::Finished(_))
+                    | Some(template_initialization_state::State::Failed(_))
+            )
+        );
+
+        if last_state.as_ref() != Some(&state) {
+            let response = WaitForTemplateInitializationResponse {
+                state: Some(state.clone()),
+            };
+
+            if sender.send(Ok(response)).await.is_err() {
+                break;
+            }
+
+            last_state = Some(state);
+        }
+
+        if is_completed {
+            break;
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(polling_interval) => {}
+            _ = sender.closed() => break,
+        }
+    }
+
+    debug!("Waiting for template {template_hash} initialization is stopped");
+}
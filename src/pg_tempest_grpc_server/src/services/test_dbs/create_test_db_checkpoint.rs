@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore, features::test_dbs::create_test_db_checkpoint::CreateTestDbCheckpointErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, parse_test_db_id};
+use crate::proto::{
+    CreateTestDbCheckpointRequest, CreateTestDbCheckpointResponse, Empty, UnexpectedError,
+    create_test_db_checkpoint_response::{CheckpointWasCreated, Result as ResponseResult},
+};
+
+pub async fn create_test_db_checkpoint(
+    tempest_core: Arc<PgTempestCore>,
+    request: CreateTestDbCheckpointRequest,
+) -> Result<CreateTestDbCheckpointResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+
+    let result = tempest_core
+        .create_test_db_checkpoint(template_hash, test_db_id)
+        .await;
+
+    let result = match result {
+        Ok(result) => ResponseResult::CheckpointWasCreated(CheckpointWasCreated {
+            checkpoint_id: result.checkpoint_id.to_string(),
+        }),
+        Err(CreateTestDbCheckpointErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbIsNotUsed) => {
+            ResponseResult::TestDbIsNotUsed(Empty {})
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbHasConnections) => {
+            ResponseResult::TestDbHasConnections(Empty {})
+        }
+        Err(CreateTestDbCheckpointErrorResult::Unknown { inner }) => {
+            ResponseResult::UnexpectedError(UnexpectedError {
+                message: inner.to_string(),
+            })
+        }
+    };
+
+    Ok(CreateTestDbCheckpointResponse {
+        result: Some(result),
+    })
+}
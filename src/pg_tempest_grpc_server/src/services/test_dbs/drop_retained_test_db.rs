@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore, features::test_dbs::drop_retained_test_db::DropRetainedTestDbErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, parse_test_db_id};
+use crate::proto::{
+    DropRetainedTestDbRequest, DropRetainedTestDbResponse, Empty,
+    drop_retained_test_db_response::Result as ResponseResult,
+};
+
+pub async fn drop_retained_test_db(
+    tempest_core: Arc<PgTempestCore>,
+    request: DropRetainedTestDbRequest,
+) -> Result<DropRetainedTestDbResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+
+    let result = tempest_core
+        .drop_retained_test_db(template_hash, test_db_id)
+        .await;
+
+    let result = match result {
+        Ok(()) => ResponseResult::TestDbWasDropped(Empty {}),
+        Err(DropRetainedTestDbErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(DropRetainedTestDbErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(DropRetainedTestDbErrorResult::TestDbIsNotRetained) => {
+            ResponseResult::TestDbIsNotRetained(Empty {})
+        }
+    };
+
+    Ok(DropRetainedTestDbResponse {
+        result: Some(result),
+    })
+}
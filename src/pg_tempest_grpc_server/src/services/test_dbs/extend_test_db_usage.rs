@@ -0,0 +1,50 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore, features::test_dbs::extend_test_db_usage::ExtendTestDbUsageErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, parse_test_db_id, to_timestamp};
+use crate::proto::{
+    Empty, ExtendTestDbUsageRequest, ExtendTestDbUsageResponse,
+    extend_test_db_usage_response::{Result as ResponseResult, UsageWasExtended},
+};
+
+pub async fn extend_test_db_usage(
+    tempest_core: Arc<PgTempestCore>,
+    request: ExtendTestDbUsageRequest,
+) -> Result<ExtendTestDbUsageResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+
+    let result = tempest_core
+        .extend_test_db_usage(
+            template_hash,
+            test_db_id,
+            Duration::from_millis(request.additional_time_ms),
+        )
+        .await;
+
+    let result = match result {
+        Ok(result) => ResponseResult::UsageWasExtended(UsageWasExtended {
+            new_usage_deadline: Some(to_timestamp(result.new_usage_deadline)),
+        }),
+        Err(ExtendTestDbUsageErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbIsNotUsed) => {
+            ResponseResult::TestDbIsNotUsed(Empty {})
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbIsCorrupted) => {
+            ResponseResult::TestDbIsCorrupted(Empty {})
+        }
+    };
+
+    Ok(ExtendTestDbUsageResponse {
+        result: Some(result),
+    })
+}
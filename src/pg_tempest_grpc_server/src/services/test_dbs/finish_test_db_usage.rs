@@ -0,0 +1,65 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::finish_test_db_usage::{
+        FinishTestDbUsageErrorResult, FinishTestDbUsageOkResult, TestDbRetention,
+        TestDbUsageOutcome,
+    },
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, parse_test_db_id, to_timestamp};
+use crate::proto::{
+    self, Empty, FinishTestDbUsageRequest, FinishTestDbUsageResponse,
+    finish_test_db_usage_response::{Result as ResponseResult, TestDbWasRetained},
+};
+
+pub async fn finish_test_db_usage(
+    tempest_core: Arc<PgTempestCore>,
+    request: FinishTestDbUsageRequest,
+) -> Result<FinishTestDbUsageResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+    let outcome = match request.outcome() {
+        proto::TestDbUsageOutcome::Succeeded => TestDbUsageOutcome::Succeeded,
+        proto::TestDbUsageOutcome::Failed => TestDbUsageOutcome::Failed,
+    };
+    let retention = request.retain_for_ms.map(|retain_for_ms| TestDbRetention {
+        retention_duration: Duration::from_millis(retain_for_ms),
+        network_profile: request.network_profile.map(Into::into),
+    });
+
+    let result = tempest_core
+        .finish_test_db_usage(template_hash, test_db_id, outcome, retention)
+        .await;
+
+    let result = match result {
+        Ok(FinishTestDbUsageOkResult::TestDbWasReleased) => {
+            ResponseResult::TestDbWasReleased(Empty {})
+        }
+        Ok(FinishTestDbUsageOkResult::TestDbWasRetained {
+            connection_options,
+            retention_deadline,
+        }) => ResponseResult::TestDbWasRetained(TestDbWasRetained {
+            db_connection_options: Some(connection_options.into()),
+            retention_deadline: Some(to_timestamp(retention_deadline)),
+        }),
+        Err(FinishTestDbUsageErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbIsNotUsed) => {
+            ResponseResult::TestDbIsNotUsed(Empty {})
+        }
+        Err(FinishTestDbUsageErrorResult::NetworkProfileWasNotFound) => {
+            ResponseResult::NetworkProfileWasNotFound(Empty {})
+        }
+    };
+
+    Ok(FinishTestDbUsageResponse {
+        result: Some(result),
+    })
+}
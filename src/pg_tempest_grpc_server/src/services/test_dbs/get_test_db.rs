@@ -0,0 +1,51 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{PgTempestCore, features::test_dbs::get_test_db::GetTestDbErrorResult};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, to_timestamp};
+use crate::proto::{
+    Empty, GetTestDbRequest, GetTestDbResponse, UnexpectedError,
+    get_test_db_response::{Result as ResponseResult, TestDbWasCreated},
+};
+
+pub async fn get_test_db(
+    tempest_core: Arc<PgTempestCore>,
+    request: GetTestDbRequest,
+) -> Result<GetTestDbResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+
+    let result = tempest_core
+        .get_test_db(
+            template_hash,
+            Duration::from_millis(request.usage_duration_ms),
+            request.network_profile.map(Into::into),
+        )
+        .await;
+
+    let result = match result {
+        Ok(result) => ResponseResult::TestDbWasCreated(TestDbWasCreated {
+            test_db_id: result.test_db_id.to_string(),
+            db_connection_options: Some(result.connection_options.into()),
+            usage_deadline: Some(to_timestamp(result.usage_deadline)),
+        }),
+        Err(GetTestDbErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(GetTestDbErrorResult::TemplateIsNotInitialized) => {
+            ResponseResult::TemplateIsNotInitialized(Empty {})
+        }
+        Err(GetTestDbErrorResult::NetworkProfileWasNotFound) => {
+            ResponseResult::NetworkProfileWasNotFound(Empty {})
+        }
+        Err(GetTestDbErrorResult::Unknown { inner }) => {
+            ResponseResult::UnknownError(UnexpectedError {
+                message: inner.to_string(),
+            })
+        }
+    };
+
+    Ok(GetTestDbResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,7 @@
+pub mod create_test_db_checkpoint;
+pub mod drop_retained_test_db;
+pub mod extend_test_db_usage;
+pub mod finish_test_db_usage;
+pub mod get_test_db;
+pub mod promote_test_db;
+pub mod restore_test_db_checkpoint;
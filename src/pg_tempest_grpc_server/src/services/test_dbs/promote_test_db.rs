@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore, features::test_dbs::promote_test_db::PromoteTestDbErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_template_hash, parse_test_db_id};
+use crate::proto::{
+    Empty, PromoteTestDbRequest, PromoteTestDbResponse, UnexpectedError,
+    promote_test_db_response::Result as ResponseResult,
+};
+
+pub async fn promote_test_db(
+    tempest_core: Arc<PgTempestCore>,
+    request: PromoteTestDbRequest,
+) -> Result<PromoteTestDbResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+    let new_template_hash = parse_template_hash(&request.new_template_hash)?;
+
+    let result = tempest_core
+        .promote_test_db(template_hash, test_db_id, new_template_hash)
+        .await;
+
+    let result = match result {
+        Ok(()) => ResponseResult::TestDbWasPromoted(Empty {}),
+        Err(PromoteTestDbErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(PromoteTestDbErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(PromoteTestDbErrorResult::TestDbIsNotUsed) => ResponseResult::TestDbIsNotUsed(Empty {}),
+        Err(PromoteTestDbErrorResult::TestDbHasConnections) => {
+            ResponseResult::TestDbHasConnections(Empty {})
+        }
+        Err(PromoteTestDbErrorResult::NewTemplateAlreadyExists) => {
+            ResponseResult::NewTemplateAlreadyExists(Empty {})
+        }
+        Err(PromoteTestDbErrorResult::Unknown { inner }) => {
+            ResponseResult::UnexpectedError(UnexpectedError {
+                message: inner.to_string(),
+            })
+        }
+    };
+
+    Ok(PromoteTestDbResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::restore_test_db_checkpoint::RestoreTestDbCheckpointErrorResult,
+};
+use tonic::Status;
+
+use crate::conversions::{parse_checkpoint_id, parse_template_hash, parse_test_db_id};
+use crate::proto::{
+    Empty, RestoreTestDbCheckpointRequest, RestoreTestDbCheckpointResponse, UnexpectedError,
+    restore_test_db_checkpoint_response::Result as ResponseResult,
+};
+
+pub async fn restore_test_db_checkpoint(
+    tempest_core: Arc<PgTempestCore>,
+    request: RestoreTestDbCheckpointRequest,
+) -> Result<RestoreTestDbCheckpointResponse, Status> {
+    let template_hash = parse_template_hash(&request.template_hash)?;
+    let test_db_id = parse_test_db_id(&request.test_db_id)?;
+    let checkpoint_id = parse_checkpoint_id(&request.checkpoint_id)?;
+
+    let result = tempest_core
+        .restore_test_db_checkpoint(template_hash, test_db_id, checkpoint_id)
+        .await;
+
+    let result = match result {
+        Ok(()) => ResponseResult::TestDbWasRestored(Empty {}),
+        Err(RestoreTestDbCheckpointErrorResult::TemplateWasNotFound) => {
+            ResponseResult::TemplateWasNotFound(Empty {})
+        }
+        Err(RestoreTestDbCheckpointErrorResult::TestDbWasNotFound) => {
+            ResponseResult::TestDbWasNotFound(Empty {})
+        }
+        Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed) => {
+            ResponseResult::TestDbIsNotUsed(Empty {})
+        }
+        Err(RestoreTestDbCheckpointErrorResult::CheckpointWasNotFound) => {
+            ResponseResult::CheckpointWasNotFound(Empty {})
+        }
+        Err(RestoreTestDbCheckpointErrorResult::TestDbHasConnections) => {
+            ResponseResult::TestDbHasConnections(Empty {})
+        }
+        Err(RestoreTestDbCheckpointErrorResult::Unknown { inner }) => {
+            ResponseResult::UnexpectedError(UnexpectedError {
+                message: inner.to_string(),
+            })
+        }
+    };
+
+    Ok(RestoreTestDbCheckpointResponse {
+        result: Some(result),
+    })
+}
@@ -0,0 +1,26 @@
+use std::{fs, path::Path};
+
+use pg_tempest_core::utils::errors::BoxDynError;
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+
+use crate::configs::GrpcTlsConfigs;
+
+pub fn build_tls_config(configs: &GrpcTlsConfigs) -> Result<ServerTlsConfig, BoxDynError> {
+    let cert = read_pem(&configs.cert_path)?;
+    let key = read_pem(&configs.key_path)?;
+
+    let tls_config = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+
+    let tls_config = match &configs.client_ca_path {
+        Some(client_ca_path) => {
+            tls_config.client_ca_root(Certificate::from_pem(read_pem(client_ca_path)?))
+        }
+        None => tls_config,
+    };
+
+    Ok(tls_config)
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>, BoxDynError> {
+    Ok(fs::read(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))?)
+}
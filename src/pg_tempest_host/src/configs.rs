@@ -3,7 +3,12 @@ use std::{env, sync::Arc};
 use crate::logging::configs::LoggingConfigs;
 use config::{Config, ConfigError};
 use pg_tempest_core::configs::templates_configs::TemplatesConfigs;
-use pg_tempest_core::configs::{db_pool_configs::DbPoolConfigs, dbms_configs::DbmsConfigs};
+use pg_tempest_core::configs::{
+    db_pool_configs::DbPoolConfigs, dbms_configs::DbmsConfigs,
+    fault_injection_configs::FaultInjectionConfigs,
+};
+use pg_tempest_grpc_server::configs::GrpcServerConfigs;
+use pg_tempest_proxy::configs::ProxyConfigs;
 use pg_tempest_server::configs::ServerConfigs;
 use serde::Deserialize;
 
@@ -12,8 +17,11 @@ pub struct AppConfigs {
     pub dbms: Arc<DbmsConfigs>,
     pub db_pool: Arc<DbPoolConfigs>,
     pub server: Arc<ServerConfigs>,
+    pub grpc: Arc<GrpcServerConfigs>,
+    pub proxy: Arc<ProxyConfigs>,
     pub logging: Arc<LoggingConfigs>,
     pub templates: Arc<TemplatesConfigs>,
+    pub faults: Arc<FaultInjectionConfigs>,
 }
 
 pub fn build_app_configs() -> Result<Arc<AppConfigs>, ConfigError> {
@@ -12,6 +12,11 @@ pub fn setup_logging(configs: Arc<LoggingConfigs>) -> Result<(), BoxDynError> {
         .with_default(Level::INFO)
         .with_target("pg_tempest_core", configs.core.unwrap_or(Level::INFO))
         .with_target("pg_tempest_server", configs.server.unwrap_or(Level::INFO))
+        .with_target(
+            "pg_tempest_grpc_server",
+            configs.server.unwrap_or(Level::INFO),
+        )
+        .with_target("pg_tempest_proxy", configs.server.unwrap_or(Level::INFO))
         .with_target("sqlx", configs.db_queries.unwrap_or(Level::INFO));
 
     let r = tracing_subscriber::registry()
@@ -1,11 +1,18 @@
 use std::sync::Arc;
 
 use pg_tempest_core::PgTempestCore;
+use pg_tempest_core::fault_injection::{FaultInjectingPgClient, FaultInjection};
 use pg_tempest_core::utils::errors::BoxDynError;
+use pg_tempest_grpc_server::{GrpcServer, configs::GrpcTlsConfigs};
 use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use pg_tempest_proxy::Proxy;
 use pg_tempest_server::Server;
+use tokio::task::JoinSet;
 
-use crate::{configs::build_app_configs, logging::setup_logging};
+use crate::{
+    configs::{AppConfigs, build_app_configs},
+    logging::setup_logging,
+};
 
 mod configs;
 pub mod logging;
@@ -16,26 +23,78 @@ async fn main() -> Result<(), BoxDynError> {
 
     setup_logging(configs.logging.clone())?;
 
-    let pg_client = Arc::new(PgClientImpl::new(configs.dbms.clone()));
+    let fault_injection = Arc::new(FaultInjection::new(configs.faults.as_ref().clone())?);
+    let pg_client = Arc::new(FaultInjectingPgClient::new(
+        Arc::new(PgClientImpl::new(configs.dbms.clone())),
+        fault_injection.clone(),
+    ));
 
-    let tempest_core = Arc::new(
-        PgTempestCore::new(
-            pg_client,
-            configs.dbms.clone(),
-            configs.db_pool.clone(),
-            configs.templates.clone(),
+    let tempest_core = PgTempestCore::start(
+        pg_client,
+        configs.dbms.clone(),
+        configs.db_pool.clone(),
+        configs.templates.clone(),
+    )
+    .await?;
+
+    let mut servers = JoinSet::new();
+
+    servers.spawn(
+        Server::new(
+            tempest_core.clone(),
+            fault_injection.clone(),
+            configs.server.clone(),
         )
-        .await?,
+        .start(),
     );
 
-    let server = Server::new(tempest_core.clone(), configs.server.clone());
+    if configs.grpc.enabled {
+        servers.spawn(build_grpc_server(tempest_core.clone(), &configs)?.start());
+    }
 
-    tempest_core
-        .clone()
-        .start_test_db_creation_retries_in_background();
-    tempest_core.start_template_initialization_deadline_handling();
+    if configs.proxy.enabled {
+        servers.spawn(
+            Proxy::new(
+                tempest_core.clone(),
+                configs.dbms.clone(),
+                configs.proxy.clone(),
+            )
+            .start(),
+        );
+    }
 
-    server.start().await?;
+    // Servers run until one of them fails
+    while let Some(result) = servers.join_next().await {
+        result??;
+    }
 
     Ok(())
 }
+
+// gRPC is secured with the TLS certificate and the admin token of the http server
+fn build_grpc_server(
+    tempest_core: Arc<PgTempestCore>,
+    configs: &AppConfigs,
+) -> Result<GrpcServer, BoxDynError> {
+    let mut grpc_server = GrpcServer::new(tempest_core, configs.grpc.clone());
+
+    if let Some(admin_token) = &configs.server.admin.token {
+        grpc_server = grpc_server.with_admin_token(admin_token.clone());
+    }
+
+    let tls = &configs.server.tls;
+
+    if tls.enabled {
+        let (Some(cert_path), Some(key_path)) = (&tls.cert_path, &tls.key_path) else {
+            return Err("server.tls.cert_path and server.tls.key_path must be configured".into());
+        };
+
+        grpc_server = grpc_server.with_tls(GrpcTlsConfigs {
+            cert_path: cert_path.clone(),
+            key_path: key_path.clone(),
+            client_ca_path: tls.client_ca_path.clone(),
+        });
+    }
+
+    Ok(grpc_server)
+}
@@ -0,0 +1,30 @@
+use syn::{ItemFn, parse_macro_input};
+
+use crate::test_attribute::{TestAttributeArgs, expand_test};
+
+mod test_attribute;
+
+// Wraps an async test: initializes a template from the migrations directory,
+// leases a test db and injects arguments implementing `pg_tempest_client::testing::TestArgument`.
+// Test dbs of failed tests are retained for `retain_failed_for_ms`, which defaults to
+// the PG_TEMPEST_RETAIN_FAILED_FOR_MS environment variable.
+// The template hash is computed from the migrations when the test is run, so added or changed
+// migrations produce a new template without rebuilding the test.
+//
+// #[pg_tempest_client::test(migrations = "migrations")]
+// async fn inserts_user(pool: sqlx::PgPool) { ... }
+#[proc_macro_attribute]
+pub fn test(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut test_attribute_args = TestAttributeArgs::default();
+    let args_parser = syn::meta::parser(|meta| test_attribute_args.parse(meta));
+    parse_macro_input!(args with args_parser);
+
+    let test_fn = parse_macro_input!(item as ItemFn);
+
+    expand_test(test_attribute_args, test_fn)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,159 @@
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ItemFn, LitInt, LitStr, meta::ParseNestedMeta, spanned::Spanned};
+
+const DEFAULT_INITIALIZATION_DURATION_MS: u64 = 60_000;
+const DEFAULT_USAGE_DURATION_MS: u64 = 60_000;
+
+#[derive(Default)]
+pub struct TestAttributeArgs {
+    migrations: Option<LitStr>,
+    initialization_duration_ms: Option<LitInt>,
+    usage_duration_ms: Option<LitInt>,
+    retain_failed_for_ms: Option<LitInt>,
+}
+
+impl TestAttributeArgs {
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("migrations") {
+            self.migrations = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("initialization_duration_ms") {
+            self.initialization_duration_ms = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("usage_duration_ms") {
+            self.usage_duration_ms = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("retain_failed_for_ms") {
+            self.retain_failed_for_ms = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("unsupported pg_tempest test argument"));
+        }
+
+        Ok(())
+    }
+}
+
+pub fn expand_test(args: TestAttributeArgs, test_fn: ItemFn) -> syn::Result<TokenStream> {
+    if test_fn.sig.asyncness.is_none() {
+        return Err(syn::Error::new(
+            test_fn.sig.fn_token.span(),
+            "pg_tempest test must be an async fn",
+        ));
+    }
+
+    if !test_fn.sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            test_fn.sig.generics.span(),
+            "pg_tempest test can't be generic",
+        ));
+    }
+
+    let Some(migrations) = args.migrations else {
+        return Err(syn::Error::new(
+            test_fn.sig.ident.span(),
+            r#"missing `migrations = "<directory>"` argument"#,
+        ));
+    };
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|err| syn::Error::new(migrations.span(), err))?;
+    let migrations_dir = Path::new(&manifest_dir).join(migrations.value());
+
+    // Migrations themselves are hashed when the test is run
+    if !migrations_dir.is_dir() {
+        return Err(syn::Error::new(
+            migrations.span(),
+            format!("{} is not a directory", migrations_dir.display()),
+        ));
+    }
+
+    let mut argument_types = Vec::new();
+    for input in test_fn.sig.inputs.iter() {
+        match input {
+            FnArg::Typed(pat_type) => argument_types.push(pat_type.ty.as_ref().clone()),
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new(
+                    receiver.span(),
+                    "pg_tempest test can't take self",
+                ));
+            }
+        }
+    }
+
+    let argument_idents: Vec<_> = (0..argument_types.len())
+        .map(|index| format_ident!("__pg_tempest_argument_{index}"))
+        .collect();
+
+    let migrations = migrations.value();
+    let initialization_duration_ms = match args.initialization_duration_ms {
+        Some(lit) => lit.base10_parse()?,
+        None => DEFAULT_INITIALIZATION_DURATION_MS,
+    };
+    let usage_duration_ms = match args.usage_duration_ms {
+        Some(lit) => lit.base10_parse()?,
+        None => DEFAULT_USAGE_DURATION_MS,
+    };
+    // Without the argument, the retention is read from the environment when the test is run
+    let failure_retention = match args.retain_failed_for_ms {
+        Some(lit) => {
+            let retain_failed_for_ms: u64 = lit.base10_parse()?;
+            quote! { ::std::option::Option::Some(::std::time::Duration::from_millis(#retain_failed_for_ms)) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let attrs = &test_fn.attrs;
+    let vis = &test_fn.vis;
+    let name = &test_fn.sig.ident;
+    let output = &test_fn.sig.output;
+    let inner_fn = ItemFn {
+        attrs: Vec::new(),
+        ..test_fn.clone()
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #[::pg_tempest_client::__private::tokio::test(crate = "::pg_tempest_client::__private::tokio")]
+        #vis async fn #name() #output {
+            #inner_fn
+
+            let __pg_tempest_template = ::pg_tempest_client::testing::TestTemplate {
+                migrations_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/", #migrations),
+                initialization_duration: ::std::time::Duration::from_millis(#initialization_duration_ms),
+                usage_duration: ::std::time::Duration::from_millis(#usage_duration_ms),
+                failure_retention: #failure_retention,
+            };
+
+            let __pg_tempest_lease =
+                ::pg_tempest_client::testing::lease_test_db(&__pg_tempest_template).await;
+
+            #(
+                let #argument_idents =
+                    ::pg_tempest_client::testing::test_argument::<#argument_types>(&__pg_tempest_lease).await;
+            )*
+
+            let __pg_tempest_result = ::pg_tempest_client::testing::catch_unwind(
+                #name(#(::std::clone::Clone::clone(&#argument_idents)),*)
+            ).await;
+
+            #(::pg_tempest_client::testing::TestArgument::close(&#argument_idents).await;)*
+
+            let __pg_tempest_test_failed = match &__pg_tempest_result {
+                Ok(__pg_tempest_output) => {
+                    ::pg_tempest_client::testing::TestOutput::is_failure(__pg_tempest_output)
+                }
+                Err(_) => true,
+            };
+
+            ::pg_tempest_client::testing::finish_test_db_lease(
+                __pg_tempest_lease,
+                __pg_tempest_test_failed,
+            ).await;
+
+            match __pg_tempest_result {
+                Ok(__pg_tempest_output) => __pg_tempest_output,
+                Err(__pg_tempest_panic) => ::std::panic::resume_unwind(__pg_tempest_panic),
+            }
+        }
+    })
+}
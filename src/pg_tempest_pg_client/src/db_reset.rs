@@ -0,0 +1,232 @@
+use pg_tempest_core::{
+    pg_client::{DbSnapshot, SequenceSnapshot, TableSnapshot},
+    utils::errors::BoxDynError,
+};
+use sqlx::{Connection, FromRow, PgConnection, Postgres, Transaction, postgres::types::Oid};
+use tokio_stream::StreamExt;
+
+pub async fn get_db_snapshot(connection: &mut PgConnection) -> Result<DbSnapshot, BoxDynError> {
+    // Modified tables and catalogs can't be found without counters
+    let (track_counts, counters_are_flushed): (bool, bool) = sqlx::query_as(
+        r#"
+        select
+            current_setting('track_counts')::bool,
+            current_setting('server_version_num')::int >= 150000;
+        "#,
+    )
+    .fetch_one(&mut *connection)
+    .await?;
+    if !track_counts {
+        return Err("Snapshot can't be taken since track_counts is off".into());
+    }
+    if !counters_are_flushed {
+        return Err(
+            "Snapshot can't be taken since counters are flushed asynchronously before Postgres 15"
+                .into(),
+        );
+    }
+
+    let catalog_tuples_modified = get_catalog_tuples_modified(connection).await?;
+
+    let table_rows: Vec<TableRow> = sqlx::query_as(
+        r#"
+        select
+            c.oid,
+            c.relfilenode as file_node,
+            s.n_tup_ins as tuples_inserted,
+            s.n_tup_upd as tuples_updated,
+            s.n_tup_del as tuples_deleted
+        from pg_stat_user_tables s
+        join pg_class c on c.oid = s.relid
+        where c.relkind = 'r' and c.relpersistence <> 't';
+        "#,
+    )
+    .fetch_all(&mut *connection)
+    .await?;
+
+    let sequence_rows: Vec<SequenceRow> = sqlx::query_as(
+        r#"
+        select c.oid, s.last_value, s.start_value
+        from pg_sequences s
+        join pg_namespace n on n.nspname = s.schemaname
+        join pg_class c on c.relnamespace = n.oid and c.relname = s.sequencename
+        where c.relpersistence <> 't';
+        "#,
+    )
+    .fetch_all(&mut *connection)
+    .await?;
+
+    let tables = table_rows
+        .into_iter()
+        .map(|row| {
+            Ok(TableSnapshot {
+                oid: row.oid.0,
+                file_node: row.file_node.0,
+                tuples_inserted: row.tuples_inserted.try_into()?,
+                tuples_updated: row.tuples_updated.try_into()?,
+                tuples_deleted: row.tuples_deleted.try_into()?,
+            })
+        })
+        .collect::<Result<Vec<TableSnapshot>, BoxDynError>>()?;
+
+    let sequences = sequence_rows
+        .into_iter()
+        .map(|row| SequenceSnapshot {
+            oid: row.oid.0,
+            last_value: row.last_value,
+            start_value: row.start_value,
+        })
+        .collect();
+
+    Ok(DbSnapshot {
+        catalog_tuples_modified,
+        tables,
+        sequences,
+    })
+}
+
+// Every DDL, GRANT, COMMENT, policy or large object writes to catalogs of the db, so any
+// counted catalog change means the db can't be reset by rewriting user tables. Shared catalogs
+// are changed by other dbs, toast tables by values of user tables and planner statistics by ANALYZE
+async fn get_catalog_tuples_modified(connection: &mut PgConnection) -> Result<u64, BoxDynError> {
+    let catalog_tuples_modified: i64 = sqlx::query_scalar(
+        r#"
+        select coalesce(sum(s.n_tup_ins + s.n_tup_upd + s.n_tup_del), 0)::bigint
+        from pg_stat_sys_tables s
+        join pg_class c on c.oid = s.relid
+        where s.schemaname <> 'pg_toast'
+            and not c.relisshared
+            and c.oid not in (
+                'pg_catalog.pg_statistic'::regclass,
+                'pg_catalog.pg_statistic_ext_data'::regclass
+            );
+        "#,
+    )
+    .fetch_one(connection)
+    .await?;
+
+    Ok(catalog_tuples_modified.try_into()?)
+}
+
+// Tables which reference truncated ones by foreign keys must be truncated too,
+// and partitioned tables are copied by their partitions
+pub async fn get_tables_to_reset(
+    connection: &mut PgConnection,
+    table_oids: Vec<Oid>,
+) -> Result<Vec<String>, BoxDynError> {
+    let table_names: Vec<String> = sqlx::query_scalar(
+        r#"
+        with recursive tables(oid) as (
+            select unnest($1::oid[])
+            union
+            select related.oid
+            from tables t
+            cross join lateral (
+                select con.conrelid as oid
+                from pg_constraint con
+                where con.contype = 'f' and con.confrelid = t.oid
+                union all
+                select pt.relid as oid
+                from pg_partition_tree(t.oid) pt
+                where pt.relid <> t.oid
+            ) related
+        )
+        select format('%I.%I', n.nspname, c.relname)
+        from tables t
+        join pg_class c on c.oid = t.oid
+        join pg_namespace n on n.oid = c.relnamespace
+        where c.relkind = 'r' and c.relpersistence <> 't'
+        order by 1;
+        "#,
+    )
+    .bind(table_oids)
+    .fetch_all(connection)
+    .await?;
+
+    Ok(table_names)
+}
+
+// Triggers and foreign key checks are disabled while rows are written back,
+// since the rows are the same as they were in the template db.
+// Rows are streamed from the template db table by table rather than read into memory,
+// and the template connection is closed right after them, since dbs can't be created
+// from a template db with connections
+pub async fn write_tables_and_sequences(
+    connection: &mut PgConnection,
+    mut template_connection: PgConnection,
+    table_names: &[String],
+    sequences: &[&SequenceSnapshot],
+) -> Result<(), BoxDynError> {
+    let template = &mut template_connection;
+    let copy_result: Result<Transaction<'_, Postgres>, BoxDynError> = async move {
+        let mut transaction = connection.begin().await?;
+
+        sqlx::query("SET LOCAL session_replication_role = replica;")
+            .execute(&mut *transaction)
+            .await?;
+
+        if !table_names.is_empty() {
+            sqlx::query(&format!("TRUNCATE ONLY {};", table_names.join(", ")))
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        for table_name in table_names {
+            let mut copy_in = transaction
+                .copy_in_raw(&format!("COPY {table_name} FROM STDIN (FORMAT binary);"))
+                .await?;
+            let mut copy_out = template
+                .copy_out_raw(&format!("COPY {table_name} TO STDOUT (FORMAT binary);"))
+                .await?;
+
+            while let Some(chunk) = copy_out.next().await {
+                copy_in.send(chunk?).await?;
+            }
+
+            copy_in.finish().await?;
+        }
+
+        Ok(transaction)
+    }
+    .await;
+
+    // Closed when the copy fails too, since the test db is recreated from the template db
+    // right after a failed reset
+    let close_result = template_connection.close().await;
+    let mut transaction = copy_result?;
+    close_result?;
+
+    for sequence in sequences {
+        let (value, is_called) = match sequence.last_value {
+            Some(last_value) => (last_value, true),
+            None => (sequence.start_value, false),
+        };
+
+        sqlx::query("select setval($1::oid::regclass, $2, $3);")
+            .bind(Oid(sequence.oid))
+            .bind(value)
+            .bind(is_called)
+            .execute(&mut *transaction)
+            .await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[derive(FromRow)]
+struct TableRow {
+    oid: Oid,
+    file_node: Oid,
+    tuples_inserted: i64,
+    tuples_updated: i64,
+    tuples_deleted: i64,
+}
+
+#[derive(FromRow)]
+struct SequenceRow {
+    oid: Oid,
+    last_value: Option<i64>,
+    start_value: i64,
+}
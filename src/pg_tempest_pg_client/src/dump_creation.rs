@@ -0,0 +1,126 @@
+use std::{
+    io,
+    pin::Pin,
+    process::Stdio,
+    task::{Context, Poll, ready},
+};
+
+use pg_tempest_core::{
+    configs::dbms_configs::DbmsConfigs,
+    models::value_types::pg_identifier::PgIdentifier,
+    pg_client::{DbDumpFormat, DbDumpReader},
+    utils::errors::BoxDynError,
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, ReadBuf},
+    process::ChildStdout,
+    sync::oneshot,
+};
+
+use crate::libpq_command::libpq_command;
+
+// Dumps are created by pg_dump of the host and streamed from its stdout
+pub fn dump_db(
+    configs: &DbmsConfigs,
+    db_name: &PgIdentifier,
+    format: DbDumpFormat,
+    with_ownership: bool,
+) -> Result<DbDumpReader, BoxDynError> {
+    let format = match format {
+        DbDumpFormat::Sql => "plain",
+        DbDumpFormat::Custom => "custom",
+    };
+
+    let mut command = libpq_command(configs, "pg_dump");
+    if !with_ownership {
+        command.args(["--no-owner", "--no-privileges"]);
+    }
+    command
+        .args(["--format", format])
+        .arg("--dbname")
+        .arg(db_name.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = command
+        .spawn()
+        .map_err(|err| format!("Failed to start \"pg_dump\": {err}"))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or("Stdout of pg_dump is not piped")?;
+    let mut stderr = child
+        .stderr
+        .take()
+        .ok_or("Stderr of pg_dump is not piped")?;
+    let (mut exit_sender, exit_receiver) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let mut stderr_output = String::new();
+
+        let status = tokio::select! {
+            status = async {
+                let _ = stderr.read_to_string(&mut stderr_output).await;
+                child.wait().await
+            } => status,
+            // pg_dump is killed and waited for when the reader is dropped,
+            // so that its connection to the db is closed as soon as possible
+            () = exit_sender.closed() => {
+                let _ = child.kill().await;
+                return;
+            }
+        };
+
+        let result = match status {
+            Ok(status) if status.success() => Ok(()),
+            Ok(status) => match stderr_output.trim() {
+                "" => Err(format!("\"pg_dump\" exited with {status}")),
+                stderr_output => Err(stderr_output.to_string()),
+            },
+            Err(err) => Err(format!("Failed to wait for \"pg_dump\": {err}")),
+        };
+
+        let _ = exit_sender.send(result);
+    });
+
+    Ok(Box::pin(PgDumpReader {
+        stdout,
+        exit_receiver: Some(exit_receiver),
+    }))
+}
+
+struct PgDumpReader {
+    stdout: ChildStdout,
+    exit_receiver: Option<oneshot::Receiver<Result<(), String>>>,
+}
+
+impl AsyncRead for PgDumpReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let filled_len = buf.filled().len();
+        ready!(Pin::new(&mut self.stdout).poll_read(cx, buf))?;
+
+        if buf.filled().len() > filled_len || buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        // Stdout is closed, so the dump is complete only if pg_dump succeeded
+        let Some(exit_receiver) = &mut self.exit_receiver else {
+            return Poll::Ready(Ok(()));
+        };
+
+        let result = ready!(Pin::new(exit_receiver).poll(cx));
+        self.exit_receiver = None;
+
+        match result {
+            Ok(Ok(())) => Poll::Ready(Ok(())),
+            Ok(Err(reason)) => Poll::Ready(Err(io::Error::other(reason))),
+            Err(_) => Poll::Ready(Err(io::Error::other("\"pg_dump\" was interrupted"))),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::{path::Path, process::Stdio};
+
+use pg_tempest_core::{
+    configs::dbms_configs::DbmsConfigs, models::value_types::pg_identifier::PgIdentifier,
+    pg_client::RestoreDbError,
+};
+use sqlx::{Executor, PgConnection};
+
+use crate::libpq_command::libpq_command;
+
+const COPY_CHUNK_SIZE: usize = 1024 * 1024;
+
+// Plain dumps come from clients, so they are executed by the dbms as simple queries rather than
+// by psql, which would run their meta-commands on the host. Only what pg_dump writes besides SQL
+// is supported: rows of COPY FROM stdin are sent as copy data and \restrict lines are skipped
+pub async fn restore_sql_dump(
+    connection: &mut PgConnection,
+    script: &str,
+) -> Result<(), sqlx::Error> {
+    let mut lines = script.split_inclusive('\n');
+    let mut statements = String::new();
+
+    while let Some(line) = lines.next() {
+        let command = line.trim_end();
+
+        if command.starts_with("\\restrict ") || command.starts_with("\\unrestrict ") {
+            continue;
+        }
+        if !(command.starts_with("COPY ") && command.ends_with(" FROM stdin;")) {
+            statements.push_str(line);
+            continue;
+        }
+
+        execute_statements(connection, &mut statements).await?;
+
+        let mut copy_in = connection.copy_in_raw(command).await?;
+        let mut rows = String::new();
+
+        for line in lines.by_ref() {
+            if line.trim_end_matches(['\r', '\n']) == "\\." {
+                break;
+            }
+
+            rows.push_str(line);
+            if rows.len() >= COPY_CHUNK_SIZE {
+                copy_in.send(rows.as_bytes()).await?;
+                rows.clear();
+            }
+        }
+
+        copy_in.send(rows.as_bytes()).await?;
+        copy_in.finish().await?;
+    }
+
+    execute_statements(connection, &mut statements).await
+}
+
+async fn execute_statements(
+    connection: &mut PgConnection,
+    statements: &mut String,
+) -> Result<(), sqlx::Error> {
+    // Queries without arguments are sent by the simple query protocol, which allows many statements
+    if !statements.trim().is_empty() {
+        connection.execute(statements.as_str()).await?;
+    }
+    statements.clear();
+
+    Ok(())
+}
+
+// Errors of pg_restore which are reported before any object is restored from a broken archive,
+// or when a truncated or corrupted data block of the archive is read
+const UNREADABLE_ARCHIVE_ERRORS: &[&str] = &[
+    "input file does not appear to be a valid archive",
+    "input file is too short",
+    "did not find magic string in file header",
+    "unsupported version",
+    "could not read from input file",
+    "could not decompress data",
+    "could not uncompress data",
+    "found unexpected block ID",
+    "could not find block ID",
+    "corrupt tar header",
+];
+
+// Archives are restored by pg_restore of the host, which reads them from the archive dir.
+// Its messages aren't translated, so that unreadable archives can be told apart
+pub async fn restore_archive(
+    configs: &DbmsConfigs,
+    db_name: &PgIdentifier,
+    path: &Path,
+    with_ownership: bool,
+) -> Result<(), RestoreDbError> {
+    let mut command = libpq_command(configs, "pg_restore");
+    if !with_ownership {
+        command.args(["--no-owner", "--no-privileges"]);
+    }
+    command
+        .arg("--exit-on-error")
+        .arg("--dbname")
+        .arg(db_name.to_string())
+        .arg(path)
+        .env("LC_ALL", "C")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    let child = command.spawn().map_err(|err| {
+        RestoreDbError::Unexpected(format!("Failed to start \"pg_restore\": {err}").into())
+    })?;
+
+    // Stderr is read while waiting, so pg_restore isn't blocked by a full pipe
+    let output = child.wait_with_output().await.map_err(|err| {
+        RestoreDbError::Unexpected(format!("Failed to wait for \"pg_restore\": {err}").into())
+    })?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reason = match stderr.trim() {
+        "" => format!("\"pg_restore\" exited with {}", output.status),
+        stderr => stderr.to_string(),
+    };
+
+    if UNREADABLE_ARCHIVE_ERRORS
+        .iter()
+        .any(|error| reason.contains(error))
+    {
+        return Err(RestoreDbError::ArchiveIsUnreadable {
+            reason: reason.into(),
+        });
+    }
+
+    Err(RestoreDbError::DumpWasNotRestored {
+        reason: reason.into(),
+    })
+}
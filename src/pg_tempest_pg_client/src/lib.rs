@@ -1,3 +1,8 @@
+mod db_reset;
+mod dump_creation;
+mod dump_restoration;
+mod libpq_command;
+mod setting_values;
 mod utils;
 
 pub mod pg_client_impl;
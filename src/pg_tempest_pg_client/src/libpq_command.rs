@@ -0,0 +1,38 @@
+use pg_tempest_core::configs::dbms_configs::{DbmsConfigs, SslMode};
+use tokio::process::Command;
+
+// Command of a libpq based tool of the host, which connects
+// to the inner endpoint the same way as the pool
+pub fn libpq_command(configs: &DbmsConfigs, program: &str) -> Command {
+    let mut command = Command::new(program);
+    command
+        .env("PGHOST", configs.inner.host.as_ref())
+        .env("PGPORT", configs.inner.port.to_string())
+        .env("PGUSER", configs.user.as_ref())
+        .env("PGPASSWORD", configs.password.as_ref())
+        .env("PGSSLMODE", libpq_ssl_mode(configs.ssl.mode))
+        .kill_on_drop(true);
+
+    if let Some(root_cert_path) = &configs.ssl.root_cert_path {
+        command.env("PGSSLROOTCERT", root_cert_path);
+    }
+    if let Some(client_cert_path) = &configs.ssl.client_cert_path {
+        command.env("PGSSLCERT", client_cert_path);
+    }
+    if let Some(client_key_path) = &configs.ssl.client_key_path {
+        command.env("PGSSLKEY", client_key_path);
+    }
+
+    command
+}
+
+fn libpq_ssl_mode(ssl_mode: SslMode) -> &'static str {
+    match ssl_mode {
+        SslMode::Disable => "disable",
+        SslMode::Allow => "allow",
+        SslMode::Prefer => "prefer",
+        SslMode::Require => "require",
+        SslMode::VerifyCa => "verify-ca",
+        SslMode::VerifyFull => "verify-full",
+    }
+}
@@ -1,38 +1,135 @@
 use std::sync::Arc;
 
-use crate::utils::{db_already_exists, db_doesnt_exist, wrong_object_type};
+use crate::db_reset::{get_db_snapshot, get_tables_to_reset, write_tables_and_sequences};
+use crate::dump_creation::dump_db;
+use crate::dump_restoration::{restore_archive, restore_sql_dump};
+use crate::setting_values::setting_value_literals;
+use crate::utils::{
+    db_already_exists, db_doesnt_exist, extension_is_not_available, object_in_use,
+    setting_is_invalid, wrong_object_type,
+};
 use async_trait::async_trait;
 use pg_tempest_core::utils::adhoc_display::AdHocDisplay;
-use pg_tempest_core::utils::errors::BoxDynError;
+use pg_tempest_core::utils::errors::{BoxDynError, ErrorExt};
 use pg_tempest_core::{
-    configs::dbms_configs::DbmsConfigs,
-    models::value_types::pg_identifier::PgIdentifier,
-    pg_client::{AlterDbIsTemplateError, CreateDbError, Db, DropDbError, PgClient},
+    configs::dbms_configs::{DbmsConfigs, SslMode},
+    models::{
+        db_settings::DbSettings,
+        value_types::{extension_name::ExtensionName, pg_identifier::PgIdentifier},
+    },
+    pg_client::{
+        AlterDbIsTemplateError, AlterDbSettingsError, CreateDbError, CreateExtensionsError, Db,
+        DbDump, DbDumpFormat, DbDumpReader, DbModificationStats, DbSnapshot, DropDbError, PgClient,
+        ResetDbError, RestoreDbError, SequenceSnapshot,
+    },
 };
 use sqlx::{
-    FromRow, PgPool,
-    postgres::{PgConnectOptions, PgPoolOptions},
+    Connection, FromRow, PgConnection, PgPool,
+    postgres::{PgConnectOptions, PgPoolOptions, PgSslMode, types::Oid},
 };
 
 pub struct PgClientImpl {
     pg_pool: PgPool,
+    configs: Arc<DbmsConfigs>,
 }
 
 impl PgClientImpl {
     pub fn new(configs: Arc<DbmsConfigs>) -> PgClientImpl {
-        let pg_connect_options = PgConnectOptions::new_without_pgpass()
+        let mut pg_connect_options = PgConnectOptions::new_without_pgpass()
             .host(&configs.inner.host)
             .port(configs.inner.port)
             .database(&configs.database)
             .username(&configs.user)
-            .password(&configs.password);
+            .password(&configs.password)
+            .ssl_mode(pg_ssl_mode(configs.ssl.mode));
+
+        if let Some(root_cert_path) = &configs.ssl.root_cert_path {
+            pg_connect_options = pg_connect_options.ssl_root_cert(root_cert_path);
+        }
+        if let Some(client_cert_path) = &configs.ssl.client_cert_path {
+            pg_connect_options = pg_connect_options.ssl_client_cert(client_cert_path);
+        }
+        if let Some(client_key_path) = &configs.ssl.client_key_path {
+            pg_connect_options = pg_connect_options.ssl_client_key(client_key_path);
+        }
 
         let pg_pool = PgPoolOptions::new()
             .max_connections(10)
             .acquire_timeout(std::time::Duration::from_millis(500))
             .connect_lazy_with(pg_connect_options);
 
-        PgClientImpl { pg_pool }
+        PgClientImpl { pg_pool, configs }
+    }
+}
+
+impl PgClientImpl {
+    // Sequence values change on nextval, which isn't counted as a modified tuple. Relation files
+    // change on TRUNCATE, VACUUM FULL and CLUSTER, which aren't counted either
+    async fn get_db_digests(
+        &self,
+        db_name: &PgIdentifier,
+        with_fingerprint: bool,
+    ) -> Result<DbDigestsRow, BoxDynError> {
+        let mut connection = self.connect_to_db(db_name).await?;
+
+        let digests: DbDigestsRow = sqlx::query_as(
+            r#"
+            select
+                (
+                    select md5(coalesce(string_agg(item, ',' order by item), ''))
+                    from (
+                        select format('%s.%s:%s', schemaname, sequencename, last_value) as item
+                        from pg_sequences
+                    ) items
+                ) as sequence_values,
+                case when $1 then (
+                    select md5(coalesce(string_agg(item, ',' order by item), ''))
+                    from (
+                        select format('%s:%s:%s', oid, relfilenode, relkind) as item
+                        from pg_class
+                        union all
+                        select format('%s.%s:%s', schemaname, sequencename, last_value) as item
+                        from pg_sequences
+                    ) items
+                ) end as fingerprint;
+            "#,
+        )
+        .bind(with_fingerprint)
+        .fetch_one(&mut connection)
+        .await?;
+
+        connection.close().await?;
+
+        Ok(digests)
+    }
+
+    // Connection to a db other than the admin one, which isn't pooled. Timeouts set for the db
+    // are meant for its users, so they are overridden for the session
+    async fn connect_to_db(&self, db_name: &PgIdentifier) -> Result<PgConnection, sqlx::Error> {
+        let connect_options = self
+            .pg_pool
+            .connect_options()
+            .as_ref()
+            .clone()
+            .database(db_name.as_ref())
+            .options([
+                ("statement_timeout", "0"),
+                ("lock_timeout", "0"),
+                ("idle_in_transaction_session_timeout", "0"),
+            ]);
+
+        PgConnection::connect_with(&connect_options).await
+    }
+}
+
+fn pg_ssl_mode(ssl_mode: SslMode) -> PgSslMode {
+    match ssl_mode {
+        SslMode::Disable => PgSslMode::Disable,
+        SslMode::Allow => PgSslMode::Allow,
+        SslMode::Prefer => PgSslMode::Prefer,
+        SslMode::Require => PgSslMode::Require,
+        SslMode::VerifyCa => PgSslMode::VerifyCa,
+        SslMode::VerifyFull => PgSslMode::VerifyFull,
     }
 }
 
@@ -60,6 +157,51 @@ impl PgClient for PgClientImpl {
         }
     }
 
+    async fn alter_db_settings(
+        &self,
+        db_name: PgIdentifier,
+        settings: &DbSettings,
+    ) -> Result<(), AlterDbSettingsError> {
+        let mut transaction = self
+            .pg_pool
+            .begin()
+            .await
+            .map_err(|err| AlterDbSettingsError::Unexpected(err.into()))?;
+
+        // Values are written as literals rather than applied in the session, so that settings
+        // like statement_timeout don't affect the statements altering the db
+        for (setting_name, value) in settings {
+            let query_result = sqlx::query(&format!(
+                r#"ALTER DATABASE "{db_name}" SET {} = {}"#,
+                setting_name.quoted_parts().collect::<Vec<_>>().join("."),
+                setting_value_literals(setting_name, value)
+            ))
+            .execute(&mut *transaction)
+            .await;
+
+            match query_result {
+                Ok(_) => {}
+                Err(sqlx::Error::Database(error)) if db_doesnt_exist(&error) => {
+                    return Err(AlterDbSettingsError::DbDoesNotExist {
+                        db_name: db_name.clone(),
+                    });
+                }
+                Err(sqlx::Error::Database(error)) if setting_is_invalid(&error) => {
+                    return Err(AlterDbSettingsError::SettingIsInvalid {
+                        setting_name: setting_name.clone(),
+                        reason: error.message().into(),
+                    });
+                }
+                Err(error) => return Err(AlterDbSettingsError::Unexpected(error.into())),
+            }
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|err| AlterDbSettingsError::Unexpected(err.into()))
+    }
+
     async fn create_db(
         &self,
         db_name: PgIdentifier,
@@ -96,10 +238,56 @@ impl PgClient for PgClientImpl {
                     template_db_name: template_db_name.unwrap(),
                 })
             }
+            Err(sqlx::Error::Database(error))
+                if object_in_use(&error) && template_db_name.is_some() =>
+            {
+                Err(CreateDbError::TemplateDbIsUsed {
+                    template_db_name: template_db_name.unwrap(),
+                })
+            }
             Err(error) => Err(CreateDbError::Unexpected(error.into())),
         }
     }
 
+    async fn create_extensions(
+        &self,
+        db_name: PgIdentifier,
+        extension_names: &[ExtensionName],
+    ) -> Result<(), CreateExtensionsError> {
+        let mut connection = match self.connect_to_db(&db_name).await {
+            Ok(connection) => connection,
+            Err(sqlx::Error::Database(error)) if db_doesnt_exist(&error) => {
+                return Err(CreateExtensionsError::DbDoesNotExist { db_name });
+            }
+            Err(error) => return Err(CreateExtensionsError::Unexpected(error.into())),
+        };
+
+        for extension_name in extension_names {
+            let query_result = sqlx::query(&format!(
+                r#"CREATE EXTENSION IF NOT EXISTS "{extension_name}" CASCADE"#
+            ))
+            .execute(&mut connection)
+            .await;
+
+            match query_result {
+                Ok(_) => {}
+                Err(sqlx::Error::Database(error)) if extension_is_not_available(&error) => {
+                    return Err(CreateExtensionsError::ExtensionIsNotAvailable {
+                        extension_name: extension_name.clone(),
+                        reason: error.message().into(),
+                    });
+                }
+                Err(error) => return Err(CreateExtensionsError::Unexpected(error.into())),
+            }
+        }
+
+        // The connection is closed before returning, since the db can't be copied while it's open
+        connection
+            .close()
+            .await
+            .map_err(|err| CreateExtensionsError::Unexpected(err.into()))
+    }
+
     async fn drop_db(&self, db_name: PgIdentifier) -> Result<(), DropDbError> {
         let query_result = sqlx::query(&format!(r#"DROP DATABASE "{db_name}""#))
             .execute(&self.pg_pool)
@@ -117,6 +305,11 @@ impl PgClient for PgClientImpl {
                     db_name: db_name.clone(),
                 })
             }
+            Err(sqlx::Error::Database(error)) if object_in_use(&error) => {
+                Err(DropDbError::DbIsUsed {
+                    db_name: db_name.clone(),
+                })
+            }
             Err(error) => Err(DropDbError::Unexpected(error.into())),
         }
     }
@@ -143,6 +336,193 @@ impl PgClient for PgClientImpl {
 
         databases
     }
+
+    async fn get_db_modification_stats(
+        &self,
+        db_name: PgIdentifier,
+        with_fingerprint: bool,
+    ) -> Result<DbModificationStats, BoxDynError> {
+        // Counters are always zero when track_counts is disabled, so no row is returned then.
+        // Since Postgres 15 a backend flushes its counters before it stops being counted in
+        // numbackends, so counters of a db without connections are complete. Before that they were
+        // sent to the stats collector asynchronously and could arrive after the check
+        let row: Option<DbStatsRow> = sqlx::query_as(
+            r#"
+            select
+                numbackends as connection_count,
+                tup_inserted as tuples_inserted,
+                tup_updated as tuples_updated,
+                tup_deleted as tuples_deleted,
+                stats_reset::text as stats_reset,
+                current_setting('server_version_num')::int >= 150000 as counters_are_flushed
+            from pg_stat_database
+            where datname = $1 and current_setting('track_counts')::bool;
+            "#,
+        )
+        .bind(db_name.to_string())
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        let row = row.ok_or_else(|| {
+            format!("Stats of {db_name} are unavailable. Db doesn't exist or track_counts is off")
+        })?;
+
+        if !row.counters_are_flushed {
+            return Err(format!(
+                "Stats of {db_name} are unreliable. Counters are flushed asynchronously before Postgres 15"
+            )
+            .into());
+        }
+
+        let digests = self.get_db_digests(&db_name, with_fingerprint).await?;
+
+        Ok(DbModificationStats {
+            connection_count: row.connection_count.try_into()?,
+            tuples_inserted: row.tuples_inserted.try_into()?,
+            tuples_updated: row.tuples_updated.try_into()?,
+            tuples_deleted: row.tuples_deleted.try_into()?,
+            stats_reset: row.stats_reset.map(Into::into),
+            sequence_values: digests.sequence_values.into(),
+            fingerprint: digests.fingerprint.map(Into::into),
+        })
+    }
+
+    async fn get_db_snapshot(&self, db_name: PgIdentifier) -> Result<DbSnapshot, BoxDynError> {
+        let mut connection = self.connect_to_db(&db_name).await?;
+        let snapshot = get_db_snapshot(&mut connection).await?;
+        connection.close().await?;
+
+        Ok(snapshot)
+    }
+
+    async fn reset_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: PgIdentifier,
+        snapshot: &DbSnapshot,
+    ) -> Result<(), ResetDbError> {
+        let connection_count: i32 = sqlx::query_scalar(
+            r#"
+            select numbackends
+            from pg_stat_database
+            where datname = $1;
+            "#,
+        )
+        .bind(db_name.to_string())
+        .fetch_optional(&self.pg_pool)
+        .await
+        .box_err()?
+        .ok_or_else(|| format!("Stats of {db_name} are unavailable"))
+        .box_err()?;
+
+        if connection_count > 0 {
+            return Err(ResetDbError::DbIsUsed { db_name });
+        }
+
+        let mut connection = self.connect_to_db(&db_name).await.box_err()?;
+
+        let reset_result: Result<(), ResetDbError> = async {
+            let current_snapshot = get_db_snapshot(&mut connection).await?;
+
+            if current_snapshot.catalog_tuples_modified != snapshot.catalog_tuples_modified {
+                return Err(ResetDbError::SchemaWasChanged {
+                    db_name: db_name.clone(),
+                });
+            }
+
+            let modified_table_oids = snapshot
+                .tables
+                .iter()
+                .filter(|table| !current_snapshot.tables.contains(table))
+                .map(|table| Oid(table.oid))
+                .collect::<Vec<Oid>>();
+            let modified_sequences = snapshot
+                .sequences
+                .iter()
+                .filter(|sequence| !current_snapshot.sequences.contains(sequence))
+                .collect::<Vec<&SequenceSnapshot>>();
+
+            if modified_table_oids.is_empty() && modified_sequences.is_empty() {
+                return Ok(());
+            }
+
+            let table_names = get_tables_to_reset(&mut connection, modified_table_oids).await?;
+
+            let template_connection = self.connect_to_db(&template_db_name).await.box_err()?;
+
+            write_tables_and_sequences(
+                &mut connection,
+                template_connection,
+                &table_names,
+                &modified_sequences,
+            )
+            .await?;
+
+            Ok(())
+        }
+        .await;
+
+        // Closed when the reset fails too, since the test db is dropped right after a failed reset
+        let close_result = connection.close().await;
+        reset_result?;
+        close_result.box_err()?;
+
+        Ok(())
+    }
+
+    async fn restore_db(&self, db_name: PgIdentifier, dump: &DbDump) -> Result<(), RestoreDbError> {
+        let script = match dump {
+            DbDump::Sql { script } => script,
+            DbDump::Archive {
+                path,
+                with_ownership,
+            } => {
+                return restore_archive(&self.configs, &db_name, path, *with_ownership).await;
+            }
+        };
+
+        let mut connection = self.connect_to_db(&db_name).await.box_err()?;
+
+        match restore_sql_dump(&mut connection, script).await {
+            Ok(()) => {}
+            Err(sqlx::Error::Database(error)) => {
+                return Err(RestoreDbError::DumpWasNotRestored {
+                    reason: error.message().into(),
+                });
+            }
+            Err(error) => return Err(RestoreDbError::Unexpected(error.into())),
+        }
+
+        // The connection is closed before returning, since the db can't be copied while it's open
+        connection.close().await.box_err()?;
+
+        Ok(())
+    }
+
+    async fn dump_db(
+        &self,
+        db_name: PgIdentifier,
+        format: DbDumpFormat,
+        with_ownership: bool,
+    ) -> Result<DbDumpReader, BoxDynError> {
+        dump_db(&self.configs, &db_name, format, with_ownership)
+    }
+}
+
+#[derive(FromRow)]
+struct DbStatsRow {
+    connection_count: i32,
+    tuples_inserted: i64,
+    tuples_updated: i64,
+    tuples_deleted: i64,
+    stats_reset: Option<String>,
+    counters_are_flushed: bool,
+}
+
+#[derive(FromRow)]
+struct DbDigestsRow {
+    sequence_values: String,
+    fingerprint: Option<String>,
 }
 
 #[derive(FromRow)]
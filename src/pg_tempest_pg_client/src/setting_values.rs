@@ -0,0 +1,110 @@
+use pg_tempest_core::models::value_types::db_setting_name::DbSettingName;
+
+// Settings whose elements are quoted as identifiers when they're listed in a single literal,
+// the same list is used by pg_dump for ALTER DATABASE ... SET
+const LIST_QUOTE_SETTING_NAMES: [&str; 6] = [
+    "local_preload_libraries",
+    "search_path",
+    "session_preload_libraries",
+    "shared_preload_libraries",
+    "temp_tablespaces",
+    "unix_socket_directories",
+];
+
+// Value of `ALTER DATABASE ... SET <name> = <value>`, which is written as in postgresql.conf.
+// Elements of list settings are passed as separate literals, otherwise the whole list
+// would become a single element
+pub fn setting_value_literals(setting_name: &DbSettingName, value: &str) -> String {
+    let setting_name = setting_name.to_string();
+    let is_list_quote_setting = LIST_QUOTE_SETTING_NAMES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(&setting_name));
+
+    if !is_list_quote_setting {
+        return quote_literal(value);
+    }
+
+    let elements = split_list(value);
+    if elements.is_empty() {
+        return quote_literal("");
+    }
+
+    elements
+        .iter()
+        .map(|element| quote_literal(element))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Elements are separated by commas and may be double-quoted to contain commas and spaces
+fn split_list(value: &str) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut chars = value.trim().chars().peekable();
+
+    while chars.peek().is_some() {
+        let mut element = String::new();
+
+        if chars.next_if_eq(&'"').is_some() {
+            while let Some(char) = chars.next() {
+                match char {
+                    '"' if chars.next_if_eq(&'"').is_some() => element.push('"'),
+                    '"' => break,
+                    char => element.push(char),
+                }
+            }
+            while chars.next_if(|char| *char != ',').is_some() {}
+        } else {
+            while let Some(char) = chars.next_if(|char| *char != ',') {
+                element.push(char);
+            }
+            element.truncate(element.trim_end().len());
+        }
+
+        elements.push(element);
+
+        chars.next();
+        while chars.next_if(|char| char.is_whitespace()).is_some() {}
+    }
+
+    elements
+}
+
+// Backslashes are escaped explicitly, so that the literal doesn't depend on standard_conforming_strings
+fn quote_literal(value: &str) -> String {
+    let quoted = value.replace('\'', "''");
+
+    if quoted.contains('\\') {
+        format!("E'{}'", quoted.replace('\\', r"\\"))
+    } else {
+        format!("'{quoted}'")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literals(setting_name: &str, value: &str) -> String {
+        setting_value_literals(&DbSettingName::new(setting_name).unwrap(), value)
+    }
+
+    #[test]
+    fn quotes_scalar_values_as_single_literal() {
+        assert_eq!(literals("statement_timeout", "1ms"), "'1ms'");
+        assert_eq!(literals("DateStyle", "ISO, MDY"), "'ISO, MDY'");
+        assert_eq!(literals("app.name", r"it's C:\tmp"), r"E'it''s C:\\tmp'");
+    }
+
+    #[test]
+    fn quotes_list_elements_separately() {
+        assert_eq!(
+            literals("search_path", r#""$user", public,app "#),
+            "'$user', 'public', 'app'"
+        );
+        assert_eq!(
+            literals("Search_Path", r#""a ""b"", c", d"#),
+            r#"'a "b", c', 'd'"#
+        );
+        assert_eq!(literals("search_path", " "), "''");
+    }
+}
@@ -19,3 +19,25 @@ pub fn db_doesnt_exist(db_error: impl AsRef<dyn DatabaseError>) -> bool {
 pub fn wrong_object_type(db_error: impl AsRef<dyn DatabaseError>) -> bool {
     has_code(db_error, "42809")
 }
+
+pub fn object_in_use(db_error: impl AsRef<dyn DatabaseError>) -> bool {
+    has_code(db_error, "55006")
+}
+
+// Unknown parameter, invalid value, or a parameter which can't be set by the user
+pub fn setting_is_invalid(db_error: impl AsRef<dyn DatabaseError>) -> bool {
+    db_error
+        .as_ref()
+        .code()
+        .map(|code| ["42704", "22023", "55P02", "42501"].contains(&code.as_ref()))
+        .unwrap_or(false)
+}
+
+// Postgres before 15 reports a missing control file of the extension as undefined_file
+pub fn extension_is_not_available(db_error: impl AsRef<dyn DatabaseError>) -> bool {
+    db_error
+        .as_ref()
+        .code()
+        .map(|code| ["0A000", "58P01"].contains(&code.as_ref()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,160 @@
+use pg_tempest_core::{
+    models::{
+        db_settings::DbSettings,
+        value_types::{db_setting_name::DbSettingName, pg_identifier::PgIdentifier},
+    },
+    pg_client::{AlterDbSettingsError, PgClient},
+};
+use testcontainers::runners::AsyncRunner;
+
+mod common;
+
+fn db_settings(settings: &[(&str, &str)]) -> DbSettings {
+    settings
+        .iter()
+        .map(|(setting_name, value)| (DbSettingName::new(*setting_name).unwrap(), (*value).into()))
+        .collect()
+}
+
+#[tokio::test]
+async fn settings_are_persisted_as_written() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    // A timeout applied to the session would abort the statements altering the db
+    let result = client
+        .alter_db_settings(
+            db_name.clone(),
+            &db_settings(&[
+                ("statement_timeout", "1ms"),
+                ("lock_timeout", "1ms"),
+                ("search_path", r#""$user", public, app"#),
+                ("app.greeting", r"it's C:\tmp"),
+            ]),
+        )
+        .await;
+
+    assert! {
+        matches!(result, Ok(())),
+        "{result:?}"
+    }
+
+    let mut connection = common::connect(
+        &postgresql_container,
+        &PgIdentifier::new("postgres").unwrap(),
+    )
+    .await;
+    let settings: Vec<String> = sqlx::query_scalar(
+        r#"
+        select unnest(s.setconfig) as setting
+        from pg_db_role_setting s
+        join pg_database d on d.oid = s.setdatabase
+        where d.datname = 'test_database'
+        order by setting;
+        "#,
+    )
+    .fetch_all(&mut connection)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        settings,
+        [
+            r"app.greeting=it's C:\tmp",
+            "lock_timeout=1ms",
+            r#"search_path="$user", public, app"#,
+            "statement_timeout=1ms",
+        ]
+    );
+
+    // Connections of the server override timeouts which are meant for users of the db
+    let result = client.get_db_snapshot(db_name).await;
+
+    assert! {
+        matches!(result, Ok(_)),
+        "{result:?}"
+    }
+}
+
+#[tokio::test]
+async fn invalid_value_is_reported() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let result = client
+        .alter_db_settings(db_name, &db_settings(&[("statement_timeout", "soon")]))
+        .await;
+
+    assert! {
+        matches!(
+            &result,
+            Err(AlterDbSettingsError::SettingIsInvalid { setting_name, .. })
+                if setting_name.to_string() == "statement_timeout"
+        ),
+        "{result:?}"
+    }
+}
+
+#[tokio::test]
+async fn unknown_setting_is_reported() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let result = client
+        .alter_db_settings(db_name, &db_settings(&[("no_such_setting", "1")]))
+        .await;
+
+    assert! {
+        matches!(result, Err(AlterDbSettingsError::SettingIsInvalid { .. })),
+        "{result:?}"
+    }
+}
+
+#[tokio::test]
+async fn settings_of_missing_db_arent_altered() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+
+    let result = client
+        .alter_db_settings(
+            PgIdentifier::new("missing_database").unwrap(),
+            &db_settings(&[("statement_timeout", "1s")]),
+        )
+        .await;
+
+    assert! {
+        matches!(result, Err(AlterDbSettingsError::DbDoesNotExist { .. })),
+        "{result:?}"
+    }
+}
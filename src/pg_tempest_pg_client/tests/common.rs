@@ -1,5 +1,7 @@
 use pg_tempest_core::configs::dbms_configs::{DbmsConfigs, InnerDbmsConfigs, OuterDbmsConfigs};
+use pg_tempest_core::models::value_types::pg_identifier::PgIdentifier;
 use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use sqlx::{Connection, PgConnection};
 use std::sync::Arc;
 use testcontainers::ContainerAsync;
 use testcontainers_modules::postgres::Postgres;
@@ -8,11 +10,13 @@ const TEST_PG_DATABASE: &str = "postgres";
 const TEST_PG_USER: &str = "postgres";
 const TEST_PG_PASSWORD: &str = "postgres";
 
-pub async fn create_pg_client(postgresql_container: &ContainerAsync<Postgres>) -> PgClientImpl {
+pub async fn create_dbms_configs(
+    postgresql_container: &ContainerAsync<Postgres>,
+) -> Arc<DbmsConfigs> {
     let host = postgresql_container.get_host().await.unwrap();
     let port = postgresql_container.get_host_port_ipv4(5432).await.unwrap();
 
-    let configs = Arc::new(DbmsConfigs {
+    Arc::new(DbmsConfigs {
         database: TEST_PG_DATABASE.into(),
         inner: InnerDbmsConfigs {
             host: host.to_string().into(),
@@ -23,8 +27,29 @@ pub async fn create_pg_client(postgresql_container: &ContainerAsync<Postgres>) -
         outer: OuterDbmsConfigs {
             host: None,
             port: None,
+            parameters: Default::default(),
         },
-    });
+        profiles: Default::default(),
+        ssl: Default::default(),
+    })
+}
+
+#[allow(dead_code)]
+pub async fn create_pg_client(postgresql_container: &ContainerAsync<Postgres>) -> PgClientImpl {
+    PgClientImpl::new(create_dbms_configs(postgresql_container).await)
+}
+
+#[allow(dead_code)]
+pub async fn connect(
+    postgresql_container: &ContainerAsync<Postgres>,
+    db_name: &PgIdentifier,
+) -> PgConnection {
+    let host = postgresql_container.get_host().await.unwrap();
+    let port = postgresql_container.get_host_port_ipv4(5432).await.unwrap();
 
-    PgClientImpl::new(configs)
+    PgConnection::connect(&format!(
+        "postgres://{TEST_PG_USER}:{TEST_PG_PASSWORD}@{host}:{port}/{db_name}"
+    ))
+    .await
+    .unwrap()
 }
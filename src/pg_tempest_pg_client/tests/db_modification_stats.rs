@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    models::value_types::pg_identifier::PgIdentifier,
+    pg_client::{DbModificationStats, PgClient},
+};
+use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use sqlx::{Connection, Executor};
+use testcontainers::{ContainerAsync, ImageExt, runners::AsyncRunner};
+use testcontainers_modules::postgres::Postgres;
+
+mod common;
+
+#[tokio::test]
+async fn unmodified_db_has_the_same_stats() {
+    let postgresql_container = start_postgres().await;
+    let client = create_test_db(&postgresql_container).await;
+    let pristine_stats = get_stats(&client, true).await;
+
+    execute(&postgresql_container, "select count(*) from items;").await;
+
+    let stats = get_stats(&client, true).await;
+
+    assert! {
+        stats.is_unmodified_since(&pristine_stats),
+        "{stats:?} {pristine_stats:?}"
+    }
+}
+
+#[tokio::test]
+async fn inserted_rows_are_detected() {
+    let postgresql_container = start_postgres().await;
+    let client = create_test_db(&postgresql_container).await;
+    let pristine_stats = get_stats(&client, false).await;
+
+    execute(&postgresql_container, "insert into items default values;").await;
+
+    let stats = get_stats(&client, false).await;
+
+    assert! {
+        !stats.is_unmodified_since(&pristine_stats),
+        "{stats:?} {pristine_stats:?}"
+    }
+}
+
+#[tokio::test]
+async fn nextval_is_detected() {
+    let postgresql_container = start_postgres().await;
+    let client = create_test_db(&postgresql_container).await;
+    let pristine_stats = get_stats(&client, false).await;
+
+    execute(&postgresql_container, "select nextval('items_id_seq');").await;
+
+    let stats = get_stats(&client, false).await;
+
+    assert! {
+        !stats.is_unmodified_since(&pristine_stats),
+        "{stats:?} {pristine_stats:?}"
+    }
+}
+
+#[tokio::test]
+async fn modification_hidden_by_stats_reset_is_detected() {
+    let postgresql_container = start_postgres().await;
+    let client = create_test_db(&postgresql_container).await;
+    // Counters of the pristine db are zeroed too, so only the reset time tells them apart
+    get_stats(&client, false).await;
+    execute(&postgresql_container, "select pg_stat_reset();").await;
+    let pristine_stats = get_stats(&client, false).await;
+
+    execute(&postgresql_container, "delete from items;").await;
+    get_stats(&client, false).await;
+    execute(&postgresql_container, "select pg_stat_reset();").await;
+
+    let stats = get_stats(&client, false).await;
+
+    assert! {
+        !stats.is_unmodified_since(&pristine_stats),
+        "{stats:?} {pristine_stats:?}"
+    }
+}
+
+#[tokio::test]
+async fn stats_before_postgres_15_are_rejected() {
+    let postgresql_container = Postgres::default()
+        .with_tag("14-alpine")
+        .start()
+        .await
+        .unwrap();
+    let client = create_test_db(&postgresql_container).await;
+
+    let result = client
+        .get_db_modification_stats(PgIdentifier::new("test_database").unwrap(), false)
+        .await;
+
+    assert! {
+        matches!(&result, Err(err) if err.to_string().contains("Postgres 15")),
+        "{result:?}"
+    }
+}
+
+async fn start_postgres() -> ContainerAsync<Postgres> {
+    Postgres::default()
+        .with_tag("16-alpine")
+        .start()
+        .await
+        .unwrap()
+}
+
+async fn create_test_db(postgresql_container: &ContainerAsync<Postgres>) -> PgClientImpl {
+    let client = common::create_pg_client(postgresql_container).await;
+
+    client
+        .create_db(PgIdentifier::new("test_database").unwrap(), None, false)
+        .await
+        .unwrap();
+    execute(
+        postgresql_container,
+        "create table items (id serial primary key); insert into items default values;",
+    )
+    .await;
+
+    client
+}
+
+async fn execute(postgresql_container: &ContainerAsync<Postgres>, sql: &str) {
+    let mut connection = common::connect(
+        postgresql_container,
+        &PgIdentifier::new("test_database").unwrap(),
+    )
+    .await;
+    connection.execute(sql).await.unwrap();
+    connection.close().await.unwrap();
+}
+
+// Backends of closed connections exit asynchronously, so stats are collected until they are gone
+async fn get_stats(client: &PgClientImpl, with_fingerprint: bool) -> DbModificationStats {
+    loop {
+        let stats = client
+            .get_db_modification_stats(
+                PgIdentifier::new("test_database").unwrap(),
+                with_fingerprint,
+            )
+            .await
+            .unwrap();
+
+        if stats.connection_count == 0 {
+            return stats;
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    configs::{
+        db_pool_configs::DbPoolConfigs,
+        template_initialization_configs::TemplateInitializationConfigs,
+        templates_configs::{TemplateCacheConfigs, TemplateImportConfigs, TemplatesConfigs},
+    },
+    features::{
+        templates::start_template_initialization::StartTemplateInitializationResult,
+        test_dbs::finish_test_db_usage::TestDbUsageOutcome,
+    },
+    models::{db_settings::DbSettings, value_types::template_hash::TemplateHash},
+};
+use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use testcontainers::runners::AsyncRunner;
+
+mod common;
+
+#[tokio::test]
+async fn embedded_core_provides_test_db() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let dbms_configs = common::create_dbms_configs(&postgresql_container).await;
+    let pg_client = Arc::new(PgClientImpl::new(dbms_configs.clone()));
+
+    let tempest_core = PgTempestCore::start(
+        pg_client,
+        dbms_configs,
+        Arc::new(DbPoolConfigs {
+            min_size: 1,
+            creation_retries_delay_in_ms: 100,
+            reuse: Default::default(),
+            recycling: Default::default(),
+        }),
+        Arc::new(TemplatesConfigs {
+            initialization: Arc::new(TemplateInitializationConfigs {
+                long_polling_timeout_ms: 5000,
+                max_deadline_handling_delay_ms: 50,
+            }),
+            parent_template_db_name: None,
+            import: TemplateImportConfigs::default(),
+            cache: TemplateCacheConfigs::default(),
+        }),
+    )
+    .await
+    .unwrap();
+
+    let template_hash = TemplateHash::new([1; 16]);
+
+    // Template initialization
+    let result = tempest_core
+        .clone()
+        .start_template_initialization(
+            template_hash,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await;
+
+    assert! {
+        matches!(result, Ok(StartTemplateInitializationResult::InitializationWasStarted {..})),
+        "Template initialization was not started"
+    }
+
+    let result = tempest_core
+        .clone()
+        .finish_template_initialization(template_hash)
+        .await;
+
+    assert! {
+        result.is_ok(),
+        "Template initialization was not finished"
+    }
+
+    // Test db usage
+    let result = tempest_core
+        .clone()
+        .get_test_db(template_hash, Duration::from_secs(10), None)
+        .await;
+
+    let Ok(test_db) = result else {
+        panic!("Test db was not provided");
+    };
+
+    let result = tempest_core
+        .clone()
+        .finish_test_db_usage(
+            template_hash,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await;
+
+    assert! {
+        result.is_ok(),
+        "Test db usage was not finished"
+    }
+}
@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    models::value_types::pg_identifier::PgIdentifier,
+    pg_client::{DbSnapshot, PgClient, ResetDbError},
+};
+use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use sqlx::{Connection, Executor};
+use testcontainers::{ContainerAsync, ImageExt, runners::AsyncRunner};
+use testcontainers_modules::postgres::Postgres;
+
+mod common;
+
+const TEMPLATE_SQL: &str = r#"
+    create table items (id serial primary key, name text not null);
+    create table item_tags (item_id int not null references items (id), tag text not null);
+    create sequence invoice_numbers;
+    create function stamp_tag() returns trigger language plpgsql as $$
+    begin
+        new.tag := new.tag || ' (stamped)';
+        return new;
+    end $$;
+    create trigger stamp before insert on item_tags for each row execute function stamp_tag();
+    insert into items (name) values ('first'), ('second');
+    insert into item_tags values (1, 'tag');
+    select nextval('invoice_numbers');
+"#;
+
+#[tokio::test]
+async fn rows_and_sequences_are_reset_without_triggers() {
+    let postgresql_container = start_postgres().await;
+    let (client, snapshot) = create_test_db(&postgresql_container).await;
+
+    execute(
+        &postgresql_container,
+        "test_database",
+        r#"
+        update items set name = 'changed' where id = 1;
+        insert into items (name) values ('third');
+        delete from item_tags;
+        insert into item_tags values (3, 'other');
+        select nextval('invoice_numbers');
+        "#,
+    )
+    .await;
+
+    let result = reset_test_db(&client, &snapshot).await;
+
+    assert! {
+        matches!(result, Ok(())),
+        "{result:?}"
+    }
+
+    let mut connection = common::connect(
+        &postgresql_container,
+        &PgIdentifier::new("test_database").unwrap(),
+    )
+    .await;
+    let rows: (String, String, i64, i64) = sqlx::query_as(
+        r#"
+        select
+            (select string_agg(format('%s:%s', id, name), ',' order by id) from items),
+            (select string_agg(format('%s:%s', item_id, tag), ',') from item_tags),
+            (select last_value from items_id_seq),
+            (select last_value from invoice_numbers);
+        "#,
+    )
+    .fetch_one(&mut connection)
+    .await
+    .unwrap();
+
+    // The trigger would stamp the tag once more if it wasn't disabled during the reset
+    assert_eq!(
+        rows,
+        (
+            "1:first,2:second".to_string(),
+            "1:tag (stamped)".to_string(),
+            2,
+            1
+        )
+    );
+}
+
+#[tokio::test]
+async fn tables_referencing_modified_ones_are_reset() {
+    let postgresql_container = start_postgres().await;
+    let (client, snapshot) = create_test_db(&postgresql_container).await;
+
+    execute(
+        &postgresql_container,
+        "test_database",
+        "insert into items (name) values ('third');",
+    )
+    .await;
+
+    let result = reset_test_db(&client, &snapshot).await;
+
+    assert! {
+        matches!(result, Ok(())),
+        "{result:?}"
+    }
+
+    let mut connection = common::connect(
+        &postgresql_container,
+        &PgIdentifier::new("test_database").unwrap(),
+    )
+    .await;
+    let tags: Vec<String> = sqlx::query_scalar("select tag from item_tags;")
+        .fetch_all(&mut connection)
+        .await
+        .unwrap();
+
+    assert_eq!(tags, ["tag (stamped)"]);
+}
+
+// The test db is recreated from the template db right after a failed reset
+#[tokio::test]
+async fn template_db_is_released_after_failed_reset() {
+    let postgresql_container = start_postgres().await;
+    let (client, snapshot) = create_test_db(&postgresql_container).await;
+
+    execute(
+        &postgresql_container,
+        "test_database",
+        "insert into items (name) values ('third');",
+    )
+    .await;
+    // Rows of the template db no longer fit the test db, so their copy fails
+    execute(
+        &postgresql_container,
+        "test_template",
+        "alter table items add column price int;",
+    )
+    .await;
+
+    let result = reset_test_db(&client, &snapshot).await;
+
+    assert! {
+        matches!(result, Err(ResetDbError::Unexpected(_))),
+        "{result:?}"
+    }
+
+    let result = client
+        .create_db(
+            PgIdentifier::new("other_test_database").unwrap(),
+            Some(PgIdentifier::new("test_template").unwrap()),
+            false,
+        )
+        .await;
+
+    assert! {
+        matches!(result, Ok(())),
+        "{result:?}"
+    }
+}
+
+// A reset truncates tables, which changes catalogs, so a snapshot taken after the reset
+// must still match the next reset
+#[tokio::test]
+async fn test_db_is_reset_repeatedly_with_new_snapshots() {
+    let postgresql_container = start_postgres().await;
+    let (client, mut snapshot) = create_test_db(&postgresql_container).await;
+
+    for _ in 0..3 {
+        execute(
+            &postgresql_container,
+            "test_database",
+            "insert into items (name) values ('third');",
+        )
+        .await;
+
+        let result = reset_test_db(&client, &snapshot).await;
+
+        assert! {
+            matches!(result, Ok(())),
+            "{result:?}"
+        }
+
+        snapshot = client
+            .get_db_snapshot(PgIdentifier::new("test_database").unwrap())
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn schema_change_isnt_reset() {
+    assert_catalog_change_isnt_reset("alter table items add column price int;").await;
+}
+
+#[tokio::test]
+async fn row_level_security_policy_isnt_reset() {
+    assert_catalog_change_isnt_reset(
+        r#"
+        alter table items enable row level security;
+        create policy first_only on items using (id = 1);
+        "#,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn large_object_isnt_reset() {
+    assert_catalog_change_isnt_reset("select lo_from_bytea(0, 'leftover');").await;
+}
+
+#[tokio::test]
+async fn grant_and_comment_arent_reset() {
+    assert_catalog_change_isnt_reset(
+        r#"
+        grant select on items to public;
+        comment on table items is 'leftover';
+        "#,
+    )
+    .await;
+}
+
+async fn assert_catalog_change_isnt_reset(sql: &str) {
+    let postgresql_container = start_postgres().await;
+    let (client, snapshot) = create_test_db(&postgresql_container).await;
+
+    execute(&postgresql_container, "test_database", sql).await;
+
+    let result = reset_test_db(&client, &snapshot).await;
+
+    assert! {
+        matches!(result, Err(ResetDbError::SchemaWasChanged { .. })),
+        "{result:?}"
+    }
+}
+
+// Counters are flushed synchronously only since Postgres 15
+async fn start_postgres() -> ContainerAsync<Postgres> {
+    Postgres::default()
+        .with_tag("16-alpine")
+        .start()
+        .await
+        .unwrap()
+}
+
+async fn create_test_db(
+    postgresql_container: &ContainerAsync<Postgres>,
+) -> (PgClientImpl, DbSnapshot) {
+    let client = common::create_pg_client(postgresql_container).await;
+    let template_db_name = PgIdentifier::new("test_template").unwrap();
+    let test_db_name = PgIdentifier::new("test_database").unwrap();
+
+    client
+        .create_db(template_db_name.clone(), None, false)
+        .await
+        .unwrap();
+    execute(postgresql_container, "test_template", TEMPLATE_SQL).await;
+    client
+        .alter_db_is_template(template_db_name.clone(), true)
+        .await
+        .unwrap();
+    client
+        .create_db(test_db_name.clone(), Some(template_db_name), false)
+        .await
+        .unwrap();
+
+    let snapshot = client.get_db_snapshot(test_db_name).await.unwrap();
+
+    (client, snapshot)
+}
+
+async fn execute(postgresql_container: &ContainerAsync<Postgres>, db_name: &str, sql: &str) {
+    let mut connection =
+        common::connect(postgresql_container, &PgIdentifier::new(db_name).unwrap()).await;
+    connection.execute(sql).await.unwrap();
+    connection.close().await.unwrap();
+}
+
+// The backend of a closed connection exits asynchronously, so the reset is retried until it's gone
+async fn reset_test_db(client: &PgClientImpl, snapshot: &DbSnapshot) -> Result<(), ResetDbError> {
+    loop {
+        let result = client
+            .reset_db(
+                PgIdentifier::new("test_database").unwrap(),
+                PgIdentifier::new("test_template").unwrap(),
+                snapshot,
+            )
+            .await;
+
+        match result {
+            Err(ResetDbError::DbIsUsed { .. }) => {
+                tokio::time::sleep(Duration::from_millis(50)).await
+            }
+            result => return result,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::path::PathBuf;
+
+use pg_tempest_core::{
+    models::value_types::pg_identifier::PgIdentifier,
+    pg_client::{DbDump, DbDumpFormat, PgClient, RestoreDbError},
+};
+use pg_tempest_pg_client::pg_client_impl::PgClientImpl;
+use sqlx::{Connection, Executor};
+use testcontainers::{ContainerAsync, runners::AsyncRunner};
+use testcontainers_modules::postgres::Postgres;
+
+mod common;
+
+// Shaped like the plain output of pg_dump 17.6+, which restricts meta-commands around the script
+const SQL_DUMP: &str = r"\restrict ABCDEF
+SET client_encoding = 'UTF8';
+SELECT pg_catalog.set_config('search_path', '', false);
+
+CREATE TABLE public.users (
+    id bigint NOT NULL,
+    name text
+);
+
+COPY public.users (id, name) FROM stdin;
+1	alice
+2	\N
+3	tab\there
+\.
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+
+\unrestrict ABCDEF
+";
+
+#[tokio::test]
+async fn sql_dump_is_restored_with_copied_rows() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let result = client
+        .restore_db(
+            db_name.clone(),
+            &DbDump::Sql {
+                script: SQL_DUMP.into(),
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(result, Ok(_)),
+        "{result:?}"
+    }
+
+    let mut connection = common::connect(&postgresql_container, &db_name).await;
+    let users: Vec<(i64, Option<String>)> =
+        sqlx::query_as("SELECT id, name FROM public.users ORDER BY id")
+            .fetch_all(&mut connection)
+            .await
+            .unwrap();
+
+    assert_eq!(
+        users,
+        [
+            (1, Some("alice".to_string())),
+            (2, None),
+            (3, Some("tab\there".to_string()))
+        ]
+    );
+}
+
+#[tokio::test]
+async fn psql_meta_commands_of_sql_dump_are_not_run() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let marker_path = std::env::temp_dir().join("pg_tempest_restore_db_meta_command");
+    let _ = std::fs::remove_file(&marker_path);
+
+    let result = client
+        .restore_db(
+            db_name,
+            &DbDump::Sql {
+                script: format!("\\! touch {}\n", marker_path.display()).into(),
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(result, Err(RestoreDbError::DumpWasNotRestored { .. })),
+        "{result:?}"
+    }
+    assert!(!marker_path.exists());
+}
+
+#[tokio::test]
+async fn failed_statement_of_sql_dump_is_reported() {
+    let postgresql_container = testcontainers_modules::postgres::Postgres::default()
+        .start()
+        .await
+        .unwrap();
+
+    let client = common::create_pg_client(&postgresql_container).await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let result = client
+        .restore_db(
+            db_name,
+            &DbDump::Sql {
+                script: "CREATE TABLE users (id bigint);\nINSERT INTO missing VALUES (1);\n".into(),
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(
+            &result,
+            Err(RestoreDbError::DumpWasNotRestored { reason }) if reason.contains("missing")
+        ),
+        "{result:?}"
+    }
+}
+
+#[tokio::test]
+async fn archive_is_restored_with_owners_and_privileges() {
+    let postgresql_container = Postgres::default().start().await.unwrap();
+    let client = common::create_pg_client(&postgresql_container).await;
+    let archive_path = dump_items_archive(&postgresql_container, &client, "owned_archive").await;
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(
+            db_name.clone(),
+            Some(PgIdentifier::new("template0").unwrap()),
+            false,
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .restore_db(
+            db_name.clone(),
+            &DbDump::Archive {
+                path: archive_path,
+                with_ownership: true,
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(result, Ok(())),
+        "{result:?}"
+    }
+
+    let mut connection = common::connect(&postgresql_container, &db_name).await;
+    let ownership: (String, bool) = sqlx::query_as(
+        r#"
+        select tableowner::text, has_table_privilege('items_reader', 'public.items', 'select')
+        from pg_tables
+        where tablename = 'items';
+        "#,
+    )
+    .fetch_one(&mut connection)
+    .await
+    .unwrap();
+
+    assert_eq!(ownership, ("items_owner".to_string(), true));
+}
+
+#[tokio::test]
+async fn failed_object_of_archive_is_reported() {
+    let postgresql_container = Postgres::default().start().await.unwrap();
+    let client = common::create_pg_client(&postgresql_container).await;
+    let archive_path =
+        dump_items_archive(&postgresql_container, &client, "conflicting_archive").await;
+    let db_name = PgIdentifier::new("source_database").unwrap();
+
+    let result = client
+        .restore_db(
+            db_name,
+            &DbDump::Archive {
+                path: archive_path,
+                with_ownership: false,
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(
+            &result,
+            Err(RestoreDbError::DumpWasNotRestored { reason }) if reason.contains("already exists")
+        ),
+        "{result:?}"
+    }
+}
+
+#[tokio::test]
+async fn unreadable_archive_is_reported() {
+    let postgresql_container = Postgres::default().start().await.unwrap();
+    let client = common::create_pg_client(&postgresql_container).await;
+    let archive_path =
+        dump_items_archive(&postgresql_container, &client, "truncated_archive").await;
+    let archive = std::fs::read(&archive_path).unwrap();
+    std::fs::write(&archive_path, &archive[..archive.len() / 2]).unwrap();
+    let db_name = PgIdentifier::new("test_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let result = client
+        .restore_db(
+            db_name,
+            &DbDump::Archive {
+                path: archive_path,
+                with_ownership: false,
+            },
+        )
+        .await;
+
+    assert! {
+        matches!(result, Err(RestoreDbError::ArchiveIsUnreadable { .. })),
+        "{result:?}"
+    }
+}
+
+// Dumps source_database with a table owned by items_owner and readable by items_reader
+async fn dump_items_archive(
+    postgresql_container: &ContainerAsync<Postgres>,
+    client: &PgClientImpl,
+    name: &str,
+) -> PathBuf {
+    let db_name = PgIdentifier::new("source_database").unwrap();
+    client
+        .create_db(db_name.clone(), None, false)
+        .await
+        .unwrap();
+
+    let mut connection = common::connect(postgresql_container, &db_name).await;
+    connection
+        .execute(
+            r#"
+            create role items_owner;
+            create role items_reader;
+            create table items (id int primary key);
+            insert into items select generate_series(1, 10000);
+            alter table items owner to items_owner;
+            grant select on items to items_reader;
+            "#,
+        )
+        .await
+        .unwrap();
+    connection.close().await.unwrap();
+
+    let archive_path =
+        std::env::temp_dir().join(format!("pg_tempest_{name}_{}.dump", std::process::id()));
+    let mut dump = client
+        .dump_db(db_name, DbDumpFormat::Custom, true)
+        .await
+        .unwrap();
+    let mut file = tokio::fs::File::create(&archive_path).await.unwrap();
+    tokio::io::copy(&mut dump, &mut file).await.unwrap();
+
+    archive_path
+}
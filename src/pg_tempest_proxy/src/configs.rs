@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use std::net::Ipv4Addr;
+
+// Usages are extended every half of the duration, which must leave time for the extension
+const MIN_USAGE_DURATION_MS: u64 = 1_000;
+
+#[derive(Deserialize)]
+pub struct ProxyConfigs {
+    pub enabled: bool,
+    pub ipv4: Ipv4Addr,
+    pub port: u16,
+    pub usage_duration_ms: u64,
+}
+
+impl ProxyConfigs {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.usage_duration_ms < MIN_USAGE_DURATION_MS {
+            return Err(format!(
+                "usage_duration_ms must be at least {MIN_USAGE_DURATION_MS}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::configs::ProxyConfigs;
+
+    fn create_configs(usage_duration_ms: u64) -> ProxyConfigs {
+        ProxyConfigs {
+            enabled: true,
+            ipv4: Ipv4Addr::LOCALHOST,
+            port: 6432,
+            usage_duration_ms,
+        }
+    }
+
+    #[test]
+    fn too_short_usage_duration_is_rejected() {
+        assert!(create_configs(0).validate().is_err());
+        assert!(create_configs(999).validate().is_err());
+    }
+
+    #[test]
+    fn usage_duration_is_accepted() {
+        assert!(create_configs(1_000).validate().is_ok());
+        assert!(create_configs(60_000).validate().is_ok());
+    }
+}
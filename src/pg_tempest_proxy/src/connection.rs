@@ -0,0 +1,172 @@
+use std::{sync::Arc, time::Duration};
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::{
+        finish_test_db_usage::TestDbUsageOutcome, get_test_db::GetTestDbErrorResult,
+    },
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+    utils::errors::BoxDynError,
+};
+use tokio::{io::AsyncWriteExt, net::TcpStream, time::sleep};
+use tracing::{debug, info, warn};
+
+use crate::{
+    dbms_connector::DbmsConnector,
+    messages::{StartupPacket, encode_cancel_request, encode_fatal_error, read_startup_packet},
+};
+
+const TEMPEST_DATABASE_PREFIX: &str = "tempest:";
+
+// SQLSTATE codes reported to clients
+const INVALID_CATALOG_NAME: &str = "3D000";
+const CANNOT_CONNECT_NOW: &str = "57P03";
+const CONNECTION_FAILURE: &str = "08006";
+
+pub async fn handle_connection(
+    tempest_core: Arc<PgTempestCore>,
+    dbms_connector: Arc<DbmsConnector>,
+    usage_duration: Duration,
+    mut client: TcpStream,
+) -> Result<(), BoxDynError> {
+    let mut startup_message = loop {
+        match read_startup_packet(&mut client).await? {
+            // Encryption isn't supported, so clients continue with a plain connection
+            StartupPacket::SslRequest | StartupPacket::GssEncryptionRequest => {
+                client.write_all(b"N").await?;
+            }
+            // Clients got the backend key from the dbms, so the request is valid for it as is
+            StartupPacket::CancelRequest { payload } => {
+                let mut server = dbms_connector.connect().await?;
+                server.write_all(&encode_cancel_request(&payload)).await?;
+                server.shutdown().await?;
+                debug!("Cancel request was forwarded to dbms");
+                return Ok(());
+            }
+            StartupPacket::StartupMessage(startup_message) => break startup_message,
+        }
+    };
+
+    let database = startup_message.parameter("database").unwrap_or_default();
+
+    let Some(template_hash) = database
+        .strip_prefix(TEMPEST_DATABASE_PREFIX)
+        .and_then(|template_hash| template_hash.parse::<TemplateHash>().ok())
+    else {
+        let message = format!(
+            "Database {database:?} must be in format {TEMPEST_DATABASE_PREFIX}<template hash>"
+        );
+        client
+            .write_all(&encode_fatal_error(INVALID_CATALOG_NAME, &message))
+            .await?;
+        return Ok(());
+    };
+
+    // The proxy itself connects to the inner address, so no network profile is needed
+    let test_db = match tempest_core
+        .clone()
+        .get_test_db(template_hash, usage_duration, None)
+        .await
+    {
+        Ok(test_db) => test_db,
+        Err(err) => {
+            let (sql_state, message) = match err {
+                GetTestDbErrorResult::TemplateWasNotFound => (
+                    INVALID_CATALOG_NAME,
+                    format!("Template {template_hash} was not found"),
+                ),
+                GetTestDbErrorResult::TemplateIsNotInitialized => (
+                    CANNOT_CONNECT_NOW,
+                    format!("Template {template_hash} is not initialized"),
+                ),
+                GetTestDbErrorResult::NetworkProfileWasNotFound => (
+                    CONNECTION_FAILURE,
+                    "Network profile was not found".to_string(),
+                ),
+                GetTestDbErrorResult::Unknown { inner } => (CONNECTION_FAILURE, inner.to_string()),
+            };
+            client
+                .write_all(&encode_fatal_error(sql_state, &message))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let test_db_id = test_db.test_db_id;
+
+    info!("Test db {template_hash} {test_db_id} was leased by proxy connection");
+
+    startup_message.set_parameter("database", &test_db.connection_options.database.to_string());
+
+    let startup_message = startup_message.encode();
+
+    // The connection is closed once the usage can't be extended anymore,
+    // since the test db may be leased by others after that
+    let proxying_result = tokio::select! {
+        result = proxy_to_test_db(&dbms_connector, &mut client, &startup_message) => result,
+        err = extend_usage_periodically(&tempest_core, template_hash, test_db_id, usage_duration) => Err(err),
+    };
+
+    if let Err(err) = tempest_core
+        .finish_test_db_usage(
+            template_hash,
+            test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await
+    {
+        warn!("Failed to finish test db {template_hash} {test_db_id} usage: {err:?}");
+    } else {
+        info!("Test db {template_hash} {test_db_id} was released by proxy connection");
+    }
+
+    proxying_result
+}
+
+async fn proxy_to_test_db(
+    dbms_connector: &DbmsConnector,
+    client: &mut TcpStream,
+    startup_message: &[u8],
+) -> Result<(), BoxDynError> {
+    let mut server = match dbms_connector.connect().await {
+        Ok(server) => server,
+        Err(err) => {
+            let message = format!("Failed to connect to dbms: {err}");
+            client
+                .write_all(&encode_fatal_error(CONNECTION_FAILURE, &message))
+                .await?;
+            return Err(err);
+        }
+    };
+
+    server.write_all(startup_message).await?;
+
+    tokio::io::copy_bidirectional(client, &mut server).await?;
+
+    Ok(())
+}
+
+// Extending by the half of the duration every half of it keeps the deadline within
+// the usage duration from now, so an abandoned test db isn't held longer than that
+async fn extend_usage_periodically(
+    tempest_core: &PgTempestCore,
+    template_hash: TemplateHash,
+    test_db_id: TestDbId,
+    usage_duration: Duration,
+) -> BoxDynError {
+    loop {
+        sleep(usage_duration / 2).await;
+
+        let result = tempest_core
+            .extend_test_db_usage(template_hash, test_db_id, usage_duration / 2)
+            .await;
+
+        if let Err(err) = result {
+            return format!(
+                "Test db {template_hash} {test_db_id} usage can't be extended: {err:?}"
+            )
+            .into();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    configs::dbms_configs::{DbmsConfigs, DbmsSslConfigs, SslMode},
+    utils::errors::BoxDynError,
+};
+use rustls::{
+    CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+    client::{
+        WebPkiServerVerifier,
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    },
+    crypto::{CryptoProvider, ring, verify_tls12_signature, verify_tls13_signature},
+    pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime, pem::PemObject},
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_rustls::TlsConnector;
+
+use crate::messages::encode_ssl_request;
+
+pub trait DbmsStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> DbmsStream for T {}
+
+// Connects to the inner endpoint of the dbms with SSL of `dbms.ssl`, following libpq semantics
+// of sslmode: allow connects without SSL like the first attempt of libpq, and require verifies
+// the certificate chain only when a root certificate is configured
+pub struct DbmsConnector {
+    host: Box<str>,
+    port: u16,
+    ssl_mode: SslMode,
+    tls_connector: TlsConnector,
+    server_name: ServerName<'static>,
+}
+
+impl DbmsConnector {
+    pub fn new(dbms_configs: &DbmsConfigs) -> Result<DbmsConnector, BoxDynError> {
+        let client_config = build_client_config(&dbms_configs.ssl)?;
+        let server_name = ServerName::try_from(dbms_configs.inner.host.to_string())
+            .map_err(|err| format!("Dbms host can't be verified by SSL: {err}"))?;
+
+        Ok(DbmsConnector {
+            host: dbms_configs.inner.host.clone(),
+            port: dbms_configs.inner.port,
+            ssl_mode: dbms_configs.ssl.mode,
+            tls_connector: TlsConnector::from(Arc::new(client_config)),
+            server_name,
+        })
+    }
+
+    pub async fn connect(&self) -> Result<Box<dyn DbmsStream>, BoxDynError> {
+        let mut stream = TcpStream::connect((self.host.as_ref(), self.port)).await?;
+
+        if matches!(self.ssl_mode, SslMode::Disable | SslMode::Allow) {
+            return Ok(Box::new(stream));
+        }
+
+        stream.write_all(&encode_ssl_request()).await?;
+
+        match stream.read_u8().await? {
+            b'S' => {
+                let tls_stream = self
+                    .tls_connector
+                    .connect(self.server_name.clone(), stream)
+                    .await?;
+
+                Ok(Box::new(tls_stream))
+            }
+            b'N' if matches!(self.ssl_mode, SslMode::Prefer) => Ok(Box::new(stream)),
+            b'N' => Err("Dbms doesn't support SSL, which is required by dbms.ssl.mode".into()),
+            response => Err(format!("Unexpected response {response} to SSL request").into()),
+        }
+    }
+}
+
+fn build_client_config(configs: &DbmsSslConfigs) -> Result<ClientConfig, BoxDynError> {
+    let provider = Arc::new(ring::default_provider());
+
+    let root_store = match &configs.root_cert_path {
+        Some(root_cert_path) => {
+            let mut root_store = RootCertStore::empty();
+            for cert in CertificateDer::pem_file_iter(root_cert_path)
+                .map_err(|err| format!("Failed to read {}: {err}", root_cert_path.display()))?
+            {
+                root_store.add(cert?)?;
+            }
+            Some(Arc::new(root_store))
+        }
+        None if matches!(configs.mode, SslMode::VerifyCa | SslMode::VerifyFull) => {
+            return Err(
+                "dbms.ssl.root_cert_path must be configured for verify-ca and verify-full".into(),
+            );
+        }
+        None => None,
+    };
+
+    let inner_verifier = match root_store {
+        Some(root_store) => Some(
+            WebPkiServerVerifier::builder_with_provider(root_store, provider.clone()).build()?,
+        ),
+        None => None,
+    };
+    let verifier = DbmsCertVerifier {
+        inner_verifier,
+        verifies_host_name: matches!(configs.mode, SslMode::VerifyFull),
+        provider: provider.clone(),
+    };
+
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier));
+
+    let client_config = match (&configs.client_cert_path, &configs.client_key_path) {
+        (Some(client_cert_path), Some(client_key_path)) => {
+            let cert_chain = CertificateDer::pem_file_iter(client_cert_path)
+                .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+                .map_err(|err| format!("Failed to read {}: {err}", client_cert_path.display()))?;
+            let key = PrivateKeyDer::from_pem_file(client_key_path)
+                .map_err(|err| format!("Failed to read {}: {err}", client_key_path.display()))?;
+
+            builder.with_client_auth_cert(cert_chain, key)?
+        }
+        _ => builder.with_no_client_auth(),
+    };
+
+    Ok(client_config)
+}
+
+// Without a root certificate any certificate is accepted, like libpq does for prefer and require
+#[derive(Debug)]
+struct DbmsCertVerifier {
+    inner_verifier: Option<Arc<WebPkiServerVerifier>>,
+    verifies_host_name: bool,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for DbmsCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let Some(inner_verifier) = &self.inner_verifier else {
+            return Ok(ServerCertVerified::assertion());
+        };
+
+        match inner_verifier.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) if !self.verifies_host_name => Ok(ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
@@ -0,0 +1,77 @@
+use std::{net::SocketAddrV4, sync::Arc, time::Duration};
+
+use pg_tempest_core::PgTempestCore;
+use pg_tempest_core::configs::dbms_configs::DbmsConfigs;
+use pg_tempest_core::utils::errors::BoxDynError;
+use tokio::{net::TcpListener, time::sleep};
+use tracing::warn;
+
+use crate::{configs::ProxyConfigs, connection::handle_connection, dbms_connector::DbmsConnector};
+
+pub mod configs;
+mod connection;
+mod dbms_connector;
+mod messages;
+
+const ACCEPT_RETRIES_DELAY: Duration = Duration::from_millis(100);
+
+// Listens for Postgres connections to `tempest:<template hash>` databases,
+// leases a test db for each connection and forwards the traffic to it
+pub struct Proxy {
+    tempest_core: Arc<PgTempestCore>,
+    dbms_configs: Arc<DbmsConfigs>,
+    configs: Arc<ProxyConfigs>,
+}
+
+impl Proxy {
+    pub fn new(
+        tempest_core: Arc<PgTempestCore>,
+        dbms_configs: Arc<DbmsConfigs>,
+        configs: Arc<ProxyConfigs>,
+    ) -> Proxy {
+        Proxy {
+            tempest_core,
+            dbms_configs,
+            configs,
+        }
+    }
+
+    pub async fn start(self) -> Result<(), BoxDynError> {
+        self.configs.validate()?;
+
+        let socket_addr = SocketAddrV4::new(self.configs.ipv4, self.configs.port);
+        let usage_duration = Duration::from_millis(self.configs.usage_duration_ms);
+        let dbms_connector = Arc::new(DbmsConnector::new(&self.dbms_configs)?);
+
+        tracing::info!("Starting proxy on {socket_addr}");
+
+        let tcp_listener = TcpListener::bind(socket_addr).await?;
+
+        loop {
+            // Accept errors like EMFILE are transient, so they don't stop the proxy
+            let (client, client_addr) = match tcp_listener.accept().await {
+                Ok(connection) => connection,
+                Err(err) => {
+                    warn!("Failed to accept proxy connection: {err}");
+                    sleep(ACCEPT_RETRIES_DELAY).await;
+                    continue;
+                }
+            };
+
+            tokio::spawn({
+                let tempest_core = self.tempest_core.clone();
+                let dbms_connector = dbms_connector.clone();
+
+                async move {
+                    let result =
+                        handle_connection(tempest_core, dbms_connector, usage_duration, client)
+                            .await;
+
+                    if let Err(err) = result {
+                        warn!("Proxy connection from {client_addr} failed: {err}");
+                    }
+                }
+            });
+        }
+    }
+}
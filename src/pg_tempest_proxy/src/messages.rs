@@ -0,0 +1,218 @@
+use pg_tempest_core::utils::errors::BoxDynError;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSS_ENCRYPTION_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+const PROTOCOL_MAJOR_VERSION: i32 = 3;
+const MAX_STARTUP_MESSAGE_LENGTH: usize = 10_000;
+
+// The first message of a connection, which has no type byte unlike other frontend messages
+pub enum StartupPacket {
+    SslRequest,
+    GssEncryptionRequest,
+    // Process id and secret key of the backend, which the dbms reported to the client
+    CancelRequest { payload: Vec<u8> },
+    StartupMessage(StartupMessage),
+}
+
+pub struct StartupMessage {
+    pub protocol_version: i32,
+    pub parameters: Vec<(Box<str>, Box<str>)>,
+}
+
+impl StartupMessage {
+    pub fn parse(protocol_version: i32, payload: &[u8]) -> Result<StartupMessage, BoxDynError> {
+        if protocol_version >> 16 != PROTOCOL_MAJOR_VERSION {
+            return Err(format!("Unsupported protocol version {protocol_version}").into());
+        }
+
+        let mut strings = payload.split(|byte| *byte == 0);
+        let mut parameters = Vec::new();
+
+        loop {
+            let name = strings.next().ok_or("Startup message isn't terminated")?;
+            if name.is_empty() {
+                break;
+            }
+
+            let value = strings
+                .next()
+                .ok_or("Startup message parameter has no value")?;
+
+            parameters.push((
+                std::str::from_utf8(name)?.into(),
+                std::str::from_utf8(value)?.into(),
+            ));
+        }
+
+        Ok(StartupMessage {
+            protocol_version,
+            parameters,
+        })
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(parameter_name, _)| parameter_name.as_ref() == name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    pub fn set_parameter(&mut self, name: &str, value: &str) {
+        match self
+            .parameters
+            .iter_mut()
+            .find(|(parameter_name, _)| parameter_name.as_ref() == name)
+        {
+            Some((_, parameter_value)) => *parameter_value = value.into(),
+            None => self.parameters.push((name.into(), value.into())),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&self.protocol_version.to_be_bytes());
+
+        for (name, value) in self.parameters.iter() {
+            payload.extend_from_slice(name.as_bytes());
+            payload.push(0);
+            payload.extend_from_slice(value.as_bytes());
+            payload.push(0);
+        }
+        payload.push(0);
+
+        let mut message = Vec::with_capacity(payload.len() + 4);
+        message.extend_from_slice(&((payload.len() + 4) as i32).to_be_bytes());
+        message.extend_from_slice(&payload);
+
+        message
+    }
+}
+
+pub async fn read_startup_packet(
+    stream: &mut (impl AsyncRead + Unpin),
+) -> Result<StartupPacket, BoxDynError> {
+    let length = stream.read_i32().await? as usize;
+    if !(8..=MAX_STARTUP_MESSAGE_LENGTH).contains(&length) {
+        return Err(format!("Invalid startup message length {length}").into());
+    }
+
+    let code = stream.read_i32().await?;
+    let mut payload = vec![0; length - 8];
+    stream.read_exact(&mut payload).await?;
+
+    match code {
+        SSL_REQUEST_CODE => Ok(StartupPacket::SslRequest),
+        GSS_ENCRYPTION_REQUEST_CODE => Ok(StartupPacket::GssEncryptionRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupPacket::CancelRequest { payload }),
+        protocol_version => Ok(StartupPacket::StartupMessage(StartupMessage::parse(
+            protocol_version,
+            &payload,
+        )?)),
+    }
+}
+
+pub fn encode_ssl_request() -> Vec<u8> {
+    encode_request(SSL_REQUEST_CODE, &[])
+}
+
+pub fn encode_cancel_request(payload: &[u8]) -> Vec<u8> {
+    encode_request(CANCEL_REQUEST_CODE, payload)
+}
+
+fn encode_request(code: i32, payload: &[u8]) -> Vec<u8> {
+    let mut request = Vec::with_capacity(payload.len() + 8);
+    request.extend_from_slice(&((payload.len() + 8) as i32).to_be_bytes());
+    request.extend_from_slice(&code.to_be_bytes());
+    request.extend_from_slice(payload);
+
+    request
+}
+
+// ErrorResponse backend message with FATAL severity
+pub fn encode_fatal_error(sql_state: &str, message: &str) -> Vec<u8> {
+    let mut fields = Vec::new();
+
+    for (field_type, value) in [
+        (b'S', "FATAL"),
+        (b'V', "FATAL"),
+        (b'C', sql_state),
+        (b'M', message),
+    ] {
+        fields.push(field_type);
+        fields.extend_from_slice(value.as_bytes());
+        fields.push(0);
+    }
+    fields.push(0);
+
+    let mut error_response = Vec::with_capacity(fields.len() + 5);
+    error_response.push(b'E');
+    error_response.extend_from_slice(&((fields.len() + 4) as i32).to_be_bytes());
+    error_response.extend_from_slice(&fields);
+
+    error_response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::messages::{
+        StartupPacket, encode_cancel_request, encode_fatal_error, read_startup_packet,
+    };
+
+    #[tokio::test]
+    async fn startup_message_is_read_and_encoded_back() {
+        let mut message = Vec::new();
+        message.extend_from_slice(&0i32.to_be_bytes());
+        message.extend_from_slice(&196608i32.to_be_bytes());
+        message.extend_from_slice(b"user\0postgres\0database\0tempest:0A\0\0");
+        let length = message.len() as i32;
+        message[..4].copy_from_slice(&length.to_be_bytes());
+
+        let startup_packet = read_startup_packet(&mut message.as_slice()).await.unwrap();
+
+        let StartupPacket::StartupMessage(mut startup_message) = startup_packet else {
+            panic!("Startup message was expected");
+        };
+        assert_eq!(startup_message.parameter("user"), Some("postgres"));
+        assert_eq!(startup_message.parameter("database"), Some("tempest:0A"));
+        assert_eq!(startup_message.encode(), message);
+
+        startup_message.set_parameter("database", "TEST_DB");
+        let encoded = startup_message.encode();
+        assert_eq!(encoded.len(), message.len() - 3);
+        assert!(encoded.ends_with(b"database\0TEST_DB\0\0"));
+    }
+
+    #[tokio::test]
+    async fn ssl_request_is_recognized() {
+        let message = [0, 0, 0, 8, 0x04, 0xD2, 0x16, 0x2F];
+
+        let startup_packet = read_startup_packet(&mut message.as_slice()).await.unwrap();
+
+        assert!(matches!(startup_packet, StartupPacket::SslRequest));
+    }
+
+    #[tokio::test]
+    async fn cancel_request_is_read_and_encoded_back() {
+        let message = encode_cancel_request(&[0, 0, 0x30, 0x39, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let startup_packet = read_startup_packet(&mut message.as_slice()).await.unwrap();
+
+        let StartupPacket::CancelRequest { payload } = startup_packet else {
+            panic!("Cancel request was expected");
+        };
+        assert_eq!(message[..8], [0, 0, 0, 16, 0x04, 0xD2, 0x16, 0x2E]);
+        assert_eq!(encode_cancel_request(&payload), message);
+    }
+
+    #[test]
+    fn fatal_error_has_valid_length() {
+        let error_response = encode_fatal_error("3D000", "Database was not found");
+
+        let length = i32::from_be_bytes(error_response[1..5].try_into().unwrap());
+        assert_eq!(error_response[0], b'E');
+        assert_eq!(length as usize, error_response.len() - 1);
+        assert!(error_response.ends_with(b"Database was not found\0\0"));
+    }
+}
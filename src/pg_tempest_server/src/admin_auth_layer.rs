@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use pg_tempest_core::utils::tokens::tokens_are_equal;
+
+use crate::dtos::api_error::ApiError;
+
+// Rejects requests without `Authorization: Bearer <admin token>`
+pub async fn admin_auth_layer(
+    State(admin_token): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let bearer_token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match bearer_token {
+        Some(bearer_token) if tokens_are_equal(bearer_token, &admin_token) => {
+            next.run(request).await
+        }
+        _ => ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "adminTokenIsInvalid",
+            "Admin token is missing or invalid",
+        )
+        .into_response(),
+    }
+}
@@ -1,8 +1,43 @@
 use serde::Deserialize;
 use std::net::Ipv4Addr;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Deserialize)]
 pub struct ServerConfigs {
     pub ipv4: Ipv4Addr,
     pub port: u16,
+    pub redoc: bool,
+    #[serde(default)]
+    pub admin: ServerAdminConfigs,
+    #[serde(default)]
+    pub tls: ServerTlsConfigs,
+    pub import: ServerImportConfigs,
+}
+
+#[derive(Deserialize)]
+pub struct ServerImportConfigs {
+    // Limit of request bodies of template imports, which carry SQL dumps
+    pub max_body_size_mb: usize,
+}
+
+impl ServerImportConfigs {
+    pub fn max_body_size_bytes(&self) -> usize {
+        self.max_body_size_mb * 1024 * 1024
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct ServerTlsConfigs {
+    pub enabled: bool,
+    pub cert_path: Option<PathBuf>,
+    pub key_path: Option<PathBuf>,
+    // Enables mTLS: clients must present a certificate signed by this CA
+    pub client_ca_path: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ServerAdminConfigs {
+    // Enables /api/admin routes and template imports, which require `Authorization: Bearer <token>`
+    pub token: Option<Arc<str>>,
 }
@@ -0,0 +1,88 @@
+use axum::{
+    extract::{
+        FromRequest, FromRequestParts,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+    },
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::dtos::json_response::JsonResponse;
+
+// Error of /api/v1 routes. `code` is a stable camelCase identifier of the error
+// and `message` is a human readable description
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiErrorDto {
+    pub code: &'static str,
+    #[schema(value_type = String)]
+    pub message: Box<str>,
+}
+
+pub struct ApiError {
+    pub status_code: StatusCode,
+    pub body: ApiErrorDto,
+}
+
+impl ApiError {
+    pub fn new(status_code: StatusCode, code: &'static str, message: impl Into<Box<str>>) -> Self {
+        ApiError {
+            status_code,
+            body: ApiErrorDto {
+                code,
+                message: message.into(),
+            },
+        }
+    }
+
+    pub fn unexpected(message: impl Into<Box<str>>) -> Self {
+        ApiError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "unexpectedError",
+            message,
+        )
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> axum::response::Response {
+        JsonResponse {
+            status_code: self.status_code,
+            body: self.body,
+        }
+        .into_response()
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        ApiError::new(rejection.status(), "invalidBody", rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        ApiError::new(rejection.status(), "invalidPath", rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::new(rejection.status(), "invalidQuery", rejection.body_text())
+    }
+}
+
+// Extractors, which reject requests with `ApiError` instead of plain text
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(ApiError))]
+pub struct ApiJson<T>(pub T);
+
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Path), rejection(ApiError))]
+pub struct ApiPath<T>(pub T);
+
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(ApiError))]
+pub struct ApiQuery<T>(pub T);
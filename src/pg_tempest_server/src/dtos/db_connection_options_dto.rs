@@ -1,26 +1,56 @@
 use pg_tempest_core::models::{
-    db_connection_options::DbConnectionOptions, value_types::pg_identifier::PgIdentifier,
+    connection_strings::ConnectionStrings, db_connection_options::DbConnectionOptions,
+    value_types::pg_identifier::PgIdentifier,
 };
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DbConnectionOptionsDto {
+    #[schema(value_type = String)]
     pub host: Box<str>,
     pub port: u16,
+    #[schema(value_type = String)]
     pub username: Box<str>,
+    #[schema(value_type = String)]
     pub password: Box<str>,
+    #[schema(value_type = String)]
     pub database: PgIdentifier,
+    pub connection_strings: ConnectionStringsDto,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionStringsDto {
+    pub uri: String,
+    pub key_value: String,
+    pub jdbc: String,
+    pub ado_net: String,
 }
 
 impl From<DbConnectionOptions> for DbConnectionOptionsDto {
     fn from(value: DbConnectionOptions) -> Self {
+        let connection_strings = ConnectionStrings::new(&value);
+
         DbConnectionOptionsDto {
             host: value.host,
             port: value.port,
             username: value.username,
             password: value.password,
             database: value.database,
+            connection_strings: connection_strings.into(),
+        }
+    }
+}
+
+impl From<ConnectionStrings> for ConnectionStringsDto {
+    fn from(value: ConnectionStrings) -> Self {
+        ConnectionStringsDto {
+            uri: value.uri,
+            key_value: value.key_value,
+            jdbc: value.jdbc,
+            ado_net: value.ado_net,
         }
     }
 }
@@ -0,0 +1,43 @@
+use pg_tempest_core::configs::fault_injection_configs::FaultInjectionConfigs;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FaultInjectionDto {
+    pub enabled: bool,
+    pub latency_ms: u64,
+    pub latency_jitter_ms: u64,
+    pub hang_probability: f64,
+    pub hang_duration_ms: u64,
+    pub unexpected_error_probability: f64,
+    pub conflict_probability: f64,
+}
+
+impl From<FaultInjectionConfigs> for FaultInjectionDto {
+    fn from(value: FaultInjectionConfigs) -> Self {
+        FaultInjectionDto {
+            enabled: value.enabled,
+            latency_ms: value.latency_ms,
+            latency_jitter_ms: value.latency_jitter_ms,
+            hang_probability: value.hang_probability,
+            hang_duration_ms: value.hang_duration_ms,
+            unexpected_error_probability: value.unexpected_error_probability,
+            conflict_probability: value.conflict_probability,
+        }
+    }
+}
+
+impl From<FaultInjectionDto> for FaultInjectionConfigs {
+    fn from(value: FaultInjectionDto) -> Self {
+        FaultInjectionConfigs {
+            enabled: value.enabled,
+            latency_ms: value.latency_ms,
+            latency_jitter_ms: value.latency_jitter_ms,
+            hang_probability: value.hang_probability,
+            hang_duration_ms: value.hang_duration_ms,
+            unexpected_error_probability: value.unexpected_error_probability,
+            conflict_probability: value.conflict_probability,
+        }
+    }
+}
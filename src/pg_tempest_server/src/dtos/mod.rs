@@ -1,2 +1,7 @@
+pub mod api_error;
 pub mod db_connection_options_dto;
+pub mod fault_injection_dto;
 pub mod json_response;
+pub mod template_dto;
+pub mod template_dump_dto;
+pub mod template_dump_format_dto;
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    features::templates::get_templates::{TemplateSummary, TestDbSummary},
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDto {
+    #[schema(value_type = String)]
+    pub template_hash: TemplateHash,
+    pub initialization_state: TemplateInitializationStateDto,
+    pub test_dbs: Vec<TestDbDto>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TemplateInitializationStateDto {
+    Creating {},
+    Created {},
+    #[serde(rename_all = "camelCase")]
+    InProgress {
+        initialization_deadline: DateTime<Utc>,
+    },
+    Finished {},
+    Failed {
+        #[schema(value_type = Option<String>)]
+        reason: Option<Arc<str>>,
+    },
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TestDbDto {
+    #[schema(value_type = String)]
+    pub test_db_id: TestDbId,
+    pub state: TestDbStateDto,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TestDbStateDto {
+    Creating {},
+    Ready {},
+    Corrupted {},
+    #[serde(rename_all = "camelCase")]
+    InUse {
+        usage_deadline: DateTime<Utc>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Retained {
+        retention_deadline: DateTime<Utc>,
+    },
+}
+
+impl From<TemplateSummary> for TemplateDto {
+    fn from(value: TemplateSummary) -> Self {
+        TemplateDto {
+            template_hash: value.template_hash,
+            initialization_state: value.initialization_state.into(),
+            test_dbs: value.test_dbs.into_iter().map(TestDbDto::from).collect(),
+        }
+    }
+}
+
+impl From<TemplateInitializationState> for TemplateInitializationStateDto {
+    fn from(value: TemplateInitializationState) -> Self {
+        match value {
+            TemplateInitializationState::Creating => TemplateInitializationStateDto::Creating {},
+            TemplateInitializationState::Created => TemplateInitializationStateDto::Created {},
+            TemplateInitializationState::InProgress {
+                initialization_deadline,
+            } => TemplateInitializationStateDto::InProgress {
+                initialization_deadline,
+            },
+            TemplateInitializationState::Finished => TemplateInitializationStateDto::Finished {},
+            TemplateInitializationState::Failed { reason } => {
+                TemplateInitializationStateDto::Failed { reason }
+            }
+        }
+    }
+}
+
+impl From<TestDbSummary> for TestDbDto {
+    fn from(value: TestDbSummary) -> Self {
+        TestDbDto {
+            test_db_id: value.id,
+            state: match value.state {
+                TestDbState::Creating => TestDbStateDto::Creating {},
+                TestDbState::Ready => TestDbStateDto::Ready {},
+                TestDbState::Corrupted => TestDbStateDto::Corrupted {},
+                TestDbState::InUse { usage_deadline } => TestDbStateDto::InUse { usage_deadline },
+                TestDbState::Retained { retention_deadline } => {
+                    TestDbStateDto::Retained { retention_deadline }
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use pg_tempest_core::features::templates::import_template::TemplateDump;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TemplateDumpDto {
+    // Plain SQL output of pg_dump
+    Sql {
+        #[schema(value_type = String)]
+        script: Box<str>,
+    },
+    // pg_dump archive inside of the archive dir of the server
+    Archive {
+        #[schema(value_type = String)]
+        path: PathBuf,
+    },
+}
+
+impl From<TemplateDumpDto> for TemplateDump {
+    fn from(value: TemplateDumpDto) -> Self {
+        match value {
+            TemplateDumpDto::Sql { script } => TemplateDump::Sql { script },
+            TemplateDumpDto::Archive { path } => TemplateDump::Archive { path: path.into() },
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use pg_tempest_core::pg_client::DbDumpFormat;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TemplateDumpFormatDto {
+    // Plain SQL script, which can be restored by psql
+    #[default]
+    Sql,
+    // pg_dump archive in custom format, which can be restored by pg_restore
+    Custom,
+}
+
+impl TemplateDumpFormatDto {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            TemplateDumpFormatDto::Sql => "application/sql",
+            TemplateDumpFormatDto::Custom => "application/octet-stream",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            TemplateDumpFormatDto::Sql => "sql",
+            TemplateDumpFormatDto::Custom => "dump",
+        }
+    }
+}
+
+impl From<TemplateDumpFormatDto> for DbDumpFormat {
+    fn from(value: TemplateDumpFormatDto) -> Self {
+        match value {
+            TemplateDumpFormatDto::Sql => DbDumpFormat::Sql,
+            TemplateDumpFormatDto::Custom => DbDumpFormat::Custom,
+        }
+    }
+}
@@ -1,19 +1,29 @@
-use std::{net::SocketAddrV4, sync::Arc};
+use std::{
+    net::{SocketAddr, SocketAddrV4},
+    sync::Arc,
+};
 
 use crate::{
     configs::ServerConfigs,
     custom_trace_layer::custom_trace_layer,
-    routes::{templates::create_templates_router, test_dbs::create_test_dbs_router},
+    routes::{
+        admin::create_admin_router, openapi::create_openapi_router,
+        templates::create_templates_router, test_dbs::create_test_dbs_router, v1::create_v1_router,
+    },
+    tls::build_rustls_config,
 };
 use axum::Router;
 use pg_tempest_core::PgTempestCore;
+use pg_tempest_core::fault_injection::FaultInjection;
 use pg_tempest_core::utils::errors::BoxDynError;
 use tokio::net::TcpListener;
 
+mod admin_auth_layer;
 pub mod configs;
 mod custom_trace_layer;
 mod dtos;
 mod routes;
+mod tls;
 
 pub struct Server {
     router: Router,
@@ -21,10 +31,29 @@ pub struct Server {
 }
 
 impl Server {
-    pub fn new(tempest_core: Arc<PgTempestCore>, configs: Arc<ServerConfigs>) -> Server {
+    pub fn new(
+        tempest_core: Arc<PgTempestCore>,
+        fault_injection: Arc<FaultInjection>,
+        configs: Arc<ServerConfigs>,
+    ) -> Server {
         let router = Router::new()
-            .merge(create_templates_router(tempest_core.clone()))
+            .merge(create_templates_router(
+                tempest_core.clone(),
+                configs.import.max_body_size_bytes(),
+                configs.admin.token.clone(),
+            ))
             .merge(create_test_dbs_router(tempest_core.clone()))
+            .merge(create_v1_router(
+                tempest_core.clone(),
+                configs.import.max_body_size_bytes(),
+                configs.admin.token.clone(),
+            ))
+            .merge(create_admin_router(
+                tempest_core.clone(),
+                fault_injection,
+                configs.admin.token.clone(),
+            ))
+            .merge(create_openapi_router(configs.redoc))
             .layer(axum::middleware::from_fn(custom_trace_layer));
 
         Server { router, configs }
@@ -33,6 +62,18 @@ impl Server {
     pub async fn start(self) -> Result<(), BoxDynError> {
         let socket_addr = SocketAddrV4::new(self.configs.ipv4, self.configs.port);
 
+        if self.configs.tls.enabled {
+            let rustls_config = build_rustls_config(&self.configs.tls)?;
+
+            tracing::info!("Starting server with TLS on {socket_addr}");
+
+            axum_server::bind_rustls(SocketAddr::V4(socket_addr), rustls_config)
+                .serve(self.router.into_make_service())
+                .await?;
+
+            return Ok(());
+        }
+
         tracing::info!("Starting server on {socket_addr}");
 
         let tcp_listener = TcpListener::bind(socket_addr).await?;
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use pg_tempest_core::{
+    PgTempestCore, features::templates::export_template::ExportTemplateErrorResult,
+    models::value_types::template_hash::TemplateHash,
+};
+use serde::Deserialize;
+use tokio_util::io::ReaderStream;
+use utoipa::IntoParams;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiPath, ApiQuery},
+    template_dump_format_dto::TemplateDumpFormatDto,
+};
+use crate::routes::v1::templates::{initialization_is_not_finished, template_was_not_found};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportTemplateQuery {
+    #[serde(default)]
+    format: TemplateDumpFormatDto,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/templates/{template_hash}/dump",
+    tag = "admin",
+    security(("adminToken" = [])),
+    params(("template_hash" = String, Path), ExportTemplateQuery),
+    responses(
+        (status = OK, description = "Dump of the template, streamed while pg_dump runs", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ApiErrorDto),
+        (status = CONFLICT, description = "initializationIsNotFinished", body = ApiErrorDto),
+    )
+)]
+pub async fn export_template(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiQuery(query): ApiQuery<ExportTemplateQuery>,
+) -> Result<Response, ApiError> {
+    let result = tempest_core
+        .export_template(template_hash, query.format.into())
+        .await;
+
+    let ok_result = match result {
+        Ok(ok_result) => ok_result,
+        Err(ExportTemplateErrorResult::TemplateWasNotFound) => {
+            return Err(template_was_not_found(template_hash));
+        }
+        Err(ExportTemplateErrorResult::InitializationIsNotFinished) => {
+            return Err(initialization_is_not_finished(template_hash));
+        }
+        Err(ExportTemplateErrorResult::Unknown { inner }) => {
+            return Err(ApiError::unexpected(inner.to_string()));
+        }
+    };
+
+    // A failure of pg_dump aborts the response,
+    // so that an incomplete dump isn't mistaken for a complete one
+    let body = Body::from_stream(ReaderStream::new(ok_result.dump));
+    let content_disposition = format!(
+        r#"attachment; filename="{template_hash}.{}""#,
+        query.format.file_extension()
+    );
+
+    Ok((
+        [
+            (CONTENT_TYPE, query.format.content_type().to_string()),
+            (CONTENT_DISPOSITION, content_disposition),
+        ],
+        body,
+    )
+        .into_response())
+}
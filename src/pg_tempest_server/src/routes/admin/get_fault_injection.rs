@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::fault_injection::FaultInjection;
+
+use crate::dtos::{
+    api_error::ApiErrorDto, fault_injection_dto::FaultInjectionDto, json_response::JsonResponse,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/fault-injection",
+    tag = "admin",
+    security(("adminToken" = [])),
+    responses(
+        (status = OK, body = FaultInjectionDto),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+    )
+)]
+pub async fn get_fault_injection(
+    State(fault_injection): State<Arc<FaultInjection>>,
+) -> JsonResponse<FaultInjectionDto> {
+    JsonResponse {
+        status_code: StatusCode::OK,
+        body: fault_injection.configs().into(),
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    middleware::from_fn_with_state,
+    routing::{delete, get},
+};
+use pg_tempest_core::{PgTempestCore, fault_injection::FaultInjection};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
+
+use crate::admin_auth_layer::admin_auth_layer;
+use crate::routes::admin::{
+    export_template::export_template, get_fault_injection::get_fault_injection,
+    purge_templates::purge_templates, set_fault_injection::set_fault_injection,
+};
+
+mod export_template;
+mod get_fault_injection;
+mod purge_templates;
+mod set_fault_injection;
+
+pub const ADMIN_TOKEN_SECURITY: &str = "adminToken";
+
+struct AdminTokenSecurity;
+
+impl Modify for AdminTokenSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        openapi
+            .components
+            .get_or_insert_default()
+            .add_security_scheme(
+                ADMIN_TOKEN_SECURITY,
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+            );
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_fault_injection::get_fault_injection,
+        set_fault_injection::set_fault_injection,
+        export_template::export_template,
+        purge_templates::purge_templates,
+    ),
+    modifiers(&AdminTokenSecurity)
+)]
+pub struct AdminApiDoc;
+
+// Admin routes are served only when an admin token is configured
+pub fn create_admin_router(
+    tempest_core: Arc<PgTempestCore>,
+    fault_injection: Arc<FaultInjection>,
+    admin_token: Option<Arc<str>>,
+) -> Router {
+    let Some(admin_token) = admin_token else {
+        return Router::new();
+    };
+
+    Router::new()
+        .route(
+            "/api/admin/fault-injection",
+            get(get_fault_injection).put(set_fault_injection),
+        )
+        .with_state(fault_injection)
+        .merge(
+            Router::new()
+                .route("/api/admin/templates", delete(purge_templates))
+                .route(
+                    "/api/admin/templates/{template_hash}/dump",
+                    get(export_template),
+                )
+                .with_state(tempest_core),
+        )
+        .layer(from_fn_with_state(admin_token, admin_auth_layer))
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiQuery},
+    json_response::JsonResponse,
+};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PurgeTemplatesQuery {
+    /// Drop dbs of templates unknown to the server too, e.g. left by previous runs
+    /// or belonging to other servers sharing the dbms
+    #[serde(default)]
+    all: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgedTemplatesDto {
+    #[schema(value_type = Vec<String>)]
+    purged_template_hashes: Vec<TemplateHash>,
+    #[schema(value_type = Vec<String>)]
+    dropped_dbs: Vec<PgIdentifier>,
+    failed_dbs: Vec<FailedDbDto>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedDbDto {
+    #[schema(value_type = String)]
+    db_name: PgIdentifier,
+    #[schema(value_type = String)]
+    reason: Arc<str>,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/templates",
+    tag = "admin",
+    security(("adminToken" = [])),
+    params(PurgeTemplatesQuery),
+    responses(
+        (status = OK, body = PurgedTemplatesDto),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn purge_templates(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiQuery(query): ApiQuery<PurgeTemplatesQuery>,
+) -> Result<JsonResponse<PurgedTemplatesDto>, ApiError> {
+    let result = tempest_core
+        .purge_templates(query.all)
+        .await
+        .map_err(|err| ApiError::unexpected(err.to_string()))?;
+
+    Ok(JsonResponse {
+        status_code: StatusCode::OK,
+        body: PurgedTemplatesDto {
+            purged_template_hashes: result.purged_template_hashes,
+            dropped_dbs: result.dropped_dbs,
+            failed_dbs: result
+                .failed_dbs
+                .into_iter()
+                .map(|failure| FailedDbDto {
+                    db_name: failure.db_name,
+                    reason: failure.reason,
+                })
+                .collect(),
+        },
+    })
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::fault_injection::FaultInjection;
+use tracing::warn;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson},
+    fault_injection_dto::FaultInjectionDto,
+    json_response::JsonResponse,
+};
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/fault-injection",
+    tag = "admin",
+    security(("adminToken" = [])),
+    request_body = FaultInjectionDto,
+    responses(
+        (status = OK, body = FaultInjectionDto),
+        (status = BAD_REQUEST, description = "faultInjectionIsInvalid", body = ApiErrorDto),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+    )
+)]
+pub async fn set_fault_injection(
+    State(fault_injection): State<Arc<FaultInjection>>,
+    ApiJson(request_body): ApiJson<FaultInjectionDto>,
+) -> Result<JsonResponse<FaultInjectionDto>, ApiError> {
+    fault_injection
+        .set_configs(request_body.into())
+        .map_err(|message| {
+            ApiError::new(StatusCode::BAD_REQUEST, "faultInjectionIsInvalid", message)
+        })?;
+
+    let configs = fault_injection.configs();
+    warn!("Fault injection was changed: {configs:?}");
+
+    Ok(JsonResponse {
+        status_code: StatusCode::OK,
+        body: configs.into(),
+    })
+}
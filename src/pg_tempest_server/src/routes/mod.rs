@@ -1,2 +1,5 @@
+pub mod admin;
+pub mod openapi;
 pub mod templates;
 pub mod test_dbs;
+pub mod v1;
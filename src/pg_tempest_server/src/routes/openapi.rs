@@ -0,0 +1,64 @@
+use axum::{Json, Router, routing::get};
+use utoipa::OpenApi;
+use utoipa_redoc::{Redoc, Servable};
+
+use crate::routes::{
+    admin::AdminApiDoc, templates::TemplatesApiDoc, test_dbs::TestDbsApiDoc, v1::V1ApiDoc,
+};
+
+#[derive(OpenApi)]
+#[openapi(info(
+    title = "pg-tempest",
+    description = "Response bodies are objects with a single key naming the result",
+    license(name = "MIT")
+))]
+struct ApiDoc;
+
+fn build_openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+
+    openapi.merge(TemplatesApiDoc::openapi());
+    openapi.merge(TestDbsApiDoc::openapi());
+    openapi.merge(V1ApiDoc::openapi());
+    openapi.merge(AdminApiDoc::openapi());
+
+    openapi
+}
+
+pub fn create_openapi_router(redoc: bool) -> Router {
+    let openapi = build_openapi();
+
+    let router = Router::new().route(
+        "/api/openapi.json",
+        get({
+            let openapi = openapi.clone();
+            async move || Json(openapi)
+        }),
+    );
+
+    if redoc {
+        router.merge(Redoc::with_url("/api/redoc", openapi))
+    } else {
+        router
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::openapi::build_openapi;
+
+    #[test]
+    fn openapi_uses_serialized_field_names() {
+        let openapi = serde_json::to_string(&build_openapi()).unwrap();
+
+        assert!(openapi.contains("\"/api/get-test-db\""));
+        assert!(openapi.contains("\"/api/admin/templates\""));
+        assert!(openapi.contains("\"/api/v1/templates/{template_hash}/test-dbs\""));
+        assert!(openapi.contains("\"usageDeadline\""));
+        assert!(!openapi.contains("\"usage_deadline\""));
+        assert!(openapi.contains("\"adminToken\""));
+        // Request bodies of v1 routes don't replace the ones of the same name
+        assert!(openapi.contains("\"v1.StartTemplateInitializationRequestBody\""));
+        assert!(openapi.contains("\"StartTemplateInitializationRequestBody\""));
+    }
+}
@@ -8,19 +8,22 @@ use pg_tempest_core::{
     models::value_types::template_hash::TemplateHash,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::dtos::json_response::JsonResponse;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ExtendTemplateInitializationRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
     additional_time_ms: u64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum ExtendTemplateInitializationResponseBody {
+    #[serde(rename_all = "camelCase")]
     InitializationWasExtended {
         new_initialization_deadline: DateTime<Utc>,
     },
@@ -28,10 +31,22 @@ pub enum ExtendTemplateInitializationResponseBody {
     InitializationIsNotStarted {},
     InitializationIsFinished {},
     InitializationIsFailed {
+        #[schema(value_type = Option<String>)]
         reason: Option<Arc<str>>,
     },
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/extend-template-initialization",
+    tag = "templates",
+    request_body = ExtendTemplateInitializationRequestBody,
+    responses(
+        (status = OK, description = "initializationWasExtended", body = ExtendTemplateInitializationResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ExtendTemplateInitializationResponseBody),
+        (status = CONFLICT, description = "initializationIsNotStarted, initializationIsFinished or initializationIsFailed", body = ExtendTemplateInitializationResponseBody),
+    )
+)]
 pub async fn extend_template_initialization(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<ExtendTemplateInitializationRequestBody>,
@@ -7,17 +7,20 @@ use pg_tempest_core::{
     models::value_types::template_hash::TemplateHash,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::dtos::json_response::JsonResponse;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FailTemplateInitializationRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
+    #[schema(value_type = Option<String>)]
     reason: Option<Arc<str>>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum FailTemplateInitializationResponseBody {
     InitializationIsFailed {},
@@ -26,6 +29,17 @@ pub enum FailTemplateInitializationResponseBody {
     InitializationIsFinished {},
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/fail-template-initialization",
+    tag = "templates",
+    request_body = FailTemplateInitializationRequestBody,
+    responses(
+        (status = OK, description = "initializationIsFailed", body = FailTemplateInitializationResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = FailTemplateInitializationResponseBody),
+        (status = CONFLICT, description = "initializationIsNotStarted or initializationIsFinished", body = FailTemplateInitializationResponseBody),
+    )
+)]
 pub async fn fail_template_initialization(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<FailTemplateInitializationRequestBody>,
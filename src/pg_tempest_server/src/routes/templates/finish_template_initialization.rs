@@ -7,24 +7,40 @@ use pg_tempest_core::{
     models::value_types::template_hash::TemplateHash,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::dtos::json_response::JsonResponse;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FinishTemplateInitializationRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum FinishTemplateInitializationResponseBody {
     InitializationIsFinished {},
     TemplateWasNotFound {},
     InitializationIsNotStarted {},
-    InitializationIsFailed { reason: Option<Arc<str>> },
+    InitializationIsFailed {
+        #[schema(value_type = Option<String>)]
+        reason: Option<Arc<str>>,
+    },
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/finish-template-initialization",
+    tag = "templates",
+    request_body = FinishTemplateInitializationRequestBody,
+    responses(
+        (status = OK, description = "initializationIsFinished", body = FinishTemplateInitializationResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = FinishTemplateInitializationResponseBody),
+        (status = CONFLICT, description = "initializationIsNotStarted or initializationIsFailed", body = FinishTemplateInitializationResponseBody),
+    )
+)]
 pub async fn finish_template_initialization(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<FinishTemplateInitializationRequestBody>,
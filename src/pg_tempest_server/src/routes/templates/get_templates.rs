@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::PgTempestCore;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::dtos::{json_response::JsonResponse, template_dto::TemplateDto};
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum GetTemplatesResponseBody {
+    TemplatesWereFound { templates: Vec<TemplateDto> },
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/get-templates",
+    tag = "templates",
+    responses(
+        (status = OK, description = "templatesWereFound", body = GetTemplatesResponseBody),
+    )
+)]
+pub async fn get_templates(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+) -> JsonResponse<GetTemplatesResponseBody> {
+    let templates = tempest_core.get_templates().await;
+
+    JsonResponse {
+        status_code: StatusCode::OK,
+        body: GetTemplatesResponseBody::TemplatesWereFound {
+            templates: templates.into_iter().map(TemplateDto::from).collect(),
+        },
+    }
+}
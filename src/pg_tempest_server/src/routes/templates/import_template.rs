@@ -0,0 +1,103 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{Json, extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::import_template::ImportTemplateResult,
+    models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::ApiErrorDto, json_response::JsonResponse, template_dump_dto::TemplateDumpDto,
+};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTemplateRequestBody {
+    #[schema(value_type = String)]
+    template_hash: TemplateHash,
+    dump: TemplateDumpDto,
+    initialization_duration_ms: u64,
+    #[schema(value_type = Option<String>)]
+    parent_template_db_name: Option<PgIdentifier>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ImportTemplateResponseBody {
+    TemplateWasImported {},
+    InitializationIsInProgress {},
+    InitializationIsFinished {},
+    InitializationIsFailed {
+        #[schema(value_type = Option<String>)]
+        reason: Option<Arc<str>>,
+    },
+    ArchiveImportIsDisabled {},
+    ArchivePathIsInvalid {},
+    UnexpectedError {
+        #[schema(value_type = String)]
+        message: Box<str>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/import-template",
+    tag = "templates",
+    security(("adminToken" = [])),
+    request_body = ImportTemplateRequestBody,
+    responses(
+        (status = OK, description = "templateWasImported, initializationIsInProgress, initializationIsFinished or initializationIsFailed", body = ImportTemplateResponseBody),
+        (status = BAD_REQUEST, description = "archiveImportIsDisabled or archivePathIsInvalid", body = ImportTemplateResponseBody),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ImportTemplateResponseBody),
+    )
+)]
+pub async fn import_template(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    Json(request_body): Json<ImportTemplateRequestBody>,
+) -> JsonResponse<ImportTemplateResponseBody> {
+    let result = tempest_core
+        .import_template(
+            request_body.template_hash,
+            request_body.dump.into(),
+            Duration::from_millis(request_body.initialization_duration_ms),
+            request_body.parent_template_db_name,
+        )
+        .await;
+
+    match result {
+        Ok(ImportTemplateResult::TemplateWasImported) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: ImportTemplateResponseBody::TemplateWasImported {},
+        },
+        Ok(ImportTemplateResult::InitializationIsInProgress) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: ImportTemplateResponseBody::InitializationIsInProgress {},
+        },
+        Ok(ImportTemplateResult::InitializationIsFinished) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: ImportTemplateResponseBody::InitializationIsFinished {},
+        },
+        Ok(ImportTemplateResult::InitializationIsFailed { reason }) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: ImportTemplateResponseBody::InitializationIsFailed { reason },
+        },
+        Ok(ImportTemplateResult::ArchiveImportIsDisabled) => JsonResponse {
+            status_code: StatusCode::BAD_REQUEST,
+            body: ImportTemplateResponseBody::ArchiveImportIsDisabled {},
+        },
+        Ok(ImportTemplateResult::ArchivePathIsInvalid) => JsonResponse {
+            status_code: StatusCode::BAD_REQUEST,
+            body: ImportTemplateResponseBody::ArchivePathIsInvalid {},
+        },
+        Err(err) => JsonResponse {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: ImportTemplateResponseBody::UnexpectedError {
+                message: err.to_string().into(),
+            },
+        },
+    }
+}
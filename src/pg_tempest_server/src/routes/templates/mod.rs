@@ -1,22 +1,41 @@
 use std::sync::Arc;
 
-use axum::{Router, routing::post};
+use axum::{Router, extract::DefaultBodyLimit, middleware::from_fn_with_state, routing::post};
 use pg_tempest_core::PgTempestCore;
+use utoipa::OpenApi;
 
+use crate::admin_auth_layer::admin_auth_layer;
 use crate::routes::templates::{
     extend_template_initialization::extend_template_initialization,
     fail_template_initialization::fail_template_initialization,
-    finish_template_initialization::finish_template_initialization,
-    start_template_initialization::start_template_initialization,
+    finish_template_initialization::finish_template_initialization, get_templates::get_templates,
+    import_template::import_template, start_template_initialization::start_template_initialization,
 };
 
 mod extend_template_initialization;
 mod fail_template_initialization;
 mod finish_template_initialization;
+mod get_templates;
+mod import_template;
 mod start_template_initialization;
 
-pub fn create_templates_router(tempest_core: Arc<PgTempestCore>) -> Router {
-    Router::new()
+#[derive(OpenApi)]
+#[openapi(paths(
+    start_template_initialization::start_template_initialization,
+    finish_template_initialization::finish_template_initialization,
+    fail_template_initialization::fail_template_initialization,
+    extend_template_initialization::extend_template_initialization,
+    get_templates::get_templates,
+    import_template::import_template,
+))]
+pub struct TemplatesApiDoc;
+
+pub fn create_templates_router(
+    tempest_core: Arc<PgTempestCore>,
+    max_import_body_size: usize,
+    admin_token: Option<Arc<str>>,
+) -> Router {
+    let router = Router::new()
         .route(
             "/api/start-template-initialization",
             post(start_template_initialization),
@@ -33,5 +52,20 @@ pub fn create_templates_router(tempest_core: Arc<PgTempestCore>) -> Router {
             "/api/extend-template-initialization",
             post(extend_template_initialization),
         )
+        .route("/api/get-templates", post(get_templates));
+
+    // Imports execute client dumps in the dbms, so like admin routes
+    // they are served only when an admin token is configured
+    let Some(admin_token) = admin_token else {
+        return router.with_state(tempest_core);
+    };
+
+    router
+        .route(
+            "/api/import-template",
+            post(import_template)
+                .layer(DefaultBodyLimit::max(max_import_body_size))
+                .layer(from_fn_with_state(admin_token, admin_auth_layer)),
+        )
         .with_state(tempest_core)
 }
@@ -3,6 +3,8 @@ use std::{sync::Arc, time::Duration};
 use crate::dtos::{db_connection_options_dto::DbConnectionOptionsDto, json_response::JsonResponse};
 use axum::{Json, extract::State, http::StatusCode};
 use chrono::{DateTime, Utc};
+use pg_tempest_core::models::db_settings::DbSettings;
+use pg_tempest_core::models::value_types::extension_name::ExtensionName;
 use pg_tempest_core::models::value_types::pg_identifier::PgIdentifier;
 use pg_tempest_core::{
     PgTempestCore,
@@ -10,18 +12,33 @@ use pg_tempest_core::{
     models::value_types::template_hash::TemplateHash,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StartTemplateInitializationRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
     initialization_duration_ms: u64,
+    #[schema(value_type = Option<String>)]
     parent_template_db_name: Option<PgIdentifier>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    network_profile: Option<Box<str>>,
+    // Parameters of `ALTER DATABASE ... SET` for the template db and its test dbs
+    #[serde(default)]
+    #[schema(value_type = Option<BTreeMap<String, String>>)]
+    settings: DbSettings,
+    // Created by the server in the template db, e.g. pgcrypto or uuid-ossp
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>)]
+    extensions: Vec<ExtensionName>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum StartTemplateInitializationResponseBody {
+    #[serde(rename_all = "camelCase")]
     InitializationWasStarted {
         database_connection_options: DbConnectionOptionsDto,
         initialization_deadline: DateTime<Utc>,
@@ -29,13 +46,27 @@ pub enum StartTemplateInitializationResponseBody {
     InitializationIsInProgress {},
     InitializationIsFinished {},
     InitializationIsFailed {
+        #[schema(value_type = Option<String>)]
         reason: Option<Arc<str>>,
     },
+    NetworkProfileWasNotFound {},
     UnexpectedError {
+        #[schema(value_type = String)]
         message: Box<str>,
     },
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/start-template-initialization",
+    tag = "templates",
+    request_body = StartTemplateInitializationRequestBody,
+    responses(
+        (status = OK, description = "initializationWasStarted, initializationIsInProgress, initializationIsFinished or initializationIsFailed", body = StartTemplateInitializationResponseBody),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = StartTemplateInitializationResponseBody),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = StartTemplateInitializationResponseBody),
+    )
+)]
 pub async fn start_template_initialization(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<StartTemplateInitializationRequestBody>,
@@ -45,6 +76,9 @@ pub async fn start_template_initialization(
             request_body.template_hash,
             Duration::from_millis(request_body.initialization_duration_ms),
             request_body.parent_template_db_name,
+            request_body.network_profile,
+            request_body.settings,
+            request_body.extensions,
         )
         .await;
 
@@ -71,6 +105,10 @@ pub async fn start_template_initialization(
             status_code: StatusCode::OK,
             body: StartTemplateInitializationResponseBody::InitializationIsFailed { reason },
         },
+        Ok(StartTemplateInitializationResult::NetworkProfileWasNotFound) => JsonResponse {
+            status_code: StatusCode::BAD_REQUEST,
+            body: StartTemplateInitializationResponseBody::NetworkProfileWasNotFound {},
+        },
         Err(err) => JsonResponse {
             status_code: StatusCode::INTERNAL_SERVER_ERROR,
             body: StartTemplateInitializationResponseBody::UnexpectedError {
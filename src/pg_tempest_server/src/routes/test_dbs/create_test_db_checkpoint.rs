@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::create_test_db_checkpoint::{
+        CreateTestDbCheckpointErrorResult, CreateTestDbCheckpointOkResult,
+    },
+    models::value_types::{
+        checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::json_response::JsonResponse;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTestDbCheckpointRequestBody {
+    #[schema(value_type = String)]
+    template_hash: TemplateHash,
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum CreateTestDbCheckpointResponseBody {
+    CheckpointWasCreated {
+        #[schema(value_type = String)]
+        checkpoint_id: CheckpointId,
+    },
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    TestDbHasConnections {},
+    UnexpectedError {
+        #[schema(value_type = String)]
+        message: Box<str>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/create-test-db-checkpoint",
+    tag = "test-dbs",
+    request_body = CreateTestDbCheckpointRequestBody,
+    responses(
+        (status = OK, description = "checkpointWasCreated", body = CreateTestDbCheckpointResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound or testDbWasNotFound", body = CreateTestDbCheckpointResponseBody),
+        (status = CONFLICT, description = "testDbIsNotUsed or testDbHasConnections", body = CreateTestDbCheckpointResponseBody),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = CreateTestDbCheckpointResponseBody),
+    )
+)]
+pub async fn create_test_db_checkpoint(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    Json(request_body): Json<CreateTestDbCheckpointRequestBody>,
+) -> JsonResponse<CreateTestDbCheckpointResponseBody> {
+    let result = tempest_core
+        .create_test_db_checkpoint(request_body.template_hash, request_body.test_db_id)
+        .await;
+
+    match result {
+        Ok(CreateTestDbCheckpointOkResult { checkpoint_id }) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: CreateTestDbCheckpointResponseBody::CheckpointWasCreated { checkpoint_id },
+        },
+        Err(CreateTestDbCheckpointErrorResult::TemplateWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: CreateTestDbCheckpointResponseBody::TemplateWasNotFound {},
+        },
+        Err(CreateTestDbCheckpointErrorResult::TestDbWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: CreateTestDbCheckpointResponseBody::TestDbWasNotFound {},
+        },
+        Err(CreateTestDbCheckpointErrorResult::TestDbIsNotUsed) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: CreateTestDbCheckpointResponseBody::TestDbIsNotUsed {},
+        },
+        Err(CreateTestDbCheckpointErrorResult::TestDbHasConnections) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: CreateTestDbCheckpointResponseBody::TestDbHasConnections {},
+        },
+        Err(CreateTestDbCheckpointErrorResult::Unknown { inner }) => JsonResponse {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: CreateTestDbCheckpointResponseBody::UnexpectedError {
+                message: inner.to_string().into(),
+            },
+        },
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::drop_retained_test_db::DropRetainedTestDbErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::json_response::JsonResponse;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DropRetainedTestDbRequestBody {
+    #[schema(value_type = String)]
+    template_hash: TemplateHash,
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum DropRetainedTestDbResponseBody {
+    TestDbWasDropped {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotRetained {},
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/drop-retained-test-db",
+    tag = "test-dbs",
+    request_body = DropRetainedTestDbRequestBody,
+    responses(
+        (status = OK, description = "testDbWasDropped", body = DropRetainedTestDbResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound or testDbWasNotFound", body = DropRetainedTestDbResponseBody),
+        (status = CONFLICT, description = "testDbIsNotRetained", body = DropRetainedTestDbResponseBody),
+    )
+)]
+pub async fn drop_retained_test_db(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    Json(request_body): Json<DropRetainedTestDbRequestBody>,
+) -> JsonResponse<DropRetainedTestDbResponseBody> {
+    let result = tempest_core
+        .drop_retained_test_db(request_body.template_hash, request_body.test_db_id)
+        .await;
+
+    match result {
+        Ok(()) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: DropRetainedTestDbResponseBody::TestDbWasDropped {},
+        },
+        Err(DropRetainedTestDbErrorResult::TemplateWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: DropRetainedTestDbResponseBody::TemplateWasNotFound {},
+        },
+        Err(DropRetainedTestDbErrorResult::TestDbWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: DropRetainedTestDbResponseBody::TestDbWasNotFound {},
+        },
+        Err(DropRetainedTestDbErrorResult::TestDbIsNotRetained) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: DropRetainedTestDbResponseBody::TestDbIsNotRetained {},
+        },
+    }
+}
@@ -8,27 +8,44 @@ use pg_tempest_core::{
     models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::dtos::json_response::JsonResponse;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ExtendTestDbUsageRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
+    #[schema(value_type = String)]
     test_db_id: TestDbId,
     additional_time_ms: u64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum ExtendTestDbUsageResponseBody {
-    UsageWasExtended { new_usage_deadline: DateTime<Utc> },
+    #[serde(rename_all = "camelCase")]
+    UsageWasExtended {
+        new_usage_deadline: DateTime<Utc>,
+    },
     TemplateWasNotFound {},
     TestDbWasNotFound {},
     TestDbIsNotUsed {},
     TestDbIsCorrupted {},
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/extend-test-db-usage",
+    tag = "test-dbs",
+    request_body = ExtendTestDbUsageRequestBody,
+    responses(
+        (status = OK, description = "usageWasExtended", body = ExtendTestDbUsageResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound or testDbWasNotFound", body = ExtendTestDbUsageResponseBody),
+        (status = BAD_REQUEST, description = "testDbIsNotUsed or testDbIsCorrupted", body = ExtendTestDbUsageResponseBody),
+    )
+)]
 pub async fn extend_test_db_usage(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<ExtendTestDbUsageRequestBody>,
@@ -1,44 +1,107 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::{Json, extract::State, http::StatusCode};
+use chrono::{DateTime, Utc};
 use pg_tempest_core::{
     PgTempestCore,
-    features::test_dbs::finish_test_db_usage::FinishTestDbUsageErrorResult,
+    features::test_dbs::finish_test_db_usage::{
+        FinishTestDbUsageErrorResult, FinishTestDbUsageOkResult, TestDbRetention,
+        TestDbUsageOutcome,
+    },
     models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::dtos::json_response::JsonResponse;
+use crate::dtos::{db_connection_options_dto::DbConnectionOptionsDto, json_response::JsonResponse};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FinishTestDbUsageRequestBody {
+    #[schema(value_type = String)]
     template_hash: TemplateHash,
+    #[schema(value_type = String)]
     test_db_id: TestDbId,
+    #[serde(default)]
+    outcome: TestDbUsageOutcomeDto,
+    // The test db is retained only if the outcome is failed
+    #[serde(default)]
+    retain_for_ms: Option<u64>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    network_profile: Option<Box<str>>,
 }
 
-#[derive(Serialize)]
+#[derive(Deserialize, ToSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TestDbUsageOutcomeDto {
+    #[default]
+    Succeeded,
+    Failed,
+}
+
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum FinishTestDbUsageResponseBody {
     TestDbWasReleased {},
+    #[serde(rename_all = "camelCase")]
+    TestDbWasRetained {
+        db_connection_options: DbConnectionOptionsDto,
+        retention_deadline: DateTime<Utc>,
+    },
     TemplateWasNotFound {},
     TestDbWasNotFound {},
     TestDbIsNotUsed {},
+    NetworkProfileWasNotFound {},
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/finish-test-db-usage",
+    tag = "test-dbs",
+    request_body = FinishTestDbUsageRequestBody,
+    responses(
+        (status = OK, description = "testDbWasReleased or testDbWasRetained", body = FinishTestDbUsageResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound or testDbWasNotFound", body = FinishTestDbUsageResponseBody),
+        (status = CONFLICT, description = "testDbIsNotUsed", body = FinishTestDbUsageResponseBody),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = FinishTestDbUsageResponseBody),
+    )
+)]
 pub async fn finish_test_db_usage(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<FinishTestDbUsageRequestBody>,
 ) -> JsonResponse<FinishTestDbUsageResponseBody> {
+    let retention = request_body
+        .retain_for_ms
+        .map(|retain_for_ms| TestDbRetention {
+            retention_duration: Duration::from_millis(retain_for_ms),
+            network_profile: request_body.network_profile,
+        });
+
     let result = tempest_core
-        .finish_test_db_usage(request_body.template_hash, request_body.test_db_id)
+        .finish_test_db_usage(
+            request_body.template_hash,
+            request_body.test_db_id,
+            request_body.outcome.into(),
+            retention,
+        )
         .await;
 
     match result {
-        Ok(_) => JsonResponse {
+        Ok(FinishTestDbUsageOkResult::TestDbWasReleased) => JsonResponse {
             status_code: StatusCode::OK,
             body: FinishTestDbUsageResponseBody::TestDbWasReleased {},
         },
+        Ok(FinishTestDbUsageOkResult::TestDbWasRetained {
+            connection_options,
+            retention_deadline,
+        }) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: FinishTestDbUsageResponseBody::TestDbWasRetained {
+                db_connection_options: connection_options.into(),
+                retention_deadline,
+            },
+        },
         Err(FinishTestDbUsageErrorResult::TemplateWasNotFound) => JsonResponse {
             status_code: StatusCode::NOT_FOUND,
             body: FinishTestDbUsageResponseBody::TemplateWasNotFound {},
@@ -51,5 +114,18 @@ pub async fn finish_test_db_usage(
             status_code: StatusCode::CONFLICT,
             body: FinishTestDbUsageResponseBody::TestDbIsNotUsed {},
         },
+        Err(FinishTestDbUsageErrorResult::NetworkProfileWasNotFound) => JsonResponse {
+            status_code: StatusCode::BAD_REQUEST,
+            body: FinishTestDbUsageResponseBody::NetworkProfileWasNotFound {},
+        },
+    }
+}
+
+impl From<TestDbUsageOutcomeDto> for TestDbUsageOutcome {
+    fn from(value: TestDbUsageOutcomeDto) -> Self {
+        match value {
+            TestDbUsageOutcomeDto::Succeeded => TestDbUsageOutcome::Succeeded,
+            TestDbUsageOutcomeDto::Failed => TestDbUsageOutcome::Failed,
+        }
     }
 }
@@ -8,31 +8,55 @@ use pg_tempest_core::{
     models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::dtos::{db_connection_options_dto::DbConnectionOptionsDto, json_response::JsonResponse};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTestDbRequestBody {
+    #[schema(value_type = String)]
     pub template_hash: TemplateHash,
     pub usage_duration_ms: u64,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub network_profile: Option<Box<str>>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum GetTestDbResponseBody {
+    // utoipa ignores `rename_all_fields`, so variants with multi-word fields repeat `rename_all`
+    // to keep the schema in sync with serde
+    #[serde(rename_all = "camelCase")]
     TestDbWasCreated {
+        #[schema(value_type = String)]
         test_db_id: TestDbId,
         db_connection_options: DbConnectionOptionsDto,
         usage_deadline: DateTime<Utc>,
     },
     TemplateWasNotFound {},
     TemplateIsNotInitialized {},
+    NetworkProfileWasNotFound {},
     UnknownError {
+        #[schema(value_type = String)]
         message: Box<str>,
     },
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/get-test-db",
+    tag = "test-dbs",
+    request_body = GetTestDbRequestBody,
+    responses(
+        (status = OK, description = "testDbWasCreated", body = GetTestDbResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = GetTestDbResponseBody),
+        (status = CONFLICT, description = "templateIsNotInitialized", body = GetTestDbResponseBody),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = GetTestDbResponseBody),
+        (status = INTERNAL_SERVER_ERROR, description = "unknownError", body = GetTestDbResponseBody),
+    )
+)]
 pub async fn get_test_db(
     State(tempest_core): State<Arc<PgTempestCore>>,
     Json(request_body): Json<GetTestDbRequestBody>,
@@ -41,6 +65,7 @@ pub async fn get_test_db(
         .get_test_db(
             request_body.template_hash,
             Duration::from_millis(request_body.usage_duration_ms),
+            request_body.network_profile,
         )
         .await;
 
@@ -61,6 +86,10 @@ pub async fn get_test_db(
             status_code: StatusCode::CONFLICT,
             body: GetTestDbResponseBody::TemplateIsNotInitialized {},
         },
+        Err(GetTestDbErrorResult::NetworkProfileWasNotFound) => JsonResponse {
+            status_code: StatusCode::BAD_REQUEST,
+            body: GetTestDbResponseBody::NetworkProfileWasNotFound {},
+        },
         Err(GetTestDbErrorResult::Unknown { inner }) => JsonResponse {
             status_code: StatusCode::INTERNAL_SERVER_ERROR,
             body: GetTestDbResponseBody::UnknownError {
@@ -1,21 +1,50 @@
+mod create_test_db_checkpoint;
+mod drop_retained_test_db;
 mod extend_test_db_usage;
 mod finish_test_db_usage;
 mod get_test_db;
+mod promote_test_db;
+mod restore_test_db_checkpoint;
 
 use std::sync::Arc;
 
 use axum::{Router, routing::post};
 use pg_tempest_core::PgTempestCore;
+use utoipa::OpenApi;
 
 use crate::routes::test_dbs::{
-    extend_test_db_usage::extend_test_db_usage, finish_test_db_usage::finish_test_db_usage,
-    get_test_db::get_test_db,
+    create_test_db_checkpoint::create_test_db_checkpoint,
+    drop_retained_test_db::drop_retained_test_db, extend_test_db_usage::extend_test_db_usage,
+    finish_test_db_usage::finish_test_db_usage, get_test_db::get_test_db,
+    promote_test_db::promote_test_db, restore_test_db_checkpoint::restore_test_db_checkpoint,
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_test_db::get_test_db,
+    extend_test_db_usage::extend_test_db_usage,
+    finish_test_db_usage::finish_test_db_usage,
+    drop_retained_test_db::drop_retained_test_db,
+    create_test_db_checkpoint::create_test_db_checkpoint,
+    restore_test_db_checkpoint::restore_test_db_checkpoint,
+    promote_test_db::promote_test_db,
+))]
+pub struct TestDbsApiDoc;
+
 pub fn create_test_dbs_router(tempest_core: Arc<PgTempestCore>) -> Router {
     Router::new()
         .route("/api/get-test-db", post(get_test_db))
         .route("/api/extend-test-db-usage", post(extend_test_db_usage))
         .route("/api/finish-test-db-usage", post(finish_test_db_usage))
+        .route("/api/drop-retained-test-db", post(drop_retained_test_db))
+        .route(
+            "/api/create-test-db-checkpoint",
+            post(create_test_db_checkpoint),
+        )
+        .route(
+            "/api/restore-test-db-checkpoint",
+            post(restore_test_db_checkpoint),
+        )
+        .route("/api/promote-test-db", post(promote_test_db))
         .with_state(tempest_core)
 }
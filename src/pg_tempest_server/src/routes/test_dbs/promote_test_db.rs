@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::promote_test_db::PromoteTestDbErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::json_response::JsonResponse;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PromoteTestDbRequestBody {
+    #[schema(value_type = String)]
+    template_hash: TemplateHash,
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+    #[schema(value_type = String)]
+    new_template_hash: TemplateHash,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum PromoteTestDbResponseBody {
+    TestDbWasPromoted {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    TestDbHasConnections {},
+    NewTemplateAlreadyExists {},
+    UnexpectedError {
+        #[schema(value_type = String)]
+        message: Box<str>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/promote-test-db",
+    tag = "test-dbs",
+    request_body = PromoteTestDbRequestBody,
+    responses(
+        (status = OK, description = "testDbWasPromoted", body = PromoteTestDbResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound or testDbWasNotFound", body = PromoteTestDbResponseBody),
+        (status = CONFLICT, description = "testDbIsNotUsed, testDbHasConnections or newTemplateAlreadyExists", body = PromoteTestDbResponseBody),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = PromoteTestDbResponseBody),
+    )
+)]
+pub async fn promote_test_db(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    Json(request_body): Json<PromoteTestDbRequestBody>,
+) -> JsonResponse<PromoteTestDbResponseBody> {
+    let result = tempest_core
+        .promote_test_db(
+            request_body.template_hash,
+            request_body.test_db_id,
+            request_body.new_template_hash,
+        )
+        .await;
+
+    match result {
+        Ok(()) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: PromoteTestDbResponseBody::TestDbWasPromoted {},
+        },
+        Err(PromoteTestDbErrorResult::TemplateWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: PromoteTestDbResponseBody::TemplateWasNotFound {},
+        },
+        Err(PromoteTestDbErrorResult::TestDbWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: PromoteTestDbResponseBody::TestDbWasNotFound {},
+        },
+        Err(PromoteTestDbErrorResult::TestDbIsNotUsed) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: PromoteTestDbResponseBody::TestDbIsNotUsed {},
+        },
+        Err(PromoteTestDbErrorResult::TestDbHasConnections) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: PromoteTestDbResponseBody::TestDbHasConnections {},
+        },
+        Err(PromoteTestDbErrorResult::NewTemplateAlreadyExists) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: PromoteTestDbResponseBody::NewTemplateAlreadyExists {},
+        },
+        Err(PromoteTestDbErrorResult::Unknown { inner }) => JsonResponse {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: PromoteTestDbResponseBody::UnexpectedError {
+                message: inner.to_string().into(),
+            },
+        },
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::restore_test_db_checkpoint::RestoreTestDbCheckpointErrorResult,
+    models::value_types::{
+        checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::json_response::JsonResponse;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTestDbCheckpointRequestBody {
+    #[schema(value_type = String)]
+    template_hash: TemplateHash,
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+    #[schema(value_type = String)]
+    checkpoint_id: CheckpointId,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum RestoreTestDbCheckpointResponseBody {
+    TestDbWasRestored {},
+    TemplateWasNotFound {},
+    TestDbWasNotFound {},
+    TestDbIsNotUsed {},
+    CheckpointWasNotFound {},
+    TestDbHasConnections {},
+    UnexpectedError {
+        #[schema(value_type = String)]
+        message: Box<str>,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/restore-test-db-checkpoint",
+    tag = "test-dbs",
+    request_body = RestoreTestDbCheckpointRequestBody,
+    responses(
+        (status = OK, description = "testDbWasRestored", body = RestoreTestDbCheckpointResponseBody),
+        (status = NOT_FOUND, description = "templateWasNotFound, testDbWasNotFound or checkpointWasNotFound", body = RestoreTestDbCheckpointResponseBody),
+        (status = CONFLICT, description = "testDbIsNotUsed or testDbHasConnections", body = RestoreTestDbCheckpointResponseBody),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = RestoreTestDbCheckpointResponseBody),
+    )
+)]
+pub async fn restore_test_db_checkpoint(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    Json(request_body): Json<RestoreTestDbCheckpointRequestBody>,
+) -> JsonResponse<RestoreTestDbCheckpointResponseBody> {
+    let result = tempest_core
+        .restore_test_db_checkpoint(
+            request_body.template_hash,
+            request_body.test_db_id,
+            request_body.checkpoint_id,
+        )
+        .await;
+
+    match result {
+        Ok(()) => JsonResponse {
+            status_code: StatusCode::OK,
+            body: RestoreTestDbCheckpointResponseBody::TestDbWasRestored {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::TemplateWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: RestoreTestDbCheckpointResponseBody::TemplateWasNotFound {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::TestDbWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: RestoreTestDbCheckpointResponseBody::TestDbWasNotFound {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::CheckpointWasNotFound) => JsonResponse {
+            status_code: StatusCode::NOT_FOUND,
+            body: RestoreTestDbCheckpointResponseBody::CheckpointWasNotFound {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: RestoreTestDbCheckpointResponseBody::TestDbIsNotUsed {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::TestDbHasConnections) => JsonResponse {
+            status_code: StatusCode::CONFLICT,
+            body: RestoreTestDbCheckpointResponseBody::TestDbHasConnections {},
+        },
+        Err(RestoreTestDbCheckpointErrorResult::Unknown { inner }) => JsonResponse {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: RestoreTestDbCheckpointResponseBody::UnexpectedError {
+                message: inner.to_string().into(),
+            },
+        },
+    }
+}
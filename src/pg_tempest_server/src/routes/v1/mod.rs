@@ -0,0 +1,495 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    http::StatusCode,
+    middleware::from_fn_with_state,
+    routing::{delete, get, post, put},
+};
+use pg_tempest_core::PgTempestCore;
+use utoipa::OpenApi;
+
+use crate::admin_auth_layer::admin_auth_layer;
+use crate::dtos::api_error::ApiError;
+use crate::routes::v1::templates::{
+    complete_template_initialization::complete_template_initialization,
+    extend_template_initialization::extend_template_initialization, get_template::get_template,
+    get_templates::get_templates, import_template::import_template,
+    start_template_initialization::start_template_initialization,
+};
+use crate::routes::v1::test_dbs::{
+    complete_test_db_usage::complete_test_db_usage, create_test_db::create_test_db,
+    create_test_db_checkpoint::create_test_db_checkpoint,
+    drop_retained_test_db::drop_retained_test_db, extend_test_db_usage::extend_test_db_usage,
+    finish_test_db_usage::finish_test_db_usage, promote_test_db::promote_test_db,
+    restore_test_db_checkpoint::restore_test_db_checkpoint,
+};
+
+pub mod templates;
+mod test_dbs;
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    templates::get_templates::get_templates,
+    templates::get_template::get_template,
+    templates::start_template_initialization::start_template_initialization,
+    templates::extend_template_initialization::extend_template_initialization,
+    templates::complete_template_initialization::complete_template_initialization,
+    templates::import_template::import_template,
+    test_dbs::create_test_db::create_test_db,
+    test_dbs::extend_test_db_usage::extend_test_db_usage,
+    test_dbs::finish_test_db_usage::finish_test_db_usage,
+    test_dbs::complete_test_db_usage::complete_test_db_usage,
+    test_dbs::drop_retained_test_db::drop_retained_test_db,
+    test_dbs::create_test_db_checkpoint::create_test_db_checkpoint,
+    test_dbs::restore_test_db_checkpoint::restore_test_db_checkpoint,
+    test_dbs::promote_test_db::promote_test_db,
+))]
+pub struct V1ApiDoc;
+
+pub fn create_v1_router(
+    tempest_core: Arc<PgTempestCore>,
+    max_import_body_size: usize,
+    admin_token: Option<Arc<str>>,
+) -> Router {
+    let router = Router::new()
+        .route("/api/v1/templates", get(get_templates))
+        .route("/api/v1/templates/{template_hash}", get(get_template))
+        .route(
+            "/api/v1/templates/{template_hash}/initialization",
+            put(start_template_initialization),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/initialization/extensions",
+            post(extend_template_initialization),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/initialization/result",
+            put(complete_template_initialization),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs",
+            post(create_test_db),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage/extensions",
+            post(extend_test_db_usage),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage",
+            delete(finish_test_db_usage),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage/result",
+            put(complete_test_db_usage),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}",
+            delete(drop_retained_test_db),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/checkpoints",
+            post(create_test_db_checkpoint),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/checkpoints/{checkpoint_id}/restorations",
+            post(restore_test_db_checkpoint),
+        )
+        .route(
+            "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/promotions",
+            post(promote_test_db),
+        );
+
+    // Imports execute client dumps in the dbms, so like admin routes
+    // they are served only when an admin token is configured
+    let Some(admin_token) = admin_token else {
+        return router.with_state(tempest_core);
+    };
+
+    router
+        .route(
+            "/api/v1/templates/{template_hash}/import",
+            put(import_template)
+                .layer(DefaultBodyLimit::max(max_import_body_size))
+                .layer(from_fn_with_state(admin_token, admin_auth_layer)),
+        )
+        .with_state(tempest_core)
+}
+
+pub fn network_profile_was_not_found(network_profile: &str) -> ApiError {
+    ApiError::new(
+        StatusCode::BAD_REQUEST,
+        "networkProfileWasNotFound",
+        format!("Network profile {network_profile} was not found"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Method, Request, StatusCode, header::AUTHORIZATION, header::CONTENT_TYPE},
+    };
+    use pg_tempest_testkit::{TestContext, TestContextBuilder};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::routes::v1::create_v1_router;
+
+    const TEMPLATE_HASH: &str = "0123456789abcdef0123456789abcdef";
+    const ADMIN_TOKEN: &str = "admin-token";
+
+    fn router(context: &TestContext, admin_token: Option<&str>) -> Router {
+        create_v1_router(
+            context.tempest_core.clone(),
+            1024 * 1024,
+            admin_token.map(Arc::from),
+        )
+    }
+
+    async fn send(
+        router: &Router,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+        bearer_token: Option<&str>,
+    ) -> (StatusCode, Value) {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(bearer_token) = bearer_token {
+            request = request.header(AUTHORIZATION, format!("Bearer {bearer_token}"));
+        }
+        let request = match body {
+            Some(body) => request
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status_code = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = match body.is_empty() {
+            true => Value::Null,
+            false => serde_json::from_slice(&body).unwrap(),
+        };
+
+        (status_code, body)
+    }
+
+    async fn start_initialization(router: &Router) -> (StatusCode, Value) {
+        send(
+            router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization"),
+            Some(json!({ "initializationDurationMs": 60_000 })),
+            None,
+        )
+        .await
+    }
+
+    async fn initialize_template(router: &Router) {
+        start_initialization(router).await;
+        let (status_code, _) = send(
+            router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization/result"),
+            Some(json!({ "status": "finished" })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn initialization_is_started_extended_and_finished() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+
+        let (status_code, body) = start_initialization(&router).await;
+        assert_eq!(status_code, StatusCode::CREATED, "{body}");
+        assert!(body["databaseConnectionOptions"].is_object(), "{body}");
+        assert!(body["initializationDeadline"].is_string(), "{body}");
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization/extensions"),
+            Some(json!({ "additionalTimeMs": 1000 })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::OK, "{body}");
+        assert!(body["newInitializationDeadline"].is_string(), "{body}");
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization/result"),
+            Some(json!({ "status": "finished" })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NO_CONTENT, "{body}");
+
+        let (status_code, body) = start_initialization(&router).await;
+        assert_eq!(status_code, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "initializationIsFinished");
+    }
+
+    #[tokio::test]
+    async fn failed_initialization_is_reported_with_its_reason() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+        start_initialization(&router).await;
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization/result"),
+            Some(json!({ "status": "failed", "reason": "migration failed" })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NO_CONTENT, "{body}");
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization/extensions"),
+            Some(json!({ "additionalTimeMs": 1000 })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "initializationIsFailed");
+        assert!(
+            body["message"]
+                .as_str()
+                .unwrap()
+                .contains("migration failed"),
+            "{body}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_db_usage_is_extended_and_finished() {
+        let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+        let router = router(&context, None);
+        initialize_template(&router).await;
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/test-dbs"),
+            Some(json!({ "usageDurationMs": 60_000 })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::CREATED, "{body}");
+        assert!(body["dbConnectionOptions"].is_object(), "{body}");
+        assert!(body["usageDeadline"].is_string(), "{body}");
+        let test_db_id = body["testDbId"].as_str().unwrap().to_string();
+        let usage_uri = format!("/api/v1/templates/{TEMPLATE_HASH}/test-dbs/{test_db_id}/usage");
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("{usage_uri}/extensions"),
+            Some(json!({ "additionalTimeMs": 1000 })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::OK, "{body}");
+        assert!(body["newUsageDeadline"].is_string(), "{body}");
+
+        let (status_code, body) = send(&router, Method::DELETE, &usage_uri, None, None).await;
+        assert_eq!(status_code, StatusCode::NO_CONTENT, "{body}");
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("{usage_uri}/extensions"),
+            Some(json!({ "additionalTimeMs": 1000 })),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "testDbIsNotUsed");
+    }
+
+    #[tokio::test]
+    async fn missing_resources_are_not_found() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+
+        let (status_code, body) = send(
+            &router,
+            Method::GET,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}"),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "templateWasNotFound");
+
+        initialize_template(&router).await;
+
+        let (status_code, body) = send(
+            &router,
+            Method::DELETE,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/test-dbs/0001/usage"),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "testDbWasNotFound");
+    }
+
+    #[tokio::test]
+    async fn test_db_of_uninitialized_template_is_conflict() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+        start_initialization(&router).await;
+
+        let (status_code, body) = send(
+            &router,
+            Method::POST,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/test-dbs"),
+            Some(json!({ "usageDurationMs": 60_000 })),
+            None,
+        )
+        .await;
+
+        assert_eq!(status_code, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "templateIsNotInitialized");
+    }
+
+    #[tokio::test]
+    async fn unknown_network_profile_is_bad_request() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization"),
+            Some(json!({ "initializationDurationMs": 60_000, "networkProfile": "unknown" })),
+            None,
+        )
+        .await;
+
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "networkProfileWasNotFound");
+    }
+
+    #[tokio::test]
+    async fn rejected_requests_are_reported_as_api_errors() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+
+        let (status_code, body) = send(
+            &router,
+            Method::GET,
+            "/api/v1/templates/not-a-hash",
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalidPath");
+        assert!(body["message"].is_string(), "{body}");
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/initialization"),
+            Some(json!({})),
+            None,
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "invalidBody");
+    }
+
+    #[tokio::test]
+    async fn import_requires_admin_token() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, Some(ADMIN_TOKEN));
+        let import_uri = format!("/api/v1/templates/{TEMPLATE_HASH}/import");
+        let import_body = json!({
+            "dump": { "sql": { "script": "create table users (id bigint);" } },
+            "initializationDurationMs": 60_000,
+        });
+
+        for bearer_token in [None, Some("invalid-token")] {
+            let (status_code, body) = send(
+                &router,
+                Method::PUT,
+                &import_uri,
+                Some(import_body.clone()),
+                bearer_token,
+            )
+            .await;
+            assert_eq!(status_code, StatusCode::UNAUTHORIZED);
+            assert_eq!(body["code"], "adminTokenIsInvalid");
+        }
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &import_uri,
+            Some(import_body),
+            Some(ADMIN_TOKEN),
+        )
+        .await;
+        assert_eq!(status_code, StatusCode::NO_CONTENT, "{body}");
+    }
+
+    #[tokio::test]
+    async fn import_isnt_served_without_configured_admin_token() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, None);
+
+        let (status_code, _) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/import"),
+            Some(json!({
+                "dump": { "sql": { "script": "create table users (id bigint);" } },
+                "initializationDurationMs": 60_000,
+            })),
+            Some(ADMIN_TOKEN),
+        )
+        .await;
+
+        assert_eq!(status_code, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn archive_import_without_archive_dir_is_bad_request() {
+        let context = TestContextBuilder::new().start().await;
+        let router = router(&context, Some(ADMIN_TOKEN));
+
+        let (status_code, body) = send(
+            &router,
+            Method::PUT,
+            &format!("/api/v1/templates/{TEMPLATE_HASH}/import"),
+            Some(json!({
+                "dump": { "archive": { "path": "template.dump" } },
+                "initializationDurationMs": 60_000,
+            })),
+            Some(ADMIN_TOKEN),
+        )
+        .await;
+
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "archiveImportIsDisabled");
+    }
+}
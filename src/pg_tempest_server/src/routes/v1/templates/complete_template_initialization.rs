@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::{
+        fail_template_initialization::FailTemplateInitializationError,
+        finish_template_initialization::FinishTemplateInitializationErrorResult,
+    },
+    models::value_types::template_hash::TemplateHash,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::dtos::api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath};
+use crate::routes::v1::templates::{
+    initialization_is_failed, initialization_is_finished, initialization_is_not_started,
+    template_was_not_found,
+};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum TemplateInitializationResultDto {
+    Finished,
+    Failed {
+        #[schema(value_type = Option<String>)]
+        reason: Option<Arc<str>>,
+    },
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/templates/{template_hash}/initialization/result",
+    tag = "v1",
+    params(("template_hash" = String, Path)),
+    request_body = TemplateInitializationResultDto,
+    responses(
+        (status = NO_CONTENT),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ApiErrorDto),
+        (
+            status = CONFLICT,
+            description = "initializationIsNotStarted, initializationIsFinished or initializationIsFailed",
+            body = ApiErrorDto
+        ),
+    )
+)]
+pub async fn complete_template_initialization(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiJson(request_body): ApiJson<TemplateInitializationResultDto>,
+) -> Result<StatusCode, ApiError> {
+    match request_body {
+        TemplateInitializationResultDto::Finished => {
+            let result = tempest_core
+                .finish_template_initialization(template_hash)
+                .await;
+
+            match result {
+                Ok(()) => Ok(StatusCode::NO_CONTENT),
+                Err(FinishTemplateInitializationErrorResult::TemplateWasNotFound) => {
+                    Err(template_was_not_found(template_hash))
+                }
+                Err(FinishTemplateInitializationErrorResult::InitializationIsFailed { reason }) => {
+                    Err(initialization_is_failed(template_hash, reason))
+                }
+                Err(FinishTemplateInitializationErrorResult::InitializationIsNotStarted) => {
+                    Err(initialization_is_not_started(template_hash))
+                }
+            }
+        }
+        TemplateInitializationResultDto::Failed { reason } => {
+            let result = tempest_core
+                .fail_template_initialization(template_hash, reason)
+                .await;
+
+            match result {
+                Ok(()) => Ok(StatusCode::NO_CONTENT),
+                Err(FailTemplateInitializationError::TemplateWasNotFound { .. }) => {
+                    Err(template_was_not_found(template_hash))
+                }
+                Err(FailTemplateInitializationError::InitializationIsFinished) => {
+                    Err(initialization_is_finished(template_hash))
+                }
+                Err(FailTemplateInitializationError::InitializationIsNotStarted) => {
+                    Err(initialization_is_not_started(template_hash))
+                }
+            }
+        }
+    }
+}
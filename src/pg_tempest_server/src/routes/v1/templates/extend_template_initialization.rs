@@ -0,0 +1,83 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode};
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::extend_template_initialization::ExtendTemplateInitializationErrorResult,
+    models::value_types::template_hash::TemplateHash,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    json_response::JsonResponse,
+};
+use crate::routes::v1::templates::{
+    initialization_is_failed, initialization_is_finished, initialization_is_not_started,
+    template_was_not_found,
+};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::ExtendTemplateInitializationRequestBody)]
+pub struct ExtendTemplateInitializationRequestBody {
+    additional_time_ms: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializationWasExtendedDto {
+    new_initialization_deadline: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/initialization/extensions",
+    tag = "v1",
+    params(("template_hash" = String, Path)),
+    request_body = ExtendTemplateInitializationRequestBody,
+    responses(
+        (status = OK, body = InitializationWasExtendedDto),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ApiErrorDto),
+        (
+            status = CONFLICT,
+            description = "initializationIsNotStarted, initializationIsFinished or initializationIsFailed",
+            body = ApiErrorDto
+        ),
+    )
+)]
+pub async fn extend_template_initialization(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiJson(request_body): ApiJson<ExtendTemplateInitializationRequestBody>,
+) -> Result<JsonResponse<InitializationWasExtendedDto>, ApiError> {
+    let result = tempest_core
+        .extend_template_initialization(
+            template_hash,
+            Duration::from_millis(request_body.additional_time_ms),
+        )
+        .await;
+
+    match result {
+        Ok(result) => Ok(JsonResponse {
+            status_code: StatusCode::OK,
+            body: InitializationWasExtendedDto {
+                new_initialization_deadline: result.new_initialization_deadline,
+            },
+        }),
+        Err(ExtendTemplateInitializationErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsFinished) => {
+            Err(initialization_is_finished(template_hash))
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsFailed { reason }) => {
+            Err(initialization_is_failed(template_hash, reason))
+        }
+        Err(ExtendTemplateInitializationErrorResult::InitializationIsNotStarted) => {
+            Err(initialization_is_not_started(template_hash))
+        }
+    }
+}
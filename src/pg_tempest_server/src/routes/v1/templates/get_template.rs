@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{PgTempestCore, models::value_types::template_hash::TemplateHash};
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiPath},
+    json_response::JsonResponse,
+    template_dto::TemplateDto,
+};
+use crate::routes::v1::templates::template_was_not_found;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/templates/{template_hash}",
+    tag = "v1",
+    params(("template_hash" = String, Path)),
+    responses(
+        (status = OK, body = TemplateDto),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ApiErrorDto),
+    )
+)]
+pub async fn get_template(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+) -> Result<JsonResponse<TemplateDto>, ApiError> {
+    match tempest_core.get_template(template_hash).await {
+        Some(template) => Ok(JsonResponse {
+            status_code: StatusCode::OK,
+            body: template.into(),
+        }),
+        None => Err(template_was_not_found(template_hash)),
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::PgTempestCore;
+
+use crate::dtos::{json_response::JsonResponse, template_dto::TemplateDto};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/templates",
+    tag = "v1",
+    responses(
+        (status = OK, body = Vec<TemplateDto>),
+    )
+)]
+pub async fn get_templates(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+) -> JsonResponse<Vec<TemplateDto>> {
+    let templates = tempest_core.get_templates().await;
+
+    JsonResponse {
+        status_code: StatusCode::OK,
+        body: templates.into_iter().map(TemplateDto::from).collect(),
+    }
+}
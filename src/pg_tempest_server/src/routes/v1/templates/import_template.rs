@@ -0,0 +1,90 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::import_template::ImportTemplateResult,
+    models::value_types::{pg_identifier::PgIdentifier, template_hash::TemplateHash},
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    template_dump_dto::TemplateDumpDto,
+};
+use crate::routes::v1::templates::{initialization_is_failed, initialization_is_finished};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::ImportTemplateRequestBody)]
+pub struct ImportTemplateRequestBody {
+    dump: TemplateDumpDto,
+    initialization_duration_ms: u64,
+    #[schema(value_type = Option<String>)]
+    parent_template_db_name: Option<PgIdentifier>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/templates/{template_hash}/import",
+    tag = "v1",
+    security(("adminToken" = [])),
+    params(("template_hash" = String, Path)),
+    request_body = ImportTemplateRequestBody,
+    responses(
+        (status = NO_CONTENT),
+        (
+            status = BAD_REQUEST,
+            description = "archiveImportIsDisabled or archivePathIsInvalid",
+            body = ApiErrorDto
+        ),
+        (status = UNAUTHORIZED, description = "adminTokenIsInvalid", body = ApiErrorDto),
+        (
+            status = CONFLICT,
+            description = "initializationIsInProgress, initializationIsFinished or initializationIsFailed",
+            body = ApiErrorDto
+        ),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn import_template(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiJson(request_body): ApiJson<ImportTemplateRequestBody>,
+) -> Result<StatusCode, ApiError> {
+    let result = tempest_core
+        .import_template(
+            template_hash,
+            request_body.dump.into(),
+            Duration::from_millis(request_body.initialization_duration_ms),
+            request_body.parent_template_db_name,
+        )
+        .await;
+
+    match result {
+        Ok(ImportTemplateResult::TemplateWasImported) => Ok(StatusCode::NO_CONTENT),
+        Ok(ImportTemplateResult::InitializationIsInProgress) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "initializationIsInProgress",
+            format!("Template {template_hash} is being initialized by another client"),
+        )),
+        Ok(ImportTemplateResult::InitializationIsFinished) => {
+            Err(initialization_is_finished(template_hash))
+        }
+        Ok(ImportTemplateResult::InitializationIsFailed { reason }) => {
+            Err(initialization_is_failed(template_hash, reason))
+        }
+        Ok(ImportTemplateResult::ArchiveImportIsDisabled) => Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "archiveImportIsDisabled",
+            "Archive dir of template imports is not configured",
+        )),
+        Ok(ImportTemplateResult::ArchivePathIsInvalid) => Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "archivePathIsInvalid",
+            "Archive path must be relative to the archive dir of template imports",
+        )),
+        Err(err) => Err(ApiError::unexpected(err.to_string())),
+    }
+}
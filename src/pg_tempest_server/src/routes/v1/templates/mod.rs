@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use pg_tempest_core::models::value_types::template_hash::TemplateHash;
+
+use crate::dtos::api_error::ApiError;
+
+pub mod complete_template_initialization;
+pub mod extend_template_initialization;
+pub mod get_template;
+pub mod get_templates;
+pub mod import_template;
+pub mod start_template_initialization;
+
+pub fn template_was_not_found(template_hash: TemplateHash) -> ApiError {
+    ApiError::new(
+        StatusCode::NOT_FOUND,
+        "templateWasNotFound",
+        format!("Template {template_hash} was not found"),
+    )
+}
+
+pub fn initialization_is_not_started(template_hash: TemplateHash) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "initializationIsNotStarted",
+        format!("Template {template_hash} initialization is not started"),
+    )
+}
+
+pub fn initialization_is_finished(template_hash: TemplateHash) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "initializationIsFinished",
+        format!("Template {template_hash} initialization is already finished"),
+    )
+}
+
+pub fn initialization_is_failed(template_hash: TemplateHash, reason: Option<Arc<str>>) -> ApiError {
+    let message = match reason {
+        Some(reason) => format!("Template {template_hash} initialization is failed: {reason}"),
+        None => format!("Template {template_hash} initialization is failed"),
+    };
+
+    ApiError::new(StatusCode::CONFLICT, "initializationIsFailed", message)
+}
+
+pub fn initialization_is_not_finished(template_hash: TemplateHash) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "initializationIsNotFinished",
+        format!("Template {template_hash} initialization is not finished"),
+    )
+}
@@ -0,0 +1,115 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode};
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::start_template_initialization::StartTemplateInitializationResult,
+    models::{
+        db_settings::DbSettings,
+        value_types::{
+            extension_name::ExtensionName, pg_identifier::PgIdentifier, template_hash::TemplateHash,
+        },
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    db_connection_options_dto::DbConnectionOptionsDto,
+    json_response::JsonResponse,
+};
+use crate::routes::v1::{network_profile_was_not_found, templates::initialization_is_failed};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::StartTemplateInitializationRequestBody)]
+pub struct StartTemplateInitializationRequestBody {
+    initialization_duration_ms: u64,
+    #[schema(value_type = Option<String>)]
+    parent_template_db_name: Option<PgIdentifier>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    network_profile: Option<Box<str>>,
+    // Parameters of `ALTER DATABASE ... SET` for the template db and its test dbs
+    #[serde(default)]
+    #[schema(value_type = Option<BTreeMap<String, String>>)]
+    settings: DbSettings,
+    // Created by the server in the template db, e.g. pgcrypto or uuid-ossp
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>)]
+    extensions: Vec<ExtensionName>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializationWasStartedDto {
+    database_connection_options: DbConnectionOptionsDto,
+    initialization_deadline: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/templates/{template_hash}/initialization",
+    tag = "v1",
+    params(("template_hash" = String, Path)),
+    request_body = StartTemplateInitializationRequestBody,
+    responses(
+        (status = CREATED, body = InitializationWasStartedDto),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = ApiErrorDto),
+        (
+            status = CONFLICT,
+            description = "initializationIsInProgress, initializationIsFinished or initializationIsFailed",
+            body = ApiErrorDto
+        ),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn start_template_initialization(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiJson(request_body): ApiJson<StartTemplateInitializationRequestBody>,
+) -> Result<JsonResponse<InitializationWasStartedDto>, ApiError> {
+    let network_profile = request_body.network_profile;
+    let result = tempest_core
+        .start_template_initialization(
+            template_hash,
+            Duration::from_millis(request_body.initialization_duration_ms),
+            request_body.parent_template_db_name,
+            network_profile.clone(),
+            request_body.settings,
+            request_body.extensions,
+        )
+        .await;
+
+    match result {
+        Ok(StartTemplateInitializationResult::InitializationWasStarted {
+            database_connection_options,
+            initialization_deadline,
+        }) => Ok(JsonResponse {
+            status_code: StatusCode::CREATED,
+            body: InitializationWasStartedDto {
+                database_connection_options: database_connection_options.into(),
+                initialization_deadline,
+            },
+        }),
+        Ok(StartTemplateInitializationResult::InitializationIsInProgress) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "initializationIsInProgress",
+            format!("Template {template_hash} is being initialized by another client"),
+        )),
+        Ok(StartTemplateInitializationResult::InitializationIsFinished) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "initializationIsFinished",
+            format!("Template {template_hash} is already initialized"),
+        )),
+        Ok(StartTemplateInitializationResult::InitializationIsFailed { reason }) => {
+            Err(initialization_is_failed(template_hash, reason))
+        }
+        Ok(StartTemplateInitializationResult::NetworkProfileWasNotFound) => Err(
+            network_profile_was_not_found(network_profile.as_deref().unwrap_or_default()),
+        ),
+        Err(err) => Err(ApiError::unexpected(err.to_string())),
+    }
+}
@@ -0,0 +1,126 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::finish_test_db_usage::{
+        FinishTestDbUsageErrorResult, FinishTestDbUsageOkResult, TestDbRetention,
+        TestDbUsageOutcome,
+    },
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    db_connection_options_dto::DbConnectionOptionsDto,
+    json_response::JsonResponse,
+};
+use crate::routes::v1::network_profile_was_not_found;
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{test_db_is_not_used, test_db_was_not_found};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum TestDbUsageResultDto {
+    Succeeded,
+    // The test db is retained only if `retainForMs` is set
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        #[serde(default)]
+        retain_for_ms: Option<u64>,
+        #[serde(default)]
+        #[schema(value_type = Option<String>)]
+        network_profile: Option<Box<str>>,
+    },
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TestDbWasRetainedDto {
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+    db_connection_options: DbConnectionOptionsDto,
+    retention_deadline: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage/result",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    request_body = TestDbUsageResultDto,
+    responses(
+        (status = NO_CONTENT, description = "Test db was released"),
+        (status = OK, description = "Test db was retained", body = TestDbWasRetainedDto),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = ApiErrorDto),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (status = CONFLICT, description = "testDbIsNotUsed", body = ApiErrorDto),
+    )
+)]
+pub async fn complete_test_db_usage(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+    ApiJson(request_body): ApiJson<TestDbUsageResultDto>,
+) -> Result<Response, ApiError> {
+    let (outcome, retention) = match request_body {
+        TestDbUsageResultDto::Succeeded => (TestDbUsageOutcome::Succeeded, None),
+        TestDbUsageResultDto::Failed {
+            retain_for_ms,
+            network_profile,
+        } => (
+            TestDbUsageOutcome::Failed,
+            retain_for_ms.map(|retain_for_ms| TestDbRetention {
+                retention_duration: Duration::from_millis(retain_for_ms),
+                network_profile,
+            }),
+        ),
+    };
+    let network_profile = retention
+        .as_ref()
+        .and_then(|retention| retention.network_profile.clone());
+
+    let result = tempest_core
+        .finish_test_db_usage(template_hash, test_db_id, outcome, retention)
+        .await;
+
+    match result {
+        Ok(FinishTestDbUsageOkResult::TestDbWasReleased) => {
+            Ok(StatusCode::NO_CONTENT.into_response())
+        }
+        Ok(FinishTestDbUsageOkResult::TestDbWasRetained {
+            connection_options,
+            retention_deadline,
+        }) => Ok(JsonResponse {
+            status_code: StatusCode::OK,
+            body: TestDbWasRetainedDto {
+                test_db_id,
+                db_connection_options: connection_options.into(),
+                retention_deadline,
+            },
+        }
+        .into_response()),
+        Err(FinishTestDbUsageErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        Err(FinishTestDbUsageErrorResult::NetworkProfileWasNotFound) => Err(
+            network_profile_was_not_found(network_profile.as_deref().unwrap_or_default()),
+        ),
+    }
+}
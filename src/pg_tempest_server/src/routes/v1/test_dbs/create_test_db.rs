@@ -0,0 +1,91 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode};
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::get_test_db::GetTestDbErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    db_connection_options_dto::DbConnectionOptionsDto,
+    json_response::JsonResponse,
+};
+use crate::routes::v1::{network_profile_was_not_found, templates::template_was_not_found};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::CreateTestDbRequestBody)]
+pub struct CreateTestDbRequestBody {
+    usage_duration_ms: u64,
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    network_profile: Option<Box<str>>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TestDbWasCreatedDto {
+    #[schema(value_type = String)]
+    test_db_id: TestDbId,
+    db_connection_options: DbConnectionOptionsDto,
+    usage_deadline: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/test-dbs",
+    tag = "v1",
+    params(("template_hash" = String, Path)),
+    request_body = CreateTestDbRequestBody,
+    responses(
+        (status = CREATED, body = TestDbWasCreatedDto),
+        (status = BAD_REQUEST, description = "networkProfileWasNotFound", body = ApiErrorDto),
+        (status = NOT_FOUND, description = "templateWasNotFound", body = ApiErrorDto),
+        (status = CONFLICT, description = "templateIsNotInitialized", body = ApiErrorDto),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn create_test_db(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath(template_hash): ApiPath<TemplateHash>,
+    ApiJson(request_body): ApiJson<CreateTestDbRequestBody>,
+) -> Result<JsonResponse<TestDbWasCreatedDto>, ApiError> {
+    let network_profile = request_body.network_profile;
+    let result = tempest_core
+        .get_test_db(
+            template_hash,
+            Duration::from_millis(request_body.usage_duration_ms),
+            network_profile.clone(),
+        )
+        .await;
+
+    match result {
+        Ok(result) => Ok(JsonResponse {
+            status_code: StatusCode::CREATED,
+            body: TestDbWasCreatedDto {
+                test_db_id: result.test_db_id,
+                db_connection_options: result.connection_options.into(),
+                usage_deadline: result.usage_deadline,
+            },
+        }),
+        Err(GetTestDbErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(GetTestDbErrorResult::TemplateIsNotInitialized) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "templateIsNotInitialized",
+            format!("Template {template_hash} is not initialized"),
+        )),
+        Err(GetTestDbErrorResult::NetworkProfileWasNotFound) => Err(network_profile_was_not_found(
+            network_profile.as_deref().unwrap_or_default(),
+        )),
+        Err(GetTestDbErrorResult::Unknown { inner }) => {
+            Err(ApiError::unexpected(inner.to_string()))
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::create_test_db_checkpoint::CreateTestDbCheckpointErrorResult,
+    models::value_types::{
+        checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+    },
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiPath},
+    json_response::JsonResponse,
+};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{
+    test_db_has_connections, test_db_is_not_used, test_db_was_not_found,
+};
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointWasCreatedDto {
+    #[schema(value_type = String)]
+    checkpoint_id: CheckpointId,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/checkpoints",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    responses(
+        (status = CREATED, body = CheckpointWasCreatedDto),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (
+            status = CONFLICT,
+            description = "testDbIsNotUsed or testDbHasConnections",
+            body = ApiErrorDto
+        ),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn create_test_db_checkpoint(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+) -> Result<JsonResponse<CheckpointWasCreatedDto>, ApiError> {
+    let result = tempest_core
+        .create_test_db_checkpoint(template_hash, test_db_id)
+        .await;
+
+    match result {
+        Ok(result) => Ok(JsonResponse {
+            status_code: StatusCode::CREATED,
+            body: CheckpointWasCreatedDto {
+                checkpoint_id: result.checkpoint_id,
+            },
+        }),
+        Err(CreateTestDbCheckpointErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        Err(CreateTestDbCheckpointErrorResult::TestDbHasConnections) => {
+            Err(test_db_has_connections(template_hash, test_db_id))
+        }
+        Err(CreateTestDbCheckpointErrorResult::Unknown { inner }) => {
+            Err(ApiError::unexpected(inner.to_string()))
+        }
+    }
+}
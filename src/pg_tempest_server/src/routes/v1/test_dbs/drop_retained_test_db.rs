@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::drop_retained_test_db::DropRetainedTestDbErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+
+use crate::dtos::api_error::{ApiError, ApiErrorDto, ApiPath};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{test_db_is_not_retained, test_db_was_not_found};
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    responses(
+        (status = NO_CONTENT),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (status = CONFLICT, description = "testDbIsNotRetained", body = ApiErrorDto),
+    )
+)]
+pub async fn drop_retained_test_db(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+) -> Result<StatusCode, ApiError> {
+    let result = tempest_core
+        .drop_retained_test_db(template_hash, test_db_id)
+        .await;
+
+    match result {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(DropRetainedTestDbErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(DropRetainedTestDbErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(DropRetainedTestDbErrorResult::TestDbIsNotRetained) => {
+            Err(test_db_is_not_retained(template_hash, test_db_id))
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode};
+use chrono::{DateTime, Utc};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::extend_test_db_usage::ExtendTestDbUsageErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dtos::{
+    api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath},
+    json_response::JsonResponse,
+};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{test_db_is_not_used, test_db_was_not_found};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::ExtendTestDbUsageRequestBody)]
+pub struct ExtendTestDbUsageRequestBody {
+    additional_time_ms: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageWasExtendedDto {
+    new_usage_deadline: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage/extensions",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    request_body = ExtendTestDbUsageRequestBody,
+    responses(
+        (status = OK, body = UsageWasExtendedDto),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (
+            status = CONFLICT,
+            description = "testDbIsNotUsed or testDbIsCorrupted",
+            body = ApiErrorDto
+        ),
+    )
+)]
+pub async fn extend_test_db_usage(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+    ApiJson(request_body): ApiJson<ExtendTestDbUsageRequestBody>,
+) -> Result<JsonResponse<UsageWasExtendedDto>, ApiError> {
+    let result = tempest_core
+        .extend_test_db_usage(
+            template_hash,
+            test_db_id,
+            Duration::from_millis(request_body.additional_time_ms),
+        )
+        .await;
+
+    match result {
+        Ok(result) => Ok(JsonResponse {
+            status_code: StatusCode::OK,
+            body: UsageWasExtendedDto {
+                new_usage_deadline: result.new_usage_deadline,
+            },
+        }),
+        Err(ExtendTestDbUsageErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        Err(ExtendTestDbUsageErrorResult::TestDbIsCorrupted) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "testDbIsCorrupted",
+            format!("Test db {template_hash} {test_db_id} is corrupted"),
+        )),
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::finish_test_db_usage::{FinishTestDbUsageErrorResult, TestDbUsageOutcome},
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+
+use crate::dtos::api_error::{ApiError, ApiErrorDto, ApiPath};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{test_db_is_not_used, test_db_was_not_found};
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/usage",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    responses(
+        (status = NO_CONTENT),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (status = CONFLICT, description = "testDbIsNotUsed", body = ApiErrorDto),
+    )
+)]
+pub async fn finish_test_db_usage(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+) -> Result<StatusCode, ApiError> {
+    let result = tempest_core
+        .finish_test_db_usage(
+            template_hash,
+            test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(FinishTestDbUsageErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(FinishTestDbUsageErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        // Network profiles are checked only for retained test dbs, which are never retained here
+        Err(FinishTestDbUsageErrorResult::NetworkProfileWasNotFound) => Err(ApiError::unexpected(
+            "Network profile of a released test db was checked",
+        )),
+    }
+}
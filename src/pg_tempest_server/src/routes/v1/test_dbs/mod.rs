@@ -0,0 +1,45 @@
+use axum::http::StatusCode;
+use pg_tempest_core::models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId};
+
+use crate::dtos::api_error::ApiError;
+
+pub mod complete_test_db_usage;
+pub mod create_test_db;
+pub mod create_test_db_checkpoint;
+pub mod drop_retained_test_db;
+pub mod extend_test_db_usage;
+pub mod finish_test_db_usage;
+pub mod promote_test_db;
+pub mod restore_test_db_checkpoint;
+
+pub fn test_db_was_not_found(template_hash: TemplateHash, test_db_id: TestDbId) -> ApiError {
+    ApiError::new(
+        StatusCode::NOT_FOUND,
+        "testDbWasNotFound",
+        format!("Test db {template_hash} {test_db_id} was not found"),
+    )
+}
+
+pub fn test_db_is_not_used(template_hash: TemplateHash, test_db_id: TestDbId) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "testDbIsNotUsed",
+        format!("Test db {template_hash} {test_db_id} is not used"),
+    )
+}
+
+pub fn test_db_is_not_retained(template_hash: TemplateHash, test_db_id: TestDbId) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "testDbIsNotRetained",
+        format!("Test db {template_hash} {test_db_id} is not retained"),
+    )
+}
+
+pub fn test_db_has_connections(template_hash: TemplateHash, test_db_id: TestDbId) -> ApiError {
+    ApiError::new(
+        StatusCode::CONFLICT,
+        "testDbHasConnections",
+        format!("Test db {template_hash} {test_db_id} has connections"),
+    )
+}
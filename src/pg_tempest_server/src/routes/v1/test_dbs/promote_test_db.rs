@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::promote_test_db::PromoteTestDbErrorResult,
+    models::value_types::{template_hash::TemplateHash, test_db_id::TestDbId},
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::dtos::api_error::{ApiError, ApiErrorDto, ApiJson, ApiPath};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{
+    test_db_has_connections, test_db_is_not_used, test_db_was_not_found,
+};
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = v1::PromoteTestDbRequestBody)]
+pub struct PromoteTestDbRequestBody {
+    #[schema(value_type = String)]
+    new_template_hash: TemplateHash,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/promotions",
+    tag = "v1",
+    params(("template_hash" = String, Path), ("test_db_id" = String, Path)),
+    request_body = PromoteTestDbRequestBody,
+    responses(
+        (status = NO_CONTENT),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound or testDbWasNotFound",
+            body = ApiErrorDto
+        ),
+        (
+            status = CONFLICT,
+            description = "testDbIsNotUsed, testDbHasConnections or templateAlreadyExists",
+            body = ApiErrorDto
+        ),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn promote_test_db(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id)): ApiPath<(TemplateHash, TestDbId)>,
+    ApiJson(request_body): ApiJson<PromoteTestDbRequestBody>,
+) -> Result<StatusCode, ApiError> {
+    let new_template_hash = request_body.new_template_hash;
+    let result = tempest_core
+        .promote_test_db(template_hash, test_db_id, new_template_hash)
+        .await;
+
+    match result {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(PromoteTestDbErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(PromoteTestDbErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(PromoteTestDbErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        Err(PromoteTestDbErrorResult::TestDbHasConnections) => {
+            Err(test_db_has_connections(template_hash, test_db_id))
+        }
+        Err(PromoteTestDbErrorResult::NewTemplateAlreadyExists) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "templateAlreadyExists",
+            format!("Template {new_template_hash} already exists"),
+        )),
+        Err(PromoteTestDbErrorResult::Unknown { inner }) => {
+            Err(ApiError::unexpected(inner.to_string()))
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode};
+use pg_tempest_core::{
+    PgTempestCore,
+    features::test_dbs::restore_test_db_checkpoint::RestoreTestDbCheckpointErrorResult,
+    models::value_types::{
+        checkpoint_id::CheckpointId, template_hash::TemplateHash, test_db_id::TestDbId,
+    },
+};
+
+use crate::dtos::api_error::{ApiError, ApiErrorDto, ApiPath};
+use crate::routes::v1::templates::template_was_not_found;
+use crate::routes::v1::test_dbs::{
+    test_db_has_connections, test_db_is_not_used, test_db_was_not_found,
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/templates/{template_hash}/test-dbs/{test_db_id}/checkpoints/{checkpoint_id}/restorations",
+    tag = "v1",
+    params(
+        ("template_hash" = String, Path),
+        ("test_db_id" = String, Path),
+        ("checkpoint_id" = String, Path)
+    ),
+    responses(
+        (status = NO_CONTENT),
+        (
+            status = NOT_FOUND,
+            description = "templateWasNotFound, testDbWasNotFound or checkpointWasNotFound",
+            body = ApiErrorDto
+        ),
+        (
+            status = CONFLICT,
+            description = "testDbIsNotUsed or testDbHasConnections",
+            body = ApiErrorDto
+        ),
+        (status = INTERNAL_SERVER_ERROR, description = "unexpectedError", body = ApiErrorDto),
+    )
+)]
+pub async fn restore_test_db_checkpoint(
+    State(tempest_core): State<Arc<PgTempestCore>>,
+    ApiPath((template_hash, test_db_id, checkpoint_id)): ApiPath<(
+        TemplateHash,
+        TestDbId,
+        CheckpointId,
+    )>,
+) -> Result<StatusCode, ApiError> {
+    let result = tempest_core
+        .restore_test_db_checkpoint(template_hash, test_db_id, checkpoint_id)
+        .await;
+
+    match result {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(RestoreTestDbCheckpointErrorResult::TemplateWasNotFound) => {
+            Err(template_was_not_found(template_hash))
+        }
+        Err(RestoreTestDbCheckpointErrorResult::TestDbWasNotFound) => {
+            Err(test_db_was_not_found(template_hash, test_db_id))
+        }
+        Err(RestoreTestDbCheckpointErrorResult::CheckpointWasNotFound) => Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            "checkpointWasNotFound",
+            format!("Checkpoint {template_hash} {test_db_id} {checkpoint_id} was not found"),
+        )),
+        Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed) => {
+            Err(test_db_is_not_used(template_hash, test_db_id))
+        }
+        Err(RestoreTestDbCheckpointErrorResult::TestDbHasConnections) => {
+            Err(test_db_has_connections(template_hash, test_db_id))
+        }
+        Err(RestoreTestDbCheckpointErrorResult::Unknown { inner }) => {
+            Err(ApiError::unexpected(inner.to_string()))
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::{path::Path, sync::Arc};
+
+use axum_server::tls_rustls::RustlsConfig;
+use pg_tempest_core::utils::errors::BoxDynError;
+use rustls::{
+    RootCertStore, ServerConfig,
+    crypto::{CryptoProvider, ring},
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    server::{WebPkiClientVerifier, danger::ClientCertVerifier},
+};
+
+use crate::configs::ServerTlsConfigs;
+
+pub fn build_rustls_config(configs: &ServerTlsConfigs) -> Result<RustlsConfig, BoxDynError> {
+    let (Some(cert_path), Some(key_path)) = (&configs.cert_path, &configs.key_path) else {
+        return Err("server.tls.cert_path and server.tls.key_path must be configured".into());
+    };
+
+    let cert_chain = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|err| format!("Failed to read {}: {err}", cert_path.display()))?;
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .map_err(|err| format!("Failed to read {}: {err}", key_path.display()))?;
+
+    let provider = Arc::new(ring::default_provider());
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?;
+
+    let builder = match &configs.client_ca_path {
+        Some(client_ca_path) => {
+            builder.with_client_cert_verifier(build_client_verifier(client_ca_path, provider)?)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder.with_single_cert(cert_chain, key)?;
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(RustlsConfig::from_config(Arc::new(server_config)))
+}
+
+fn build_client_verifier(
+    client_ca_path: &Path,
+    provider: Arc<CryptoProvider>,
+) -> Result<Arc<dyn ClientCertVerifier>, BoxDynError> {
+    let mut roots = RootCertStore::empty();
+
+    for cert in CertificateDer::pem_file_iter(client_ca_path)
+        .map_err(|err| format!("Failed to read {}: {err}", client_ca_path.display()))?
+    {
+        roots.add(cert?)?;
+    }
+
+    let verifier =
+        WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider).build()?;
+
+    Ok(verifier)
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use pg_tempest_core::configs::{
+    db_pool_configs::DbPoolConfigs,
+    dbms_configs::{DbmsConfigs, InnerDbmsConfigs, OuterDbmsConfigs},
+    template_initialization_configs::TemplateInitializationConfigs,
+    templates_configs::{TemplateCacheConfigs, TemplateImportConfigs, TemplatesConfigs},
+};
+
+pub const TEST_DB_CREATION_RETRIES_DELAY_MS: u64 = 100;
+pub const TEST_LONG_POLLING_TIMEOUT_MS: u64 = 1000;
+pub const TEST_MAX_DEADLINE_HANDLING_DELAY_MS: u64 = 100;
+
+pub fn create_dbms_configs() -> DbmsConfigs {
+    DbmsConfigs {
+        inner: InnerDbmsConfigs {
+            host: "localhost".into(),
+            port: 5432,
+        },
+        outer: OuterDbmsConfigs {
+            host: None,
+            port: None,
+            parameters: Default::default(),
+        },
+        profiles: Default::default(),
+        database: "postgres".into(),
+        user: "postgres".into(),
+        password: "postgres".into(),
+        ssl: Default::default(),
+    }
+}
+
+pub fn create_db_pool_configs(min_size: u8) -> DbPoolConfigs {
+    DbPoolConfigs {
+        min_size,
+        creation_retries_delay_in_ms: TEST_DB_CREATION_RETRIES_DELAY_MS,
+        reuse: Default::default(),
+        recycling: Default::default(),
+    }
+}
+
+pub fn create_templates_configs() -> TemplatesConfigs {
+    TemplatesConfigs {
+        initialization: Arc::new(TemplateInitializationConfigs {
+            long_polling_timeout_ms: TEST_LONG_POLLING_TIMEOUT_MS,
+            max_deadline_handling_delay_ms: TEST_MAX_DEADLINE_HANDLING_DELAY_MS,
+        }),
+        parent_template_db_name: None,
+        import: TemplateImportConfigs::default(),
+        cache: TemplateCacheConfigs::default(),
+    }
+}
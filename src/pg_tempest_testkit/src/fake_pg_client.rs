@@ -0,0 +1,590 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Cursor;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use pg_tempest_core::{
+    models::{
+        db_settings::DbSettings,
+        value_types::{
+            db_setting_name::DbSettingName, extension_name::ExtensionName,
+            pg_identifier::PgIdentifier,
+        },
+    },
+    pg_client::{
+        AlterDbIsTemplateError, AlterDbSettingsError, CreateDbError, CreateExtensionsError, Db,
+        DbDump, DbDumpFormat, DbDumpReader, DbModificationStats, DbSnapshot, DropDbError, PgClient,
+        ResetDbError, RestoreDbError, SequenceSnapshot, TableSnapshot,
+    },
+    utils::errors::BoxDynError,
+};
+
+const DEFAULT_TEMPLATE_DB_NAME: &str = "template1";
+const POSTGRES_OWNER_OID: u32 = 10;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PgClientOperation {
+    AlterDbIsTemplate,
+    AlterDbSettings,
+    CreateDb,
+    CreateExtensions,
+    DropDb,
+    GetDbs,
+    GetDbModificationStats,
+    GetDbSnapshot,
+    RestoreDb,
+    ResetDb,
+    DumpDb,
+}
+
+#[derive(Clone, Debug)]
+pub struct FakeDb {
+    pub oid: u32,
+    pub is_template: bool,
+    // Db which the db was copied from
+    pub template_db_name: PgIdentifier,
+    pub has_connections: bool,
+    pub restored_dump: Option<DbDump>,
+    // Like in Postgres, settings aren't copied along with the db
+    pub settings: DbSettings,
+    // Unlike settings, extensions are copied along with the db
+    pub extension_names: Vec<ExtensionName>,
+    // Changes counted by pg_stat_database
+    pub modified_tuple_count: u64,
+    // Changes which aren't counted by pg_stat_database
+    pub sequence_value: u64,
+    // Number of pg_stat_reset calls, which zero the counters
+    pub stats_reset_count: u64,
+    // Changes of catalogs, which can't be undone by a reset
+    pub modified_catalog_tuple_count: u64,
+}
+
+// In-memory PgClient which follows error semantics of Postgres:
+// a template db can't be dropped, a db can't be created twice or from a missing template,
+// a db with connections can't be dropped or copied, a rejected setting can't be set,
+// an uninstalled extension can't be created
+pub struct FakePgClient {
+    state: Mutex<FakePgClientState>,
+}
+
+struct FakePgClientState {
+    dbs: BTreeMap<PgIdentifier, FakeDb>,
+    next_oid: u32,
+    delay: Duration,
+    pending_failures: HashMap<PgClientOperation, usize>,
+    call_counts: HashMap<PgClientOperation, usize>,
+    rejected_setting_names: BTreeSet<DbSettingName>,
+    uninstalled_extension_names: BTreeSet<ExtensionName>,
+}
+
+impl FakePgClient {
+    pub fn new() -> FakePgClient {
+        let mut state = FakePgClientState {
+            dbs: BTreeMap::new(),
+            next_oid: 1,
+            delay: Duration::ZERO,
+            pending_failures: HashMap::new(),
+            call_counts: HashMap::new(),
+            rejected_setting_names: BTreeSet::new(),
+            uninstalled_extension_names: BTreeSet::new(),
+        };
+
+        for (db_name, is_template) in [
+            ("template0", true),
+            ("template1", true),
+            ("postgres", false),
+        ] {
+            state.insert_db(
+                pg_identifier(db_name),
+                is_template,
+                pg_identifier("template0"),
+            );
+        }
+
+        FakePgClient {
+            state: Mutex::new(state),
+        }
+    }
+
+    // Every operation sleeps for the delay before it is applied, which lets tests
+    // interleave requests with db creation when tokio time is paused
+    pub fn set_delay(&self, delay: Duration) {
+        self.state.lock().unwrap().delay = delay;
+    }
+
+    // Next `count` calls of the operation fail with an unexpected error and change nothing
+    pub fn fail_next(&self, operation: PgClientOperation, count: usize) {
+        *self
+            .state
+            .lock()
+            .unwrap()
+            .pending_failures
+            .entry(operation)
+            .or_default() += count;
+    }
+
+    pub fn call_count(&self, operation: PgClientOperation) -> usize {
+        let state = self.state.lock().unwrap();
+        state
+            .call_counts
+            .get(&operation)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn db(&self, db_name: &str) -> Option<FakeDb> {
+        self.state
+            .lock()
+            .unwrap()
+            .dbs
+            .get(&pg_identifier(db_name))
+            .cloned()
+    }
+
+    pub fn db_names(&self) -> Vec<PgIdentifier> {
+        self.state.lock().unwrap().dbs.keys().cloned().collect()
+    }
+
+    // Creates a db as if it was made outside of pg-tempest, e.g. a parent template
+    pub fn insert_db(&self, db_name: &str, is_template: bool) {
+        self.state.lock().unwrap().insert_db(
+            pg_identifier(db_name),
+            is_template,
+            pg_identifier(DEFAULT_TEMPLATE_DB_NAME),
+        );
+    }
+
+    // Simulates an unknown parameter or an invalid value
+    pub fn reject_setting(&self, setting_name: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .rejected_setting_names
+            .insert(DbSettingName::new(setting_name).expect("Setting name must be valid"));
+    }
+
+    // Simulates an extension whose files aren't installed on the cluster
+    pub fn uninstall_extension(&self, extension_name: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .uninstalled_extension_names
+            .insert(ExtensionName::new(extension_name).expect("Extension name must be valid"));
+    }
+
+    // Simulates an INSERT, UPDATE, DELETE or DDL made by a test
+    pub fn modify_tuples(&self, db_name: &str) {
+        if let Some(db) = self
+            .state
+            .lock()
+            .unwrap()
+            .dbs
+            .get_mut(&pg_identifier(db_name))
+        {
+            db.modified_tuple_count += 1;
+        }
+    }
+
+    // Simulates nextval called by a test
+    pub fn advance_sequence(&self, db_name: &str) {
+        if let Some(db) = self
+            .state
+            .lock()
+            .unwrap()
+            .dbs
+            .get_mut(&pg_identifier(db_name))
+        {
+            db.sequence_value += 1;
+        }
+    }
+
+    // Simulates pg_stat_reset called by a test
+    pub fn reset_stats(&self, db_name: &str) {
+        if let Some(db) = self
+            .state
+            .lock()
+            .unwrap()
+            .dbs
+            .get_mut(&pg_identifier(db_name))
+        {
+            db.modified_tuple_count = 0;
+            db.stats_reset_count += 1;
+        }
+    }
+
+    // Simulates DDL made by a test
+    pub fn change_schema(&self, db_name: &str) {
+        if let Some(db) = self
+            .state
+            .lock()
+            .unwrap()
+            .dbs
+            .get_mut(&pg_identifier(db_name))
+        {
+            db.modified_tuple_count += 1;
+            db.modified_catalog_tuple_count += 1;
+        }
+    }
+
+    // Simulates clients which are connected to the db
+    pub fn set_has_connections(&self, db_name: &str, has_connections: bool) {
+        if let Some(db) = self
+            .state
+            .lock()
+            .unwrap()
+            .dbs
+            .get_mut(&pg_identifier(db_name))
+        {
+            db.has_connections = has_connections;
+        }
+    }
+
+    async fn begin(&self, operation: PgClientOperation) -> Result<(), BoxDynError> {
+        let delay = {
+            let mut state = self.state.lock().unwrap();
+            *state.call_counts.entry(operation).or_default() += 1;
+            state.delay
+        };
+
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        match state.pending_failures.get_mut(&operation) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                Err(format!("{operation:?} failure was injected").into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for FakePgClient {
+    fn default() -> Self {
+        FakePgClient::new()
+    }
+}
+
+impl FakePgClientState {
+    fn insert_db(
+        &mut self,
+        db_name: PgIdentifier,
+        is_template: bool,
+        template_db_name: PgIdentifier,
+    ) {
+        let extension_names = self
+            .dbs
+            .get(&template_db_name)
+            .map(|template_db| template_db.extension_names.clone())
+            .unwrap_or_default();
+
+        let oid = self.next_oid;
+        self.next_oid += 1;
+
+        self.dbs.insert(
+            db_name,
+            FakeDb {
+                oid,
+                is_template,
+                template_db_name,
+                has_connections: false,
+                restored_dump: None,
+                settings: DbSettings::new(),
+                extension_names,
+                modified_tuple_count: 0,
+                sequence_value: 0,
+                stats_reset_count: 0,
+                modified_catalog_tuple_count: 0,
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl PgClient for FakePgClient {
+    async fn alter_db_is_template(
+        &self,
+        db_name: PgIdentifier,
+        is_template: bool,
+    ) -> Result<(), AlterDbIsTemplateError> {
+        self.begin(PgClientOperation::AlterDbIsTemplate).await?;
+
+        let mut state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get_mut(&db_name) else {
+            return Err(AlterDbIsTemplateError::DbDoesNotExists { db_name });
+        };
+
+        db.is_template = is_template;
+
+        Ok(())
+    }
+
+    async fn alter_db_settings(
+        &self,
+        db_name: PgIdentifier,
+        settings: &DbSettings,
+    ) -> Result<(), AlterDbSettingsError> {
+        self.begin(PgClientOperation::AlterDbSettings).await?;
+
+        let mut state = self.state.lock().unwrap();
+
+        let rejected_setting_name = settings
+            .keys()
+            .find(|setting_name| state.rejected_setting_names.contains(*setting_name));
+        if let Some(setting_name) = rejected_setting_name {
+            return Err(AlterDbSettingsError::SettingIsInvalid {
+                setting_name: setting_name.clone(),
+                reason: format!("unrecognized configuration parameter \"{setting_name}\"").into(),
+            });
+        }
+
+        let Some(db) = state.dbs.get_mut(&db_name) else {
+            return Err(AlterDbSettingsError::DbDoesNotExist { db_name });
+        };
+
+        db.settings.extend(settings.clone());
+
+        Ok(())
+    }
+
+    async fn create_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: Option<PgIdentifier>,
+        is_template: bool,
+    ) -> Result<(), CreateDbError> {
+        self.begin(PgClientOperation::CreateDb).await?;
+
+        let mut state = self.state.lock().unwrap();
+
+        if state.dbs.contains_key(&db_name) {
+            return Err(CreateDbError::DbAlreadyExists { db_name });
+        }
+
+        let template_db_name =
+            template_db_name.unwrap_or_else(|| pg_identifier(DEFAULT_TEMPLATE_DB_NAME));
+        let Some(template_db) = state.dbs.get(&template_db_name) else {
+            return Err(CreateDbError::TemplateDbDoesNotExist { template_db_name });
+        };
+
+        if template_db.has_connections {
+            return Err(CreateDbError::TemplateDbIsUsed { template_db_name });
+        }
+
+        state.insert_db(db_name, is_template, template_db_name);
+
+        Ok(())
+    }
+
+    async fn create_extensions(
+        &self,
+        db_name: PgIdentifier,
+        extension_names: &[ExtensionName],
+    ) -> Result<(), CreateExtensionsError> {
+        self.begin(PgClientOperation::CreateExtensions).await?;
+
+        let mut state = self.state.lock().unwrap();
+
+        let uninstalled_extension_name = extension_names
+            .iter()
+            .find(|extension_name| state.uninstalled_extension_names.contains(*extension_name));
+        if let Some(extension_name) = uninstalled_extension_name {
+            return Err(CreateExtensionsError::ExtensionIsNotAvailable {
+                extension_name: extension_name.clone(),
+                reason: format!("extension \"{extension_name}\" is not available").into(),
+            });
+        }
+
+        let Some(db) = state.dbs.get_mut(&db_name) else {
+            return Err(CreateExtensionsError::DbDoesNotExist { db_name });
+        };
+
+        for extension_name in extension_names {
+            if !db.extension_names.contains(extension_name) {
+                db.extension_names.push(extension_name.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn drop_db(&self, db_name: PgIdentifier) -> Result<(), DropDbError> {
+        self.begin(PgClientOperation::DropDb).await?;
+
+        let mut state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get(&db_name) else {
+            return Err(DropDbError::DbDoesNotExist { db_name });
+        };
+
+        if db.is_template {
+            return Err(DropDbError::DbIsTemplate { db_name });
+        }
+
+        if db.has_connections {
+            return Err(DropDbError::DbIsUsed { db_name });
+        }
+
+        state.dbs.remove(&db_name);
+
+        Ok(())
+    }
+
+    async fn get_dbs(&self) -> Result<Vec<Db>, BoxDynError> {
+        self.begin(PgClientOperation::GetDbs).await?;
+
+        let state = self.state.lock().unwrap();
+        let dbs = state
+            .dbs
+            .iter()
+            .map(|(db_name, db)| Db {
+                oid: db.oid,
+                name: db_name.clone(),
+                is_template: db.is_template,
+                owner_oid: POSTGRES_OWNER_OID,
+                allow_connection: true,
+            })
+            .collect();
+
+        Ok(dbs)
+    }
+
+    // Fingerprints are the same for dbs with the same oid and sequence values
+    async fn get_db_modification_stats(
+        &self,
+        db_name: PgIdentifier,
+        with_fingerprint: bool,
+    ) -> Result<DbModificationStats, BoxDynError> {
+        self.begin(PgClientOperation::GetDbModificationStats)
+            .await?;
+
+        let state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get(&db_name) else {
+            return Err(format!("Db {db_name} does not exist").into());
+        };
+
+        Ok(DbModificationStats {
+            connection_count: db.has_connections.into(),
+            tuples_inserted: db.modified_tuple_count,
+            tuples_updated: 0,
+            tuples_deleted: 0,
+            stats_reset: (db.stats_reset_count > 0)
+                .then(|| db.stats_reset_count.to_string().into()),
+            sequence_values: db.sequence_value.to_string().into(),
+            fingerprint: with_fingerprint
+                .then(|| format!("{}:{}", db.oid, db.sequence_value).into()),
+        })
+    }
+
+    // The db has a single table and a single sequence
+    async fn get_db_snapshot(&self, db_name: PgIdentifier) -> Result<DbSnapshot, BoxDynError> {
+        self.begin(PgClientOperation::GetDbSnapshot).await?;
+
+        let state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get(&db_name) else {
+            return Err(format!("Db {db_name} does not exist").into());
+        };
+
+        Ok(DbSnapshot {
+            catalog_tuples_modified: db.modified_catalog_tuple_count,
+            tables: vec![TableSnapshot {
+                oid: 1,
+                file_node: 1,
+                tuples_inserted: db.modified_tuple_count,
+                tuples_updated: 0,
+                tuples_deleted: 0,
+            }],
+            sequences: vec![SequenceSnapshot {
+                oid: 2,
+                last_value: (db.sequence_value > 0).then_some(db.sequence_value as i64),
+                start_value: 1,
+            }],
+        })
+    }
+
+    async fn reset_db(
+        &self,
+        db_name: PgIdentifier,
+        template_db_name: PgIdentifier,
+        snapshot: &DbSnapshot,
+    ) -> Result<(), ResetDbError> {
+        self.begin(PgClientOperation::ResetDb).await?;
+
+        let mut state = self.state.lock().unwrap();
+        if !state.dbs.contains_key(&template_db_name) {
+            return Err(ResetDbError::Unexpected(
+                format!("Db {template_db_name} does not exist").into(),
+            ));
+        }
+        let Some(db) = state.dbs.get_mut(&db_name) else {
+            return Err(ResetDbError::Unexpected(
+                format!("Db {db_name} does not exist").into(),
+            ));
+        };
+
+        if db.has_connections {
+            return Err(ResetDbError::DbIsUsed { db_name });
+        }
+        if db.modified_catalog_tuple_count != snapshot.catalog_tuples_modified {
+            return Err(ResetDbError::SchemaWasChanged { db_name });
+        }
+
+        db.modified_tuple_count = snapshot.tables[0].tuples_inserted;
+        db.sequence_value = snapshot.sequences[0].last_value.unwrap_or(0) as u64;
+
+        Ok(())
+    }
+
+    async fn restore_db(&self, db_name: PgIdentifier, dump: &DbDump) -> Result<(), RestoreDbError> {
+        self.begin(PgClientOperation::RestoreDb).await?;
+
+        // Archives which weren't dumped by the fake are as unreadable as garbage is for pg_restore
+        if let DbDump::Archive { path, .. } = dump
+            && let Ok(archive) = std::fs::read(path)
+            && !archive.starts_with(b"-- ")
+        {
+            return Err(RestoreDbError::ArchiveIsUnreadable {
+                reason: format!("{path:?} is not an archive").into(),
+            });
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get_mut(&db_name) else {
+            return Err(RestoreDbError::Unexpected(
+                format!("Db {db_name} does not exist").into(),
+            ));
+        };
+
+        db.restored_dump = Some(dump.clone());
+
+        Ok(())
+    }
+
+    // The dump consists of a single line naming the db, so copies of a template can be told apart
+    async fn dump_db(
+        &self,
+        db_name: PgIdentifier,
+        format: DbDumpFormat,
+        _with_ownership: bool,
+    ) -> Result<DbDumpReader, BoxDynError> {
+        self.begin(PgClientOperation::DumpDb).await?;
+
+        let state = self.state.lock().unwrap();
+        let Some(db) = state.dbs.get(&db_name) else {
+            return Err(format!("Db {db_name} does not exist").into());
+        };
+
+        let dump = format!(
+            "-- {format:?} dump of {db_name} copied from {}\n",
+            db.template_db_name
+        );
+
+        Ok(Box::pin(Cursor::new(dump.into_bytes())))
+    }
+}
+
+fn pg_identifier(value: &str) -> PgIdentifier {
+    PgIdentifier::new(value).expect("Fake db names must be valid identifiers")
+}
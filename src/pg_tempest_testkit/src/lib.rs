@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    configs::{
+        db_pool_configs::{
+            DbPoolConfigs, TestDbRecyclingConfigs, TestDbRecyclingStrategy, TestDbReuseConfigs,
+            TestDbReuseStrictness,
+        },
+        templates_configs::{TemplateCacheConfigs, TemplatesConfigs},
+    },
+};
+
+use crate::{
+    configs::{create_db_pool_configs, create_dbms_configs, create_templates_configs},
+    fake_pg_client::FakePgClient,
+    manual_clock::ManualClock,
+};
+
+pub mod configs;
+pub mod fake_pg_client;
+pub mod manual_clock;
+
+pub struct TestContext {
+    pub pg_client: Arc<FakePgClient>,
+    pub clock: Arc<ManualClock>,
+    pub tempest_core: Arc<PgTempestCore>,
+}
+
+// Starts a core over the fake client and the manual clock with configs from `configs`,
+// which are changed by the setters. Meant to be used in tokio tests with paused time,
+// so that background loops are deterministic
+pub struct TestContextBuilder {
+    db_pool_configs: DbPoolConfigs,
+    templates_configs: TemplatesConfigs,
+}
+
+impl TestContextBuilder {
+    pub fn new() -> TestContextBuilder {
+        TestContextBuilder {
+            db_pool_configs: create_db_pool_configs(0),
+            templates_configs: create_templates_configs(),
+        }
+    }
+
+    pub fn db_pool_min_size(mut self, min_size: u8) -> TestContextBuilder {
+        self.db_pool_configs.min_size = min_size;
+        self
+    }
+
+    pub fn test_db_reuse(mut self, strictness: TestDbReuseStrictness) -> TestContextBuilder {
+        self.db_pool_configs.reuse = TestDbReuseConfigs { strictness };
+        self
+    }
+
+    pub fn test_db_recycling(mut self, strategy: TestDbRecyclingStrategy) -> TestContextBuilder {
+        self.db_pool_configs.recycling = TestDbRecyclingConfigs { strategy };
+        self
+    }
+
+    pub fn template_cache_dir(mut self, dir: impl Into<PathBuf>) -> TestContextBuilder {
+        self.templates_configs.cache = TemplateCacheConfigs {
+            dir: Some(dir.into()),
+        };
+        self
+    }
+
+    pub async fn start(self) -> TestContext {
+        let pg_client = Arc::new(FakePgClient::new());
+        let clock = Arc::new(ManualClock::default());
+        let tempest_core = PgTempestCore::start_with_clock(
+            pg_client.clone(),
+            clock.clone(),
+            Arc::new(create_dbms_configs()),
+            Arc::new(self.db_pool_configs),
+            Arc::new(self.templates_configs),
+        )
+        .await
+        .expect("Core must start with test configs");
+
+        TestContext {
+            pg_client,
+            clock,
+            tempest_core,
+        }
+    }
+}
+
+impl Default for TestContextBuilder {
+    fn default() -> Self {
+        TestContextBuilder::new()
+    }
+}
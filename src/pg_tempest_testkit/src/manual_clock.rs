@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use pg_tempest_core::utils::clock::Clock;
+
+// Clock which moves only when it is told to. Tokio timers are independent of it,
+// so tests usually advance both: the clock for deadlines and paused tokio time for background loops
+pub struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    pub fn new(now: DateTime<Utc>) -> ManualClock {
+        ManualClock {
+            now: Mutex::new(now),
+        }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+
+    // Advances the clock and then paused tokio time, letting background loops observe the new time
+    pub async fn advance_with_tokio_time(&self, duration: Duration) {
+        self.advance(duration);
+        tokio::time::sleep(duration).await;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        ManualClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
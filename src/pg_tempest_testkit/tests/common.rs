@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use pg_tempest_core::{
+    PgTempestCore,
+    features::templates::start_template_initialization::StartTemplateInitializationResult,
+    features::test_dbs::finish_test_db_usage::TestDbUsageOutcome,
+    metadata::template_metadata::TestDbState,
+    models::{db_settings::DbSettings, value_types::template_hash::TemplateHash},
+};
+use pg_tempest_testkit::{
+    TestContext, configs::TEST_DB_CREATION_RETRIES_DELAY_MS, fake_pg_client::FakePgClient,
+};
+
+pub const TEMPLATE_HASH: TemplateHash = TemplateHash::new([1; 16]);
+#[allow(dead_code)]
+pub const NEW_TEMPLATE_HASH: TemplateHash = TemplateHash::new([2; 16]);
+
+#[allow(dead_code)]
+pub async fn initialize_template(tempest_core: &Arc<PgTempestCore>) {
+    let result = tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        StartTemplateInitializationResult::InitializationWasStarted { .. }
+    ));
+
+    tempest_core
+        .clone()
+        .finish_template_initialization(TEMPLATE_HASH)
+        .await
+        .unwrap();
+}
+
+#[allow(dead_code)]
+pub async fn test_db_states(tempest_core: &PgTempestCore) -> Vec<TestDbState> {
+    let template = tempest_core.get_template(TEMPLATE_HASH).await.unwrap();
+    template.test_dbs.into_iter().map(|x| x.state).collect()
+}
+
+#[allow(dead_code)]
+pub async fn wait_for_background_tasks() {
+    tokio::time::sleep(Duration::from_millis(TEST_DB_CREATION_RETRIES_DELAY_MS)).await;
+}
+
+// Leases the only test db, lets `usage` act on it and finishes the usage.
+// Returns oids of the test db before and after the usage
+#[allow(dead_code)]
+pub async fn use_test_db(
+    context: &TestContext,
+    usage: impl FnOnce(&FakePgClient, &str),
+) -> (u32, u32) {
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db_name = format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001");
+    let oid_before_usage = context.pg_client.db(&test_db_name).unwrap().oid;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    usage(&context.pg_client, &test_db_name);
+    context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await
+        .unwrap();
+    wait_for_background_tasks().await;
+
+    assert!(matches!(
+        test_db_states(&context.tempest_core).await.as_slice(),
+        [TestDbState::Ready]
+    ));
+    let oid_after_usage = context.pg_client.db(&test_db_name).unwrap().oid;
+
+    (oid_before_usage, oid_after_usage)
+}
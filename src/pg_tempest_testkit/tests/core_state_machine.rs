@@ -0,0 +1,323 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::{
+        templates::start_template_initialization::StartTemplateInitializationResult,
+        test_dbs::get_test_db::GetTestDbErrorResult,
+    },
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+    models::{db_settings::DbSettings, value_types::pg_identifier::PgIdentifier},
+    utils::clock::Clock,
+};
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::{
+    NEW_TEMPLATE_HASH, TEMPLATE_HASH, initialize_template, test_db_states,
+    wait_for_background_tasks,
+};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn pool_is_filled_after_initialization() {
+    let context = TestContextBuilder::new().db_pool_min_size(2).start().await;
+
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let states = test_db_states(&context.tempest_core).await;
+    assert_eq!(states.len(), 2);
+    assert!(states.iter().all(|x| matches!(x, TestDbState::Ready)));
+
+    let template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert!(template_db.is_template);
+
+    let test_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001"))
+        .unwrap();
+    assert_eq!(
+        test_db.template_db_name.to_string(),
+        format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE")
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn initialization_fails_after_deadline() {
+    let context = TestContextBuilder::new().start().await;
+
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    context
+        .clock
+        .advance_with_tokio_time(Duration::from_secs(9))
+        .await;
+    let template = context
+        .tempest_core
+        .get_template(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    assert!(matches!(
+        template.initialization_state,
+        TemplateInitializationState::InProgress { .. }
+    ));
+
+    context
+        .clock
+        .advance_with_tokio_time(Duration::from_secs(1))
+        .await;
+    wait_for_background_tasks().await;
+    let template = context
+        .tempest_core
+        .get_template(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    assert!(matches!(
+        template.initialization_state,
+        TemplateInitializationState::Failed { .. }
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_db_is_recreated_after_usage_deadline() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(5), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        test_db.usage_deadline,
+        context.clock.now() + Duration::from_secs(5)
+    );
+    let drops_before_deadline = context.pg_client.call_count(PgClientOperation::DropDb);
+
+    context
+        .clock
+        .advance_with_tokio_time(Duration::from_secs(5))
+        .await;
+    wait_for_background_tasks().await;
+
+    assert!(context.pg_client.call_count(PgClientOperation::DropDb) > drops_before_deadline);
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Ready]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn awaiter_gets_test_db_created_for_it() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    // Creation of dbs takes time from now, so the second request has to wait for a new test db
+    context.pg_client.set_delay(Duration::from_secs(1));
+
+    let first = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let second = tokio::spawn(context.tempest_core.clone().get_test_db(
+        TEMPLATE_HASH,
+        Duration::from_secs(60),
+        None,
+    ));
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(
+        states.as_slice(),
+        [TestDbState::InUse { .. }, TestDbState::Creating]
+    ));
+
+    let second = second.await.unwrap().unwrap();
+    assert_ne!(first.test_db_id, second.test_db_id);
+
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(
+        states
+            .iter()
+            .all(|x| matches!(x, TestDbState::InUse { .. }))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn template_db_creation_failure_fails_initialization() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    context.pg_client.fail_next(PgClientOperation::CreateDb, 1);
+
+    let result = context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let template = context
+        .tempest_core
+        .get_template(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    assert!(matches!(
+        template.initialization_state,
+        TemplateInitializationState::Failed { .. }
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn failed_test_db_creation_is_retried() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+    context.pg_client.fail_next(PgClientOperation::CreateDb, 1);
+    context
+        .tempest_core
+        .clone()
+        .finish_template_initialization(TEMPLATE_HASH)
+        .await
+        .unwrap();
+
+    tokio::task::yield_now().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Corrupted]));
+
+    wait_for_background_tasks().await;
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Ready]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn initialization_fails_without_parent_template_db() {
+    let context = TestContextBuilder::new().start().await;
+    let parent_template_db_name = PgIdentifier::new("MISSING_PARENT").unwrap();
+
+    let result = context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            Some(parent_template_db_name),
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    let StartTemplateInitializationResult::InitializationIsFailed { reason } = result else {
+        panic!("Initialization must fail");
+    };
+    assert!(reason.unwrap().contains("MISSING_PARENT"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn purge_keeps_dbs_of_unknown_templates_unless_all_are_purged() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+    let unknown_template_db_name = format!("TEMPEST_{NEW_TEMPLATE_HASH}_TEMPLATE");
+    let unknown_test_db_name = format!("TEMPEST_{NEW_TEMPLATE_HASH}_TEST_DB_0001");
+    context.pg_client.insert_db(&unknown_template_db_name, true);
+    context.pg_client.insert_db(&unknown_test_db_name, false);
+
+    let result = context.tempest_core.purge_templates(false).await.unwrap();
+
+    assert_eq!(result.purged_template_hashes, vec![TEMPLATE_HASH]);
+    assert_eq!(result.dropped_dbs.len(), 2);
+    assert!(context.pg_client.db(&unknown_template_db_name).is_some());
+    assert!(context.pg_client.db(&unknown_test_db_name).is_some());
+
+    let result = context.tempest_core.purge_templates(true).await.unwrap();
+
+    assert!(result.purged_template_hashes.is_empty());
+    assert_eq!(result.dropped_dbs.len(), 2);
+    assert!(context.pg_client.db(&unknown_template_db_name).is_none());
+    assert!(context.pg_client.db(&unknown_test_db_name).is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_db_isnt_leased_before_initialization_is_finished() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    let result = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(10), None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(GetTestDbErrorResult::TemplateIsNotInitialized)
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn background_loops_stop_when_core_is_dropped() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+    let runtime_metrics = tokio::runtime::Handle::current().metrics();
+    assert_eq!(runtime_metrics.num_alive_tasks(), 2);
+
+    let weak_tempest_core = Arc::downgrade(&context.tempest_core);
+    drop(context);
+    assert_eq!(weak_tempest_core.strong_count(), 0);
+
+    // Each loop notices that the core is dropped when it wakes up next time
+    wait_for_background_tasks().await;
+    assert_eq!(runtime_metrics.num_alive_tasks(), 0);
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use pg_tempest_core::{
+    configs::fault_injection_configs::FaultInjectionConfigs,
+    fault_injection::{FaultInjectingPgClient, FaultInjection},
+    models::value_types::pg_identifier::PgIdentifier,
+    pg_client::{CreateDbError, DropDbError, PgClient},
+};
+use pg_tempest_testkit::fake_pg_client::{FakePgClient, PgClientOperation};
+
+fn create_client(configs: FaultInjectionConfigs) -> (Arc<FakePgClient>, FaultInjectingPgClient) {
+    let inner = Arc::new(FakePgClient::new());
+    let fault_injection = Arc::new(FaultInjection::new(configs).unwrap());
+
+    (
+        inner.clone(),
+        FaultInjectingPgClient::new(inner, fault_injection),
+    )
+}
+
+fn db_name() -> PgIdentifier {
+    PgIdentifier::new("FAULTY_DB").unwrap()
+}
+
+#[tokio::test]
+async fn disabled_faults_are_not_injected() {
+    let (inner, client) = create_client(FaultInjectionConfigs {
+        enabled: false,
+        unexpected_error_probability: 1.0,
+        ..Default::default()
+    });
+
+    client.create_db(db_name(), None, false).await.unwrap();
+
+    assert!(inner.db("FAULTY_DB").is_some());
+}
+
+#[tokio::test]
+async fn unexpected_errors_skip_inner_client() {
+    let (inner, client) = create_client(FaultInjectionConfigs {
+        enabled: true,
+        unexpected_error_probability: 1.0,
+        ..Default::default()
+    });
+
+    let result = client.create_db(db_name(), None, false).await;
+
+    assert!(matches!(result, Err(CreateDbError::Unexpected(_))));
+    assert_eq!(inner.call_count(PgClientOperation::CreateDb), 0);
+}
+
+#[tokio::test]
+async fn conflicts_are_injected_per_operation() {
+    let (inner, client) = create_client(FaultInjectionConfigs {
+        enabled: true,
+        conflict_probability: 1.0,
+        ..Default::default()
+    });
+    inner.insert_db("FAULTY_DB", false);
+
+    let create_result = client.create_db(db_name(), None, false).await;
+    let drop_result = client.drop_db(db_name()).await;
+
+    assert!(matches!(
+        create_result,
+        Err(CreateDbError::DbAlreadyExists { .. })
+    ));
+    assert!(matches!(
+        drop_result,
+        Err(DropDbError::DbDoesNotExist { .. })
+    ));
+    assert!(inner.db("FAULTY_DB").is_some());
+}
+
+#[tokio::test(start_paused = true)]
+async fn hang_delays_operation_and_fails_it() {
+    let (_, client) = create_client(FaultInjectionConfigs {
+        enabled: true,
+        latency_ms: 100,
+        hang_probability: 1.0,
+        hang_duration_ms: 10_000,
+        ..Default::default()
+    });
+    let start = tokio::time::Instant::now();
+
+    let result = client.drop_db(db_name()).await;
+
+    assert!(matches!(result, Err(DropDbError::Unexpected(_))));
+    assert_eq!(start.elapsed(), Duration::from_millis(10_100));
+}
+
+#[test]
+fn invalid_probabilities_are_rejected() {
+    let too_large = FaultInjectionConfigs {
+        hang_probability: 1.5,
+        ..Default::default()
+    };
+    let sum_too_large = FaultInjectionConfigs {
+        hang_probability: 0.6,
+        conflict_probability: 0.6,
+        ..Default::default()
+    };
+
+    assert!(FaultInjection::new(too_large).is_err());
+    assert!(FaultInjection::new(sum_too_large).is_err());
+}
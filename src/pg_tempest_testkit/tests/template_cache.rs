@@ -0,0 +1,183 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::templates::start_template_initialization::StartTemplateInitializationResult,
+    metadata::template_metadata::TestDbState,
+    models::{db_settings::DbSettings, value_types::pg_identifier::PgIdentifier},
+    pg_client::DbDump,
+};
+use pg_tempest_testkit::{TestContext, TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::{
+    TEMPLATE_HASH, initialize_template, test_db_states, wait_for_background_tasks,
+};
+
+mod common;
+
+// Cache tests run in real time, because the cache is written by blocking file operations
+fn create_cache_dir(name: &str) -> PathBuf {
+    let cache_dir = std::env::temp_dir().join(format!("pg-tempest-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    cache_dir
+}
+
+async fn wait_for_cached_template(cached_template_path: &Path) {
+    for _ in 0..100 {
+        if cached_template_path.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+async fn start_initialization(
+    context: &TestContext,
+    parent_template_db_name: Option<&str>,
+) -> StartTemplateInitializationResult {
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            parent_template_db_name.map(|name| PgIdentifier::new(name).unwrap()),
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn cached_template_is_restored_without_initializer() {
+    let cache_dir = create_cache_dir("cache-restoration");
+    let cached_template_path = cache_dir.join(format!("{TEMPLATE_HASH}.template1.dump"));
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .template_cache_dir(&cache_dir)
+        .start()
+        .await;
+
+    initialize_template(&context.tempest_core).await;
+    wait_for_cached_template(&cached_template_path).await;
+    let cached_dump = std::fs::read_to_string(&cached_template_path).unwrap();
+    assert!(cached_dump.starts_with("-- Custom dump of"));
+
+    context.tempest_core.purge_templates(false).await.unwrap();
+
+    let result = start_initialization(&context, None).await;
+    assert!(matches!(
+        result,
+        StartTemplateInitializationResult::InitializationIsFinished
+    ));
+
+    let template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert_eq!(template_db.template_db_name.to_string(), "template0");
+    assert!(matches!(
+        template_db.restored_dump,
+        Some(DbDump::Archive { path, with_ownership: true }) if path == cached_template_path
+    ));
+
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states[..], [TestDbState::Ready]));
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[tokio::test]
+async fn unreadable_cached_template_is_removed_and_initialized_again() {
+    let cache_dir = create_cache_dir("broken-cache");
+    let cached_template_path = cache_dir.join(format!("{TEMPLATE_HASH}.template1.dump"));
+    std::fs::write(&cached_template_path, "broken").unwrap();
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .template_cache_dir(&cache_dir)
+        .start()
+        .await;
+
+    let result = start_initialization(&context, None).await;
+    assert!(matches!(
+        result,
+        StartTemplateInitializationResult::InitializationWasStarted { .. }
+    ));
+    assert!(!cached_template_path.exists());
+
+    let template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert_eq!(template_db.template_db_name.to_string(), "template1");
+    assert!(template_db.restored_dump.is_none());
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[tokio::test]
+async fn cached_template_is_kept_when_restoration_fails() {
+    let cache_dir = create_cache_dir("failed-cache-restoration");
+    let cached_template_path = cache_dir.join(format!("{TEMPLATE_HASH}.template1.dump"));
+    std::fs::write(&cached_template_path, "-- Custom dump").unwrap();
+    let context = TestContextBuilder::new()
+        .template_cache_dir(&cache_dir)
+        .start()
+        .await;
+    context.pg_client.fail_next(PgClientOperation::RestoreDb, 1);
+
+    let result = start_initialization(&context, None).await;
+
+    assert!(matches!(
+        result,
+        StartTemplateInitializationResult::InitializationWasStarted { .. }
+    ));
+    assert!(cached_template_path.exists());
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[tokio::test]
+async fn cached_template_of_another_parent_isnt_restored() {
+    let cache_dir = create_cache_dir("cache-of-another-parent");
+    let default_parent_cache_path = cache_dir.join(format!("{TEMPLATE_HASH}.template1.dump"));
+    let custom_parent_cache_path = cache_dir.join(format!("{TEMPLATE_HASH}.custom_parent.dump"));
+    let context = TestContextBuilder::new()
+        .template_cache_dir(&cache_dir)
+        .start()
+        .await;
+    context.pg_client.insert_db("custom_parent", true);
+
+    initialize_template(&context.tempest_core).await;
+    wait_for_cached_template(&default_parent_cache_path).await;
+    assert!(default_parent_cache_path.exists());
+    context.tempest_core.purge_templates(false).await.unwrap();
+
+    let result = start_initialization(&context, Some("custom_parent")).await;
+
+    assert!(matches!(
+        result,
+        StartTemplateInitializationResult::InitializationWasStarted { .. }
+    ));
+    let template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert_eq!(template_db.template_db_name.to_string(), "custom_parent");
+    assert!(template_db.restored_dump.is_none());
+
+    context
+        .tempest_core
+        .clone()
+        .finish_template_initialization(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    wait_for_cached_template(&custom_parent_cache_path).await;
+    assert!(custom_parent_cache_path.exists());
+
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::templates::start_template_initialization::StartTemplateInitializationResult,
+    models::{
+        db_settings::DbSettings,
+        value_types::{db_setting_name::DbSettingName, extension_name::ExtensionName},
+    },
+};
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::{TEMPLATE_HASH, wait_for_background_tasks};
+
+mod common;
+
+fn statement_timeout_settings() -> DbSettings {
+    DbSettings::from([(
+        DbSettingName::new("statement_timeout").unwrap(),
+        "5s".into(),
+    )])
+}
+
+#[tokio::test(start_paused = true)]
+async fn settings_are_applied_to_template_and_test_dbs() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            statement_timeout_settings(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+    context
+        .tempest_core
+        .clone()
+        .finish_template_initialization(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    wait_for_background_tasks().await;
+
+    for db_name in [
+        format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"),
+        format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001"),
+    ] {
+        let db = context.pg_client.db(&db_name).unwrap();
+        assert_eq!(db.settings, statement_timeout_settings(), "{db_name}");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn invalid_setting_fails_initialization() {
+    let context = TestContextBuilder::new().start().await;
+    context.pg_client.reject_setting("statement_timeout");
+
+    let result = context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            statement_timeout_settings(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    let StartTemplateInitializationResult::InitializationIsFailed { reason } = result else {
+        panic!("Initialization must fail");
+    };
+    assert!(reason.unwrap().contains("statement_timeout"));
+}
+
+fn pgcrypto_extension_names() -> Vec<ExtensionName> {
+    vec![ExtensionName::new("pgcrypto").unwrap()]
+}
+
+#[tokio::test(start_paused = true)]
+async fn extensions_are_created_in_template_db() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            pgcrypto_extension_names(),
+        )
+        .await
+        .unwrap();
+    context
+        .tempest_core
+        .clone()
+        .finish_template_initialization(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    wait_for_background_tasks().await;
+
+    assert_eq!(
+        context
+            .pg_client
+            .call_count(PgClientOperation::CreateExtensions),
+        1
+    );
+    for db_name in [
+        format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"),
+        format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001"),
+    ] {
+        let db = context.pg_client.db(&db_name).unwrap();
+        assert_eq!(db.extension_names, pgcrypto_extension_names(), "{db_name}");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn unavailable_extension_fails_initialization() {
+    let context = TestContextBuilder::new().start().await;
+    context.pg_client.uninstall_extension("pgcrypto");
+
+    let result = context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            pgcrypto_extension_names(),
+        )
+        .await
+        .unwrap();
+
+    let StartTemplateInitializationResult::InitializationIsFailed { reason } = result else {
+        panic!("Initialization must fail");
+    };
+    assert!(reason.unwrap().contains("pgcrypto"));
+}
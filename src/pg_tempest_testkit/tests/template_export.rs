@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::templates::export_template::ExportTemplateErrorResult,
+    models::db_settings::DbSettings, pg_client::DbDumpFormat,
+};
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+use tokio::io::AsyncReadExt;
+
+use crate::common::{TEMPLATE_HASH, initialize_template, wait_for_background_tasks};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn template_is_exported_from_its_copy() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+
+    let mut dump = context
+        .tempest_core
+        .export_template(TEMPLATE_HASH, DbDumpFormat::Sql)
+        .await
+        .unwrap()
+        .dump;
+
+    let export_db_name = format!("TEMPEST_{TEMPLATE_HASH}_EXPORT_0001");
+    assert!(context.pg_client.db(&export_db_name).is_some());
+
+    let mut content = String::new();
+    dump.read_to_string(&mut content).await.unwrap();
+    assert_eq!(
+        content,
+        format!("-- Sql dump of {export_db_name} copied from TEMPEST_{TEMPLATE_HASH}_TEMPLATE\n")
+    );
+
+    drop(dump);
+    wait_for_background_tasks().await;
+    assert!(context.pg_client.db(&export_db_name).is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn template_isnt_exported_before_initialization_is_finished() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    context
+        .tempest_core
+        .clone()
+        .start_template_initialization(
+            TEMPLATE_HASH,
+            Duration::from_secs(10),
+            None,
+            None,
+            DbSettings::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    let result = context
+        .tempest_core
+        .export_template(TEMPLATE_HASH, DbDumpFormat::Custom)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ExportTemplateErrorResult::InitializationIsNotFinished)
+    ));
+    assert_eq!(context.pg_client.call_count(PgClientOperation::DumpDb), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn export_db_is_dropped_once_dump_connection_is_closed() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+
+    let dump = context
+        .tempest_core
+        .export_template(TEMPLATE_HASH, DbDumpFormat::Custom)
+        .await
+        .unwrap()
+        .dump;
+
+    // The backend of pg_dump can outlive the killed process for a moment
+    let export_db_name = format!("TEMPEST_{TEMPLATE_HASH}_EXPORT_0001");
+    context.pg_client.set_has_connections(&export_db_name, true);
+
+    drop(dump);
+    wait_for_background_tasks().await;
+    assert!(context.pg_client.db(&export_db_name).is_some());
+
+    context
+        .pg_client
+        .set_has_connections(&export_db_name, false);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(context.pg_client.db(&export_db_name).is_none());
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::templates::import_template::{ImportTemplateResult, TemplateDump},
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+    pg_client::DbDump,
+};
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::{TEMPLATE_HASH, test_db_states, wait_for_background_tasks};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn imported_template_is_finished_without_initializer() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+
+    let result = context
+        .tempest_core
+        .clone()
+        .import_template(
+            TEMPLATE_HASH,
+            TemplateDump::Sql {
+                script: "CREATE TABLE users (id BIGINT);".into(),
+            },
+            Duration::from_secs(10),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(matches!(result, ImportTemplateResult::TemplateWasImported));
+
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert_eq!(states.len(), 1);
+    assert!(matches!(states[0], TestDbState::Ready));
+
+    let template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert!(template_db.is_template);
+    assert!(matches!(
+        template_db.restored_dump,
+        Some(DbDump::Sql { script }) if &*script == "CREATE TABLE users (id BIGINT);"
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn dump_restoration_failure_fails_initialization() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    context.pg_client.fail_next(PgClientOperation::RestoreDb, 1);
+
+    let result = context
+        .tempest_core
+        .clone()
+        .import_template(
+            TEMPLATE_HASH,
+            TemplateDump::Sql {
+                script: "CREATE TABLE users (id BIGINT);".into(),
+            },
+            Duration::from_secs(10),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        ImportTemplateResult::InitializationIsFailed { reason: Some(_) }
+    ));
+
+    let template = context
+        .tempest_core
+        .get_template(TEMPLATE_HASH)
+        .await
+        .unwrap();
+    assert!(matches!(
+        template.initialization_state,
+        TemplateInitializationState::Failed { .. }
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn archive_isnt_imported_without_archive_dir() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+
+    let result = context
+        .tempest_core
+        .clone()
+        .import_template(
+            TEMPLATE_HASH,
+            TemplateDump::Archive {
+                path: std::path::Path::new("app.dump").into(),
+            },
+            Duration::from_secs(10),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        ImportTemplateResult::ArchiveImportIsDisabled
+    ));
+
+    assert!(
+        context
+            .tempest_core
+            .get_template(TEMPLATE_HASH)
+            .await
+            .is_none()
+    );
+    assert_eq!(
+        context.pg_client.call_count(PgClientOperation::RestoreDb),
+        0
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn initialization_is_extended_during_long_restoration() {
+    let context = TestContextBuilder::new().start().await;
+    // Restoration takes longer than the initialization duration, while the template db
+    // is still created within the long polling timeout
+    context.pg_client.set_delay(Duration::from_millis(300));
+
+    let import = tokio::spawn(context.tempest_core.clone().import_template(
+        TEMPLATE_HASH,
+        TemplateDump::Sql {
+            script: "CREATE TABLE users (id BIGINT);".into(),
+        },
+        Duration::from_millis(200),
+        None,
+    ));
+
+    while !import.is_finished() {
+        context
+            .clock
+            .advance_with_tokio_time(Duration::from_millis(10))
+            .await;
+    }
+
+    let result = import.await.unwrap().unwrap();
+    assert!(matches!(result, ImportTemplateResult::TemplateWasImported));
+}
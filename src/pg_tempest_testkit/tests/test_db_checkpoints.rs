@@ -0,0 +1,289 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::test_dbs::{
+        create_test_db_checkpoint::CreateTestDbCheckpointErrorResult,
+        finish_test_db_usage::{FinishTestDbUsageOkResult, TestDbRetention, TestDbUsageOutcome},
+        restore_test_db_checkpoint::RestoreTestDbCheckpointErrorResult,
+    },
+    metadata::template_metadata::TestDbState,
+    models::value_types::checkpoint_id::CheckpointId,
+};
+use pg_tempest_testkit::TestContextBuilder;
+
+use crate::common::{
+    TEMPLATE_HASH, initialize_template, test_db_states, wait_for_background_tasks,
+};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn test_db_is_restored_from_checkpoint() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let test_db_name = format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001");
+
+    let checkpoint_id = context
+        .tempest_core
+        .clone()
+        .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+        .await
+        .unwrap()
+        .checkpoint_id;
+    let checkpoint_db_name = format!("{test_db_name}_CP_{checkpoint_id}");
+    let checkpoint_db = context.pg_client.db(&checkpoint_db_name).unwrap();
+    assert_eq!(checkpoint_db.template_db_name.to_string(), test_db_name);
+
+    context
+        .tempest_core
+        .clone()
+        .restore_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id, checkpoint_id)
+        .await
+        .unwrap();
+    let restored_db = context.pg_client.db(&test_db_name).unwrap();
+    assert_eq!(restored_db.template_db_name.to_string(), checkpoint_db_name);
+    assert!(context.pg_client.db(&checkpoint_db_name).is_some());
+    assert!(matches!(
+        test_db_states(&context.tempest_core).await.as_slice(),
+        [TestDbState::InUse { .. }]
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn checkpoints_are_dropped_after_usage() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+
+    let mut checkpoint_db_names = Vec::new();
+    for _ in 0..2 {
+        let checkpoint_id = context
+            .tempest_core
+            .clone()
+            .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+            .await
+            .unwrap()
+            .checkpoint_id;
+        checkpoint_db_names.push(format!(
+            "TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001_CP_{checkpoint_id}"
+        ));
+    }
+    assert!(
+        checkpoint_db_names
+            .iter()
+            .all(|x| context.pg_client.db(x).is_some())
+    );
+
+    context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await
+        .unwrap();
+    wait_for_background_tasks().await;
+
+    assert!(
+        checkpoint_db_names
+            .iter()
+            .all(|x| context.pg_client.db(x).is_none())
+    );
+    assert!(matches!(
+        test_db_states(&context.tempest_core).await.as_slice(),
+        [TestDbState::Ready]
+    ));
+
+    let result = context
+        .tempest_core
+        .clone()
+        .restore_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id, CheckpointId::new(1))
+        .await;
+    assert!(matches!(
+        result,
+        Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed)
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn checkpoint_isnt_created_while_test_db_has_connections() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let test_db_name = format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001");
+    context.pg_client.set_has_connections(&test_db_name, true);
+
+    let result = context
+        .tempest_core
+        .clone()
+        .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CreateTestDbCheckpointErrorResult::TestDbHasConnections)
+    ));
+
+    context.pg_client.set_has_connections(&test_db_name, false);
+    let checkpoint_id = context
+        .tempest_core
+        .clone()
+        .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+        .await
+        .unwrap()
+        .checkpoint_id;
+
+    context.pg_client.set_has_connections(&test_db_name, true);
+    let result = context
+        .tempest_core
+        .clone()
+        .restore_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id, checkpoint_id)
+        .await;
+    assert!(matches!(
+        result,
+        Err(RestoreTestDbCheckpointErrorResult::TestDbHasConnections)
+    ));
+    assert!(context.pg_client.db(&test_db_name).is_some());
+}
+
+#[tokio::test(start_paused = true)]
+async fn restore_isnt_completed_after_concurrent_finish() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let checkpoint_id = context
+        .tempest_core
+        .clone()
+        .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+        .await
+        .unwrap()
+        .checkpoint_id;
+
+    // Each db operation of the restoration takes a second, so the usage is finished in between
+    context.pg_client.set_delay(Duration::from_secs(1));
+    let restoration = tokio::spawn(context.tempest_core.clone().restore_test_db_checkpoint(
+        TEMPLATE_HASH,
+        test_db.test_db_id,
+        checkpoint_id,
+    ));
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+
+    context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let result = restoration.await.unwrap();
+    assert! {
+        matches!(result, Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed)),
+        "{result:?}"
+    }
+
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    let test_db_name = format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001");
+    let recreated_db = context.pg_client.db(&test_db_name).unwrap();
+    assert_eq!(
+        recreated_db.template_db_name.to_string(),
+        format!("TEMPEST_{TEMPLATE_HASH}_TEMPLATE")
+    );
+    assert!(matches!(
+        test_db_states(&context.tempest_core).await.as_slice(),
+        [TestDbState::Ready]
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn restore_isnt_completed_after_concurrent_retention() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let checkpoint_id = context
+        .tempest_core
+        .clone()
+        .create_test_db_checkpoint(TEMPLATE_HASH, test_db.test_db_id)
+        .await
+        .unwrap()
+        .checkpoint_id;
+
+    context.pg_client.set_delay(Duration::from_secs(1));
+    let restoration = tokio::spawn(context.tempest_core.clone().restore_test_db_checkpoint(
+        TEMPLATE_HASH,
+        test_db.test_db_id,
+        checkpoint_id,
+    ));
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let result = context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Failed,
+            Some(TestDbRetention {
+                retention_duration: Duration::from_secs(600),
+                network_profile: None,
+            }),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        FinishTestDbUsageOkResult::TestDbWasRetained { .. }
+    ));
+
+    let result = restoration.await.unwrap();
+    assert! {
+        matches!(result, Err(RestoreTestDbCheckpointErrorResult::TestDbIsNotUsed)),
+        "{result:?}"
+    }
+    assert!(matches!(
+        test_db_states(&context.tempest_core).await.as_slice(),
+        [TestDbState::Retained { .. }]
+    ));
+}
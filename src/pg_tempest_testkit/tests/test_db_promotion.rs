@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::test_dbs::promote_test_db::PromoteTestDbErrorResult,
+    metadata::template_metadata::{TemplateInitializationState, TestDbState},
+};
+use pg_tempest_testkit::TestContextBuilder;
+
+use crate::common::{
+    NEW_TEMPLATE_HASH, TEMPLATE_HASH, initialize_template, test_db_states,
+    wait_for_background_tasks,
+};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn promoted_test_db_becomes_finished_template() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+
+    context
+        .tempest_core
+        .clone()
+        .promote_test_db(TEMPLATE_HASH, test_db.test_db_id, NEW_TEMPLATE_HASH)
+        .await
+        .unwrap();
+    wait_for_background_tasks().await;
+
+    let new_template = context
+        .tempest_core
+        .get_template(NEW_TEMPLATE_HASH)
+        .await
+        .unwrap();
+    assert!(matches!(
+        new_template.initialization_state,
+        TemplateInitializationState::Finished
+    ));
+    assert!(matches!(
+        new_template
+            .test_dbs
+            .iter()
+            .map(|x| &x.state)
+            .collect::<Vec<_>>()[..],
+        [TestDbState::Ready]
+    ));
+
+    let new_template_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{NEW_TEMPLATE_HASH}_TEMPLATE"))
+        .unwrap();
+    assert!(new_template_db.is_template);
+    assert_eq!(
+        new_template_db.template_db_name.to_string(),
+        format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001")
+    );
+
+    // The usage is finished, so the promoted test db is returned to the pool of its template
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states[..], [TestDbState::Ready]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_db_isnt_promoted_while_it_has_connections() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    context
+        .pg_client
+        .set_has_connections(&format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001"), true);
+
+    let result = context
+        .tempest_core
+        .clone()
+        .promote_test_db(TEMPLATE_HASH, test_db.test_db_id, NEW_TEMPLATE_HASH)
+        .await;
+    assert!(matches!(
+        result,
+        Err(PromoteTestDbErrorResult::TestDbHasConnections)
+    ));
+
+    // The new hash is released, so that the promotion can be retried
+    assert!(
+        context
+            .tempest_core
+            .get_template(NEW_TEMPLATE_HASH)
+            .await
+            .is_none()
+    );
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states[..], [TestDbState::InUse { .. }]));
+
+    let result = context
+        .tempest_core
+        .clone()
+        .promote_test_db(TEMPLATE_HASH, test_db.test_db_id, TEMPLATE_HASH)
+        .await;
+    assert!(matches!(
+        result,
+        Err(PromoteTestDbErrorResult::NewTemplateAlreadyExists)
+    ));
+}
@@ -0,0 +1,47 @@
+use pg_tempest_core::configs::db_pool_configs::TestDbRecyclingStrategy;
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::{TEMPLATE_HASH, use_test_db};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn modified_test_db_is_reset_in_place() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_recycling(TestDbRecyclingStrategy::Truncate)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.modify_tuples(test_db_name);
+        pg_client.advance_sequence(test_db_name);
+    })
+    .await;
+
+    assert_eq!(oid_before_usage, oid_after_usage);
+    assert_eq!(context.pg_client.call_count(PgClientOperation::ResetDb), 1);
+    let test_db = context
+        .pg_client
+        .db(&format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001"))
+        .unwrap();
+    assert_eq!(test_db.modified_tuple_count, 0);
+    assert_eq!(test_db.sequence_value, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_db_with_changed_schema_is_recreated_instead_of_reset() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_recycling(TestDbRecyclingStrategy::Truncate)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.change_schema(test_db_name)
+    })
+    .await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+    assert_eq!(context.pg_client.call_count(PgClientOperation::ResetDb), 1);
+}
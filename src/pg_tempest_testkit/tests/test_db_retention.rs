@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use pg_tempest_core::{
+    features::test_dbs::finish_test_db_usage::{
+        FinishTestDbUsageOkResult, TestDbRetention, TestDbUsageOutcome,
+    },
+    metadata::template_metadata::TestDbState,
+    utils::clock::Clock,
+};
+use pg_tempest_testkit::TestContextBuilder;
+
+use crate::common::{
+    TEMPLATE_HASH, initialize_template, test_db_states, wait_for_background_tasks,
+};
+
+mod common;
+
+fn retention(retention_duration: Duration) -> Option<TestDbRetention> {
+    Some(TestDbRetention {
+        retention_duration,
+        network_profile: None,
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn failed_test_db_is_retained_outside_of_pool() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    let test_db_name = format!("TEMPEST_{TEMPLATE_HASH}_TEST_DB_0001");
+    let db_before_finish = context.pg_client.db(&test_db_name).unwrap();
+
+    let result = context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Failed,
+            retention(Duration::from_secs(3600)),
+        )
+        .await
+        .unwrap();
+    let FinishTestDbUsageOkResult::TestDbWasRetained {
+        connection_options,
+        retention_deadline,
+    } = result
+    else {
+        panic!("Test db was not retained");
+    };
+    assert_eq!(connection_options.database.to_string(), test_db_name);
+    assert_eq!(
+        retention_deadline,
+        context.clock.now() + Duration::from_secs(3600)
+    );
+
+    let next_test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    assert_ne!(next_test_db.test_db_id, test_db.test_db_id);
+
+    wait_for_background_tasks().await;
+    assert_eq!(
+        context.pg_client.db(&test_db_name).unwrap().oid,
+        db_before_finish.oid
+    );
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(
+        states.as_slice(),
+        [TestDbState::Retained { .. }, TestDbState::InUse { .. }]
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn succeeded_test_db_is_released_despite_retention() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+
+    let result = context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Succeeded,
+            retention(Duration::from_secs(3600)),
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        FinishTestDbUsageOkResult::TestDbWasReleased
+    ));
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Ready]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retained_test_db_is_recreated_after_retention_deadline() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Failed,
+            retention(Duration::from_secs(120)),
+        )
+        .await
+        .unwrap();
+
+    // The usage deadline passes first, but it doesn't apply to retained test dbs
+    context
+        .clock
+        .advance_with_tokio_time(Duration::from_secs(90))
+        .await;
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Retained { .. }]));
+
+    context
+        .clock
+        .advance_with_tokio_time(Duration::from_secs(30))
+        .await;
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Ready]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retained_test_db_is_dropped_on_demand() {
+    let context = TestContextBuilder::new().db_pool_min_size(1).start().await;
+    initialize_template(&context.tempest_core).await;
+    wait_for_background_tasks().await;
+
+    let test_db = context
+        .tempest_core
+        .clone()
+        .get_test_db(TEMPLATE_HASH, Duration::from_secs(60), None)
+        .await
+        .unwrap();
+    assert!(
+        context
+            .tempest_core
+            .clone()
+            .drop_retained_test_db(TEMPLATE_HASH, test_db.test_db_id)
+            .await
+            .is_err()
+    );
+
+    context
+        .tempest_core
+        .clone()
+        .finish_test_db_usage(
+            TEMPLATE_HASH,
+            test_db.test_db_id,
+            TestDbUsageOutcome::Failed,
+            retention(Duration::from_secs(3600)),
+        )
+        .await
+        .unwrap();
+    context
+        .tempest_core
+        .clone()
+        .drop_retained_test_db(TEMPLATE_HASH, test_db.test_db_id)
+        .await
+        .unwrap();
+
+    wait_for_background_tasks().await;
+    let states = test_db_states(&context.tempest_core).await;
+    assert!(matches!(states.as_slice(), [TestDbState::Ready]));
+}
@@ -0,0 +1,103 @@
+use pg_tempest_core::configs::db_pool_configs::TestDbReuseStrictness;
+use pg_tempest_testkit::{TestContextBuilder, fake_pg_client::PgClientOperation};
+
+use crate::common::use_test_db;
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn unmodified_test_db_is_reused() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Counters)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |_, _| {}).await;
+
+    assert_eq!(oid_before_usage, oid_after_usage);
+}
+
+#[tokio::test(start_paused = true)]
+async fn modified_test_db_is_recreated() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Counters)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.modify_tuples(test_db_name)
+    })
+    .await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_db_isnt_reused_when_reuse_is_disabled() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Disabled)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |_, _| {}).await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+    assert_eq!(
+        context
+            .pg_client
+            .call_count(PgClientOperation::GetDbModificationStats),
+        0
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn sequence_change_is_detected_by_fingerprint() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Fingerprint)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.advance_sequence(test_db_name)
+    })
+    .await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+}
+
+#[tokio::test(start_paused = true)]
+async fn sequence_change_is_detected_by_counters() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Counters)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.advance_sequence(test_db_name)
+    })
+    .await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+}
+
+#[tokio::test(start_paused = true)]
+async fn modification_hidden_by_stats_reset_is_detected() {
+    let context = TestContextBuilder::new()
+        .db_pool_min_size(1)
+        .test_db_reuse(TestDbReuseStrictness::Counters)
+        .start()
+        .await;
+
+    let (oid_before_usage, oid_after_usage) = use_test_db(&context, |pg_client, test_db_name| {
+        pg_client.modify_tuples(test_db_name);
+        pg_client.reset_stats(test_db_name);
+    })
+    .await;
+
+    assert_ne!(oid_before_usage, oid_after_usage);
+}